@@ -0,0 +1,28 @@
+#!/usr/bin/env python3
+"""Writes the `.expected` file of each fixture as read by Python's configparser
+
+Each line of an `.expected` file is `section<TAB>key<TAB>value` with the
+interpolations resolved and the backslashes and line feeds escaped as `\\` and
+`\n`, sorted by section and by key.
+"""
+
+import configparser
+import pathlib
+
+HERE = pathlib.Path(__file__).parent
+
+
+def escape(value):
+    return value.replace("\\", "\\\\").replace("\n", "\\n")
+
+
+for path in sorted(HERE.glob("*.ini")):
+    parser = configparser.ConfigParser()
+    parser.read(path)
+
+    lines = []
+    for section in sorted(parser.sections()):
+        for key in sorted(parser[section]):
+            lines.append("\t".join((section, key, escape(parser.get(section, key)))))
+
+    path.with_suffix(".expected").write_text("".join(line + "\n" for line in lines))
@@ -0,0 +1,59 @@
+<?php
+/**
+ * Writes the `.expected` file of each fixture as read by `parse_ini_file($path, true)`
+ *
+ * Each line of an `.expected` file is the path of a value in the array, written
+ * with `var_export`, followed by ` = ` and the value written with `var_export`,
+ * like `['section']['key'][0] = 'value'`. An empty array is written `array()`.
+ *
+ * The constant `BIRD` and the environment variable `MININIP_HOME` are defined
+ * for every fixture, like in the tests of MinIniP.
+ *
+ * The `.expected` files in the repository were written by hand from the PHP
+ * manual and have not been produced by this script yet, so they may differ
+ * from the actual behaviour of PHP. Run `php generate.php --check` to compare
+ * them with the output of PHP without writing anything: it lists the files
+ * which differ and exits with status 1 if there is any.
+ */
+
+define('BIRD', 'Dodo bird');
+putenv('MININIP_HOME=/home/mininip');
+
+function render(array $array, string $path): string
+{
+    $result = '';
+    foreach ($array as $key => $value) {
+        $key_path = $path . '[' . var_export($key, true) . ']';
+        if ($value === []) {
+            $result .= $key_path . " = array()\n";
+        } elseif (is_array($value)) {
+            $result .= render($value, $key_path);
+        } else {
+            $result .= $key_path . ' = ' . var_export($value, true) . "\n";
+        }
+    }
+
+    return $result;
+}
+
+$check = in_array('--check', $argv, true);
+$status = 0;
+
+foreach (glob(__DIR__ . '/*.ini') as $path) {
+    $array = parse_ini_file($path, true);
+    if ($array === false) {
+        fwrite(STDERR, "Can't parse $path\n");
+        exit(1);
+    }
+
+    $expected_path = substr($path, 0, -4) . '.expected';
+    $expected = render($array, '');
+    if (!$check) {
+        file_put_contents($expected_path, $expected);
+    } elseif (file_get_contents($expected_path) !== $expected) {
+        fwrite(STDERR, "$expected_path differs from the output of PHP\n");
+        $status = 1;
+    }
+}
+
+exit($status);
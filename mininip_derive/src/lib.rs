@@ -0,0 +1,257 @@
+//! Derive macros for the [`FromIni`](https://docs.rs/mininip/*/mininip/datas/trait.FromIni.html) and [`ToIni`](https://docs.rs/mininip/*/mininip/datas/trait.ToIni.html) traits of mininip
+//! 
+//! These macros are re-exported by `mininip::datas` when the `derive` feature of mininip is enabled
+//! 
+//! A structure with named fields is mapped to a section. Each field is mapped to a key with the same name, or to a section if its type is itself a derived structure (only in the global section since sections can't be nested)
+//! 
+//! # Attributes
+//! `#[ini(rename = "name")]` on a field to use another key name
+//! 
+//! `#[ini(default)]` on a field to use `Default::default()` when the key is missing
+//! 
+//! `#[ini(default = "path::to::function")]` on a field to call a function when the key is missing
+//! 
+//! On a field mapped to a section, the default value is only used when the whole section is missing. A key missing inside an existing section is still an error
+//! 
+//! # Example
+//! ```
+//! use mininip::datas::{FromIni, ToIni};
+//! use mininip::parse::Parser;
+//! use mininip::datas::tree::Tree;
+//! 
+//! #[derive(FromIni, ToIni, Debug, PartialEq)]
+//! struct Server {
+//!     #[ini(rename = "listen-port")]
+//!     port: u16,
+//!     #[ini(default)]
+//!     verbose: bool,
+//! }
+//! 
+//! #[derive(FromIni, ToIni, Debug, PartialEq)]
+//! struct Config {
+//!     name: String,
+//!     server: Server,
+//! }
+//! 
+//! let mut parser = Parser::new();
+//! parser.parse_content("name = demo\n[server]\nlisten-port = 8080\n").unwrap();
+//! let tree = Tree::from(parser.data());
+//! 
+//! let config = Config::from_tree(&tree).unwrap();
+//! assert_eq!(config, Config {
+//!     name: String::from("demo"),
+//!     server: Server { port: 8080, verbose: false },
+//! });
+//! 
+//! let tree = config.to_tree().unwrap();
+//! assert_eq!(Config::from_tree(&tree).unwrap(), config);
+//! ```
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Expr, Fields, Lit, Path, Type};
+
+/// Implements `mininip::datas::FromIni` for a structure with named fields
+#[proc_macro_derive(FromIni, attributes(ini))]
+pub fn derive_from_ini(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_from_ini(&input).unwrap_or_else(Error::into_compile_error)
+                           .into()
+}
+
+/// Implements `mininip::datas::ToIni` for a structure with named fields
+#[proc_macro_derive(ToIni, attributes(ini))]
+pub fn derive_to_ini(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_to_ini(&input).unwrap_or_else(Error::into_compile_error)
+                         .into()
+}
+
+
+/// What to do when the key of a field is missing
+enum Fallback {
+    /// The field is required
+    None,
+    /// `Default::default()` is used
+    Trait,
+    /// The function at this path is called
+    Function(Path),
+}
+
+/// A field of the structure derived
+struct Field {
+    member: syn::Ident,
+    ty: Type,
+    key: String,
+    default: Fallback,
+}
+
+/// Reads the fields of the structure derived and their `#[ini(...)]` attributes
+fn fields(input: &DeriveInput) -> Result<Vec<Field>, Error> {
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _                     => return Err(Error::new_spanned(&input.ident, "Only a structure with named fields can be mapped to an INI section")),
+        },
+        _                  => return Err(Error::new_spanned(&input.ident, "Only a structure with named fields can be mapped to an INI section")),
+    };
+
+    let mut fields = Vec::with_capacity(named.len());
+    for i in named {
+        let member = i.ident.clone().expect("A named field has a name");
+        let mut key = member.to_string();
+        let mut default = Fallback::None;
+
+        for attr in i.attrs.iter().filter(|attr| attr.path().is_ident("ini")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    key = string_literal(&meta.value()?.parse()?)?;
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    default = if meta.input.peek(syn::Token![=]) {
+                        Fallback::Function(syn::parse_str(&string_literal(&meta.value()?.parse()?)?)?)
+                    } else {
+                        Fallback::Trait
+                    };
+                    Ok(())
+                } else {
+                    Err(meta.error("Unknown ini attribute, expected `rename` or `default`"))
+                }
+            })?;
+        }
+
+        let key = key.trim_start_matches("r#").to_string();
+        fields.push(Field {
+            member,
+            ty: i.ty.clone(),
+            key,
+            default,
+        });
+    }
+
+    Ok(fields)
+}
+
+/// Returns the content of a string literal
+fn string_literal(expr: &Expr) -> Result<String, Error> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Str(string) => Ok(string.value()),
+            _                => Err(Error::new_spanned(expr, "Expected a string literal")),
+        },
+        _              => Err(Error::new_spanned(expr, "Expected a string literal")),
+    }
+}
+
+/// Checks at compile time that the key names are valid identifiers, with the rule of `mininip::datas::Identifier::is_valid` itself
+fn key_checks(fields: &[Field]) -> TokenStream2 {
+    let checks = fields.iter().map(|field| {
+        let key = &field.key;
+        let message = format!("{:?} is not a valid INI identifier, use #[ini(rename = \"...\")]", key);
+
+        quote_spanned! { field.member.span() =>
+            const _: () = ::std::assert!(::mininip::datas::Identifier::is_valid(#key), #message);
+        }
+    });
+
+    quote! { #(#checks)* }
+}
+
+fn expand_from_ini(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = fields(input)?;
+    let checks = key_checks(&fields);
+    let fields = fields.into_iter().map(|field| {
+        let Field { member, ty, key, default } = field;
+        let ident = quote! {
+            ::mininip::datas::Identifier::new(section.map(::std::string::String::from), ::std::string::String::from(#key))
+        };
+
+        let default = match default {
+            Fallback::None              => return quote! { #member: <#ty as ::mininip::datas::FromIni>::from_key(data, &#ident)? },
+            Fallback::Trait             => quote! { ::std::default::Default::default() },
+            Fallback::Function(fn_path) => quote! { #fn_path() },
+        };
+
+        // Only a missing key of this field falls back to the default, or the whole section of a nested structure, not a key missing inside this section
+        quote! {
+            #member: {
+                let ident = #ident;
+                match <#ty as ::mininip::datas::FromIni>::from_key(data, &ident) {
+                    ::std::result::Result::Err(::mininip::errors::MappingError::MissingKey(missing))
+                        if missing == ident
+                        || section.is_none() && !data.keys().any(|i| i.section() == ::std::option::Option::Some(#key)) => #default,
+                    result                                                                                              => result?,
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #checks
+
+        impl #impl_generics ::mininip::datas::FromIni for #name #ty_generics #where_clause {
+            fn from_value(value: &::mininip::datas::Value) -> ::std::result::Result<Self, ::mininip::errors::ConversionError> {
+                ::std::result::Result::Err(::mininip::errors::ConversionError::new("Section", value.type_name()))
+            }
+
+            fn from_key(data: &::std::collections::HashMap<::mininip::datas::Identifier, ::mininip::datas::Value>, ident: &::mininip::datas::Identifier) -> ::std::result::Result<Self, ::mininip::errors::MappingError> {
+                match ident.section() {
+                    ::std::option::Option::Some(_) => ::std::result::Result::Err(::mininip::errors::MappingError::NestedSection(ident.clone())),
+                    ::std::option::Option::None    => <Self as ::mininip::datas::FromIni>::from_section(data, ::std::option::Option::Some(ident.name())),
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn from_section(data: &::std::collections::HashMap<::mininip::datas::Identifier, ::mininip::datas::Value>, section: ::std::option::Option<&str>) -> ::std::result::Result<Self, ::mininip::errors::MappingError> {
+                ::std::result::Result::Ok(#name {
+                    #(#fields,)*
+                })
+            }
+        }
+    })
+}
+
+fn expand_to_ini(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = fields(input)?;
+    let checks = key_checks(&fields);
+    let fields = fields.into_iter().map(|field| {
+        let Field { member, ty, key, .. } = field;
+        quote! {
+            <#ty as ::mininip::datas::ToIni>::to_key(&self.#member, data, ::mininip::datas::Identifier::new(section.map(::std::string::String::from), ::std::string::String::from(#key)))?;
+        }
+    });
+
+    Ok(quote! {
+        #checks
+
+        impl #impl_generics ::mininip::datas::ToIni for #name #ty_generics #where_clause {
+            fn to_value(&self) -> ::std::result::Result<::std::option::Option<::mininip::datas::Value>, ::mininip::errors::ConversionError> {
+                ::std::result::Result::Err(::mininip::errors::ConversionError::new("Value", "Section"))
+            }
+
+            fn to_key(&self, data: &mut ::std::collections::HashMap<::mininip::datas::Identifier, ::mininip::datas::Value>, ident: ::mininip::datas::Identifier) -> ::std::result::Result<(), ::mininip::errors::MappingError> {
+                match ident.section() {
+                    ::std::option::Option::Some(_) => ::std::result::Result::Err(::mininip::errors::MappingError::NestedSection(ident)),
+                    ::std::option::Option::None    => <Self as ::mininip::datas::ToIni>::to_section(self, data, ::std::option::Option::Some(ident.name())),
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn to_section(&self, data: &mut ::std::collections::HashMap<::mininip::datas::Identifier, ::mininip::datas::Value>, section: ::std::option::Option<&str>) -> ::std::result::Result<(), ::mininip::errors::MappingError> {
+                #(#fields)*
+                ::std::result::Result::Ok(())
+            }
+        }
+    })
+}
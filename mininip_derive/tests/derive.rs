@@ -0,0 +1,132 @@
+use mininip::datas::{FromIni, Identifier, ToIni, Value};
+use mininip::datas::tree::Tree;
+use mininip::errors::MappingError;
+use mininip::parse::Parser;
+use std::collections::HashMap;
+
+#[derive(FromIni, ToIni, Debug, PartialEq)]
+struct Server {
+    #[ini(rename = "listen-port")]
+    port: u16,
+    #[ini(default)]
+    verbose: bool,
+    #[ini(default = "default_hosts")]
+    hosts: Vec<String>,
+    timeout: Option<u32>,
+}
+
+fn default_hosts() -> Vec<String> {
+    vec![String::from("localhost")]
+}
+
+#[derive(FromIni, ToIni, Debug, PartialEq)]
+struct Config {
+    name: String,
+    server: Server,
+}
+
+fn parse(content: &str) -> Tree {
+    let mut parser = Parser::new();
+    parser.parse_content(content).unwrap();
+
+    Tree::from(parser.data())
+}
+
+fn ident(section: Option<&str>, name: &str) -> Identifier {
+    Identifier::new(section.map(String::from), String::from(name))
+}
+
+#[test]
+fn from_tree_with_defaults() {
+    let tree = parse("name = demo\n[server]\nlisten-port = 8080\n");
+
+    assert_eq!(Config::from_tree(&tree), Ok(Config {
+        name: String::from("demo"),
+        server: Server {
+            port: 8080,
+            verbose: false,
+            hosts: default_hosts(),
+            timeout: None,
+        },
+    }));
+}
+
+#[test]
+fn from_tree_with_every_key() {
+    let tree = parse("name = demo\n[server]\nlisten-port = 80\nverbose = on\nhosts = a.org, b.org\ntimeout = 30\n");
+    let config = Config::from_tree(&tree).unwrap();
+
+    assert!(config.server.verbose);
+    assert_eq!(config.server.hosts, vec![String::from("a.org"), String::from("b.org")]);
+    assert_eq!(config.server.timeout, Some(30));
+}
+
+#[test]
+fn from_tree_errors() {
+    let tree = parse("name = demo\n[server]\n");
+    assert_eq!(Config::from_tree(&tree), Err(MappingError::MissingKey(ident(Some("server"), "listen-port"))));
+
+    let tree = parse("name = demo\n[server]\nlisten-port = -1\n");
+    assert!(matches!(Config::from_tree(&tree), Err(MappingError::InvalidValue(_, _))));
+}
+
+#[test]
+fn to_tree() {
+    let config = Config {
+        name: String::from("demo"),
+        server: Server {
+            port: 8080,
+            verbose: true,
+            hosts: vec![String::from("a.org")],
+            timeout: None,
+        },
+    };
+    let tree = config.to_tree().unwrap();
+    let data = tree.get_data();
+
+    assert_eq!(data.len(), 4);
+    assert_eq!(data[&ident(None, "name")], Value::Str(String::from("demo")));
+    assert_eq!(data[&ident(Some("server"), "listen-port")], Value::Int(8080));
+    assert_eq!(data[&ident(Some("server"), "verbose")], Value::Bool(true));
+    assert_eq!(Config::from_tree(&tree), Ok(config));
+}
+
+#[test]
+fn nested_sections() {
+    #[derive(FromIni, ToIni, Debug)]
+    struct Nested {
+        config: Config,
+    }
+
+    let tree = parse("[config]\nname = demo\n");
+    assert_eq!(Nested::from_tree(&tree).unwrap_err(), MappingError::NestedSection(ident(Some("config"), "server")));
+
+    let mut data = HashMap::new();
+    let server = Server {
+        port: 1,
+        verbose: false,
+        hosts: Vec::new(),
+        timeout: None,
+    };
+    assert_eq!(server.to_key(&mut data, ident(Some("config"), "server")), Err(MappingError::NestedSection(ident(Some("config"), "server"))));
+}
+
+#[test]
+fn default_section() {
+    #[derive(FromIni, Debug, PartialEq, Default)]
+    struct Limits {
+        max: u32,
+    }
+
+    #[derive(FromIni, Debug, PartialEq)]
+    struct Settings {
+        #[ini(default)]
+        limits: Limits,
+    }
+
+    let tree = parse("");
+    assert_eq!(Settings::from_tree(&tree), Ok(Settings { limits: Limits { max: 0 } }));
+
+    let tree = parse("[limits]\nmin = 1\n");
+    assert_eq!(Settings::from_tree(&tree), Err(MappingError::MissingKey(ident(Some("limits"), "max"))));
+}
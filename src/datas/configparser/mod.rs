@@ -0,0 +1,177 @@
+//! A view of the data parsed with [`Dialect::ConfigParser`](../../parse/enum.Dialect.html "parse::Dialect") which looks up the values like the `configparser` module of Python
+//! 
+//! # See
+//! `ConfigParserView` to look up a value
+
+use std::collections::{BTreeSet, HashMap};
+use crate::datas::{Identifier, IdentifierPolicy, Value};
+use crate::errors::InterpolationError;
+
+/// The name of the section whose keys are inherited by every other section
+pub const DEFAULT_SECTION: &str = "DEFAULT";
+
+/// The maximum number of nested interpolations, like in Python
+const MAX_INTERPOLATION_DEPTH: usize = 10;
+
+/// A view of the data parsed with [`Dialect::ConfigParser`](../../parse/enum.Dialect.html "parse::Dialect") which resolves the values on lookup like the `BasicInterpolation` of Python
+/// - a key which is not defined in a section is looked up in the `[DEFAULT]` section, if this section exists
+/// - `%(name)s` is replaced by the value of the key `name` of the same section (or of `[DEFAULT]`) and `%%` by `%`
+/// - the key names are case-insensitive
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::configparser::ConfigParserView;
+/// use mininip::parse::{Dialect, Parser};
+/// 
+/// let mut parser = Parser::with_dialect(Dialect::ConfigParser);
+/// parser.parse_content("[DEFAULT]\nhome = /home/user\n[paths]\ncache = %(home)s/.cache\n").unwrap();
+/// let view = ConfigParserView::from(parser.data());
+/// 
+/// assert_eq!(view.sections(), vec!["paths"]);
+/// assert_eq!(view.get("paths", "Cache").unwrap(), Some(String::from("/home/user/.cache")));
+/// assert_eq!(view.get("paths", "home").unwrap(), Some(String::from("/home/user")));
+/// assert_eq!(view.get("paths", "missing").unwrap(), None);
+/// assert_eq!(view.get("missing", "home").unwrap(), None);
+/// ```
+/// 
+/// # Notes
+/// A section is only known if it has at least one key, since the data parsed does not keep the empty sections
+#[derive(Debug, Clone)]
+pub struct ConfigParserView {
+    data: HashMap<Identifier, Value>,
+}
+
+impl From<HashMap<Identifier, Value>> for ConfigParserView {
+    fn from(data: HashMap<Identifier, Value>) -> ConfigParserView {
+        ConfigParserView {
+            data,
+        }
+    }
+}
+
+impl ConfigParserView {
+    /// Returns an immutable reference to the owned data
+    pub fn get_data(&self) -> &HashMap<Identifier, Value> {
+        &self.data
+    }
+
+    /// Consumes `self` and returns the owned data
+    pub fn into_data(self) -> HashMap<Identifier, Value> {
+        self.data
+    }
+
+    /// Returns the names of the sections sorted by name, without `[DEFAULT]` nor the global section
+    pub fn sections(&self) -> Vec<&str> {
+        self.data.keys()
+                 .filter_map(Identifier::section)
+                 .filter(|section| *section != DEFAULT_SECTION)
+                 .collect::<BTreeSet<&str>>()
+                 .into_iter()
+                 .collect()
+    }
+
+    /// Returns `true` if `section` has at least one key, or if it is `[DEFAULT]`
+    pub fn has_section(&self, section: &str) -> bool {
+        section == DEFAULT_SECTION || self.data.keys().any(|ident| ident.section() == Some(section))
+    }
+
+    /// Returns the names of the keys available in `section` sorted by name, including the ones inherited from `[DEFAULT]`, or nothing if `section` does not exist
+    pub fn keys(&self, section: &str) -> Vec<&str> {
+        if !self.has_section(section) {
+            return Vec::new();
+        }
+
+        self.data.keys()
+                 .filter(|ident| ident.section() == Some(section) || ident.section() == Some(DEFAULT_SECTION))
+                 .map(Identifier::name)
+                 .collect::<BTreeSet<&str>>()
+                 .into_iter()
+                 .collect()
+    }
+
+    /// Returns the value of `key` in `section` or in `[DEFAULT]` without resolving its interpolations, or `None` if `section` does not exist
+    pub fn get_raw(&self, section: &str, key: &str) -> Option<&Value> {
+        let ident = self.identifier(section, key)?;
+        self.data.get(&ident).or_else(|| {
+            let mut ident = ident;
+            ident.change_section(Some(String::from(DEFAULT_SECTION)));
+            self.data.get(&ident)
+        })
+    }
+
+    /// Returns the value of `key` in `section` or in `[DEFAULT]` with its interpolations resolved
+    /// 
+    /// # Return value
+    /// `Ok(Some(value))` in case of success
+    /// 
+    /// `Ok(None)` if `key` is not defined or if `section` does not exist
+    /// 
+    /// `Err(error)` if an interpolation can't be resolved
+    pub fn get(&self, section: &str, key: &str) -> Result<Option<String>, InterpolationError> {
+        let (ident, value) = match (self.identifier(section, key), self.get_raw(section, key)) {
+            (Some(ident), Some(value)) => (ident, format!("{}", value)),
+            _                          => return Ok(None),
+        };
+
+        let mut result = String::with_capacity(value.len());
+        self.interpolate(&ident, &value, &mut result, 1)?;
+
+        Ok(Some(result))
+    }
+
+    /// Returns the value of `key` in `section` or in `[DEFAULT]` with its interpolations resolved as a `Value::Raw`
+    /// 
+    /// # See
+    /// [`get`](struct.ConfigParserView.html#method.get "datas::configparser::ConfigParserView::get") for the return value
+    pub fn get_value(&self, section: &str, key: &str) -> Result<Option<Value>, InterpolationError> {
+        Ok(self.get(section, key)?.map(Value::Raw))
+    }
+
+    /// Returns the identifier of `key` in `section` as it is stored by the parser, or `None` if `section` does not exist or if `key` is invalid
+    fn identifier(&self, section: &str, key: &str) -> Option<Identifier> {
+        let key = key.to_lowercase();
+        let policy = IdentifierPolicy::ConfigParser;
+        if !self.has_section(section) || !policy.is_valid(section) || !policy.is_valid(&key) {
+            return None;
+        }
+
+        Some(Identifier::with_policy(Some(String::from(section)), key, policy))
+    }
+
+    /// Appends `value` to `result`, replacing its interpolations by the values of `ident`'s section
+    fn interpolate(&self, ident: &Identifier, value: &str, result: &mut String, depth: usize) -> Result<(), InterpolationError> {
+        if depth > MAX_INTERPOLATION_DEPTH {
+            return Err(InterpolationError::TooDeep(ident.clone()));
+        }
+
+        let section = ident.section().expect("A configparser value is always in a section");
+        let mut rest = value;
+        while let Some(percent) = rest.find('%') {
+            result.push_str(&rest[..percent]);
+            rest = &rest[percent + 1..];
+
+            if let Some(after) = rest.strip_prefix('%') {
+                result.push('%');
+                rest = after;
+            } else if let Some(after) = rest.strip_prefix('(') {
+                let end = after.find(")s")
+                               .ok_or_else(|| InterpolationError::InvalidSyntax(ident.clone()))?;
+                let name = &after[..end];
+                rest = &after[end + 2..];
+
+                let referenced = self.get_raw(section, name)
+                                     .ok_or_else(|| InterpolationError::MissingReference(ident.clone(), name.to_lowercase()))?;
+                self.interpolate(ident, &format!("{}", referenced), result, depth + 1)?;
+            } else {
+                return Err(InterpolationError::InvalidSyntax(ident.clone()));
+            }
+        }
+
+        result.push_str(rest);
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests;
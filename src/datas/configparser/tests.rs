@@ -0,0 +1,114 @@
+use crate::datas::configparser::*;
+use crate::errors::InterpolationError;
+use crate::parse::{Dialect, Parser};
+
+fn view(content: &str) -> ConfigParserView {
+    let mut parser = Parser::with_dialect(Dialect::ConfigParser);
+    parser.parse_content(content).unwrap();
+
+    ConfigParserView::from(parser.data())
+}
+
+/// Renders `view` like the `generate.py` script of the fixtures
+fn render(view: &ConfigParserView) -> String {
+    let mut result = String::new();
+    for section in view.sections() {
+        for key in view.keys(section) {
+            let value = view.get(section, key).unwrap().unwrap();
+            let value = value.replace('\\', "\\\\").replace('\n', "\\n");
+            result.push_str(&format!("{}\t{}\t{}\n", section, key, value));
+        }
+    }
+
+    result
+}
+
+#[test]
+fn configparser_fixture_basic() {
+    let view = view(include_str!("../../../fixtures/configparser/basic.ini"));
+
+    assert_eq!(render(&view), include_str!("../../../fixtures/configparser/basic.expected"));
+}
+
+#[test]
+fn configparser_fixture_interpolation() {
+    let view = view(include_str!("../../../fixtures/configparser/interpolation.ini"));
+
+    assert_eq!(render(&view), include_str!("../../../fixtures/configparser/interpolation.expected"));
+}
+
+#[test]
+fn configparser_fixture_multiline() {
+    let view = view(include_str!("../../../fixtures/configparser/multiline.ini"));
+
+    assert_eq!(render(&view), include_str!("../../../fixtures/configparser/multiline.expected"));
+}
+
+#[test]
+fn configparser_fixture_identifiers() {
+    let view = view(include_str!("../../../fixtures/configparser/identifiers.ini"));
+
+    assert_eq!(render(&view), include_str!("../../../fixtures/configparser/identifiers.expected"));
+}
+
+#[test]
+fn configparser_missing_section() {
+    let view = view("[DEFAULT]\nuser = root\n[server]\nport = 22\n");
+
+    assert!(view.has_section("server"));
+    assert!(view.has_section("DEFAULT"));
+    assert!(!view.has_section("client"));
+    assert_eq!(view.get("client", "user").unwrap(), None);
+    assert_eq!(view.get_raw("client", "user"), None);
+    assert_eq!(view.keys("client"), Vec::<&str>::new());
+}
+
+#[test]
+fn configparser_default_inheritance() {
+    let view = view("[DEFAULT]\nuser = root\nport = 22\n[server]\nport = 2222\n");
+
+    assert_eq!(view.sections(), vec!["server"]);
+    assert_eq!(view.keys("server"), vec!["port", "user"]);
+    assert_eq!(view.get("server", "PORT").unwrap(), Some(String::from("2222")));
+    assert_eq!(view.get("server", "user").unwrap(), Some(String::from("root")));
+    assert_eq!(view.get("DEFAULT", "port").unwrap(), Some(String::from("22")));
+    assert_eq!(view.get("server", "missing").unwrap(), None);
+    assert_eq!(view.get("server", "not valid!").unwrap(), None);
+}
+
+#[test]
+fn configparser_missing_reference() {
+    let view = view("[section]\nkey = %(missing)s\n");
+
+    match view.get("section", "key") {
+        Err(InterpolationError::MissingReference(_, name)) => assert_eq!(name, "missing"),
+        result                                             => panic!("Unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn configparser_invalid_syntax() {
+    for content in &["[section]\nkey = 100%\n", "[section]\nkey = %(unclosed\n", "[section]\nkey = %d\n"] {
+        match view(content).get("section", "key") {
+            Err(InterpolationError::InvalidSyntax(_)) => {},
+            result                                    => panic!("Unexpected result {:?} for {:?}", result, content),
+        }
+    }
+}
+
+#[test]
+fn configparser_too_deep() {
+    let view = view("[section]\na = %(b)s\nb = %(a)s\n");
+
+    match view.get("section", "a") {
+        Err(InterpolationError::TooDeep(_)) => {},
+        result                              => panic!("Unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn configparser_get_raw() {
+    let view = view("[section]\nkey = %(other)s\n");
+
+    assert_eq!(view.get_raw("section", "key"), Some(&crate::datas::Value::Raw(String::from("%(other)s"))));
+}
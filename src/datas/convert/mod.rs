@@ -0,0 +1,333 @@
+//! Conversions between a [`Value`](../enum.Value.html "datas::Value") and the Rust types
+//! 
+//! # See
+//! The `as_*` methods of `Value` to borrow its content
+//! 
+//! `TryFrom<&Value>` to convert a `Value` into a primitive type
+//! 
+//! `From<T> for Value` to convert a Rust type into a `Value`
+//! 
+//! `Lenient` for an opt-in lenient coercion
+
+use std::convert::TryFrom;
+use std::time::Duration;
+use crate::datas::{BoolVocabulary, Value};
+use crate::datas::datetime::{Date, Time, DateTime};
+use crate::dump;
+use crate::errors::ConversionError;
+
+impl Value {
+    /// Returns the content of a `Value::Int`
+    /// 
+    /// # Return value
+    /// `Ok(value)` if `self` is an `Int`
+    /// 
+    /// `Err(error)` otherwise, with `error` naming the type found
+    pub fn as_i64(&self) -> Result<i64, ConversionError> {
+        match self {
+            Value::Int(value) => Ok(*value),
+            _                 => Err(ConversionError::new("Int", self.type_name())),
+        }
+    }
+
+    /// Returns the content of a `Value::Float`
+    /// 
+    /// # Return value
+    /// `Ok(value)` if `self` is a `Float`
+    /// 
+    /// `Err(error)` otherwise, with `error` naming the type found
+    pub fn as_f64(&self) -> Result<f64, ConversionError> {
+        match self {
+            Value::Float(value) => Ok(*value),
+            _                   => Err(ConversionError::new("Float", self.type_name())),
+        }
+    }
+
+    /// Returns the content of a `Value::Bool`
+    /// 
+    /// # Return value
+    /// `Ok(value)` if `self` is a `Bool`
+    /// 
+    /// `Err(error)` otherwise, with `error` naming the type found
+    pub fn as_bool(&self) -> Result<bool, ConversionError> {
+        match self {
+            Value::Bool(value) => Ok(*value),
+            _                  => Err(ConversionError::new("Bool", self.type_name())),
+        }
+    }
+
+    /// Returns the content of a `Value::Str` or a `Value::Raw`
+    /// 
+    /// # Return value
+    /// `Ok(value)` if `self` is a `Str` or a `Raw`
+    /// 
+    /// `Err(error)` otherwise, with `error` naming the type found
+    pub fn as_str(&self) -> Result<&str, ConversionError> {
+        match self {
+            Value::Raw(value) | Value::Str(value) => Ok(value),
+            _                                     => Err(ConversionError::new("Str", self.type_name())),
+        }
+    }
+
+    /// Returns the content of a `Value::Duration`
+    /// 
+    /// # Return value
+    /// `Ok(value)` if `self` is a `Duration`
+    /// 
+    /// `Err(error)` otherwise, with `error` naming the type found
+    pub fn as_duration(&self) -> Result<Duration, ConversionError> {
+        match self {
+            Value::Duration(value) => Ok(*value),
+            _                      => Err(ConversionError::new("Duration", self.type_name())),
+        }
+    }
+
+    /// Returns the content of a `Value::Size`
+    /// 
+    /// # Return value
+    /// `Ok(value)` if `self` is a `Size`
+    /// 
+    /// `Err(error)` otherwise, with `error` naming the type found
+    pub fn as_size(&self) -> Result<u64, ConversionError> {
+        match self {
+            Value::Size(value) => Ok(*value),
+            _                  => Err(ConversionError::new("Size", self.type_name())),
+        }
+    }
+
+    /// Returns a view of `self` which converts it in a lenient way (see [`Lenient`](struct.Lenient.html "datas::Lenient"))
+    pub fn lenient(&self) -> Lenient<'_> {
+        Lenient {
+            value: self,
+        }
+    }
+}
+
+
+/// A view of a [`Value`](enum.Value.html "datas::Value") which converts it into another type when it is possible without any loss
+/// 
+/// The coercions allowed are
+/// - `Int` into `f64`
+/// - `Float` without fractional part into `i64`
+/// - `Int` `0` and `1` into `bool`
+/// - `Raw` and `Str` into `i64`, `f64` or `bool` if their content is written as such (using the default [`BoolVocabulary`](struct.BoolVocabulary.html "datas::BoolVocabulary"))
+/// - any `Value` into a `String` as it is displayed
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::Value;
+/// 
+/// assert_eq!(Value::Raw(String::from("42")).lenient().as_i64(), Ok(42));
+/// assert_eq!(Value::Int(42).lenient().as_f64(), Ok(42.0));
+/// assert!(Value::Float(4.2).lenient().as_i64().is_err());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Lenient<'a> {
+    value: &'a Value,
+}
+
+impl<'a> Lenient<'a> {
+    /// Converts the value into an `i64`
+    /// 
+    /// # Return value
+    /// `Ok(value)` in case of success
+    /// 
+    /// `Err(error)` if the value can't be coerced into an `i64`
+    pub fn as_i64(self) -> Result<i64, ConversionError> {
+        let converted = match self.value {
+            Value::Int(value)                     => Some(*value),
+            Value::Float(value)                   => float_to_i64(*value),
+            Value::Raw(value) | Value::Str(value) => value.trim().parse().ok(),
+            _                                     => None,
+        };
+
+        converted.ok_or_else(|| ConversionError::new("Int", self.value.type_name()))
+    }
+
+    /// Converts the value into an `f64`
+    /// 
+    /// # Return value
+    /// `Ok(value)` in case of success
+    /// 
+    /// `Err(error)` if the value can't be coerced into an `f64`
+    pub fn as_f64(self) -> Result<f64, ConversionError> {
+        let converted = match self.value {
+            Value::Float(value)                   => Some(*value),
+            Value::Int(value)                     => Some(*value as f64),
+            Value::Raw(value) | Value::Str(value) => value.trim().parse().ok(),
+            _                                     => None,
+        };
+
+        converted.ok_or_else(|| ConversionError::new("Float", self.value.type_name()))
+    }
+
+    /// Converts the value into a `bool`
+    /// 
+    /// # Return value
+    /// `Ok(value)` in case of success
+    /// 
+    /// `Err(error)` if the value can't be coerced into a `bool`
+    pub fn as_bool(self) -> Result<bool, ConversionError> {
+        let converted = match self.value {
+            Value::Bool(value)                    => Some(*value),
+            Value::Int(0)                         => Some(false),
+            Value::Int(1)                         => Some(true),
+            Value::Raw(value) | Value::Str(value) => BoolVocabulary::default().parse(value.trim()),
+            _                                     => None,
+        };
+
+        converted.ok_or_else(|| ConversionError::new("Bool", self.value.type_name()))
+    }
+
+    /// Converts the value into a `String` as it is displayed. Never fails
+    pub fn as_string(self) -> String {
+        format!("{}", self.value)
+    }
+}
+
+/// Converts `value` into an `i64` if it does not have any fractional part and fits in an `i64`
+fn float_to_i64(value: f64) -> Option<i64> {
+    // -2^63 is exactly representable while 2^63 is the first value too big
+    if value.fract() == 0.0 && (-9_223_372_036_854_775_808.0..9_223_372_036_854_775_808.0).contains(&value) {
+        Some(value as i64)
+    } else {
+        None
+    }
+}
+
+
+/// Implements `TryFrom<&Value>` for an integer type, checking the value fits in it
+macro_rules! try_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl TryFrom<&Value> for $int {
+                type Error = ConversionError;
+
+                fn try_from(value: &Value) -> Result<$int, ConversionError> {
+                    let int = value.as_i64()?;
+                    <$int>::try_from(int).map_err(|_| ConversionError::out_of_range(stringify!($int), value.type_name(), int.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+try_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Implements `TryFrom<&Value>` for a type stored as is in a variant of `Value`
+macro_rules! try_from_variant {
+    ($($type:ty => $variant:ident),*) => {
+        $(
+            impl TryFrom<&Value> for $type {
+                type Error = ConversionError;
+
+                fn try_from(value: &Value) -> Result<$type, ConversionError> {
+                    match value {
+                        Value::$variant(content) => Ok(content.clone()),
+                        _                        => Err(ConversionError::new(stringify!($variant), value.type_name())),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+try_from_variant!(f64 => Float, bool => Bool, Duration => Duration, Date => Date, Time => Time, DateTime => DateTime);
+
+impl TryFrom<&Value> for f32 {
+    type Error = ConversionError;
+
+    fn try_from(value: &Value) -> Result<f32, ConversionError> {
+        Ok(value.as_f64()? as f32)
+    }
+}
+
+impl TryFrom<&Value> for String {
+    type Error = ConversionError;
+
+    fn try_from(value: &Value) -> Result<String, ConversionError> {
+        Ok(String::from(value.as_str()?))
+    }
+}
+
+
+/// Implements `From<T> for Value` for a type convertible into the content of a variant of `Value`
+macro_rules! from_type {
+    ($($type:ty => $variant:ident($content:ty)),*) => {
+        $(
+            impl From<$type> for Value {
+                fn from(value: $type) -> Value {
+                    Value::$variant(<$content>::from(value))
+                }
+            }
+        )*
+    };
+}
+
+from_type!(i8       => Int(i64),
+           i16      => Int(i64),
+           i32      => Int(i64),
+           i64      => Int(i64),
+           u8       => Int(i64),
+           u16      => Int(i64),
+           u32      => Int(i64),
+           f32      => Float(f64),
+           f64      => Float(f64),
+           bool     => Bool(bool),
+           String   => Str(String),
+           &str     => Str(String),
+           Duration => Duration(Duration),
+           Date     => Date(Date),
+           Time     => Time(Time),
+           DateTime => DateTime(DateTime));
+
+
+/// Splits a `Raw` or a `Str` value into the items of a comma-separated list, parsing each of them as a standalone value
+/// 
+/// An item between quotes (`'` or `"`) is a `Str` without its quotes. Each item is returned with its text, which is `None` for a variant other than `Raw` and `Str`. An empty string is an empty list while any other variant is a list of a single item
+pub(crate) fn split_list(value: &Value) -> Vec<(Option<&str>, Value)> {
+    match value {
+        Value::Raw(string) | Value::Str(string) if string.trim().is_empty() => Vec::new(),
+        Value::Raw(string) | Value::Str(string)                             => {
+            string.split(',')
+                  .map(|item| item.trim())
+                  .map(|item| (Some(item), parse_list_item(item)))
+                  .collect()
+        },
+        value => vec![(None, value.clone())],
+    }
+}
+
+/// Parses an item of a list split by `split_list`
+fn parse_list_item(item: &str) -> Value {
+    let quoted = item.len() >= 2 && (item.starts_with('\'') && item.ends_with('\'') || item.starts_with('"') && item.ends_with('"'));
+    if quoted {
+        return Value::Str(String::from(&item[1..item.len() - 1]));
+    }
+
+    // The item is escaped again because it has already been unescaped once
+    Value::parse(&dump::dump_str(item)).unwrap_or_else(|_| Value::Raw(String::from(item)))
+}
+
+/// Formats `value` as an item of a comma-separated list, which `split_list` reads back as the same value. A `Str` is written between single quotes so that an item like `'42'` is not read as an `Int`
+/// 
+/// # Return value
+/// `Some(item)` in case of success
+/// 
+/// `None` if the item contains a comma
+pub(crate) fn format_list_item(value: &Value) -> Option<String> {
+    let item = match value {
+        Value::Str(string) => format!("'{}'", string),
+        value              => format!("{}", value),
+    };
+
+    if item.contains(',') {
+        None
+    } else {
+        Some(item)
+    }
+}
+
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,84 @@
+use crate::datas::*;
+use crate::errors::ConversionError;
+use std::convert::TryFrom;
+use std::time::Duration;
+
+#[test]
+fn value_as_ok() {
+    assert_eq!(Value::Int(42).as_i64(), Ok(42));
+    assert_eq!(Value::Float(4.2).as_f64(), Ok(4.2));
+    assert_eq!(Value::Bool(true).as_bool(), Ok(true));
+    assert_eq!(Value::Str(String::from("abc")).as_str(), Ok("abc"));
+    assert_eq!(Value::Raw(String::from("abc")).as_str(), Ok("abc"));
+    assert_eq!(Value::Duration(Duration::from_secs(1)).as_duration(), Ok(Duration::from_secs(1)));
+    assert_eq!(Value::Size(1024).as_size(), Ok(1024));
+}
+
+#[test]
+fn value_as_err() {
+    assert_eq!(Value::Float(4.0).as_i64(), Err(ConversionError::new("Int", "Float")));
+    assert_eq!(Value::Int(4).as_f64(), Err(ConversionError::new("Float", "Int")));
+    assert_eq!(Value::Raw(String::from("on")).as_bool(), Err(ConversionError::new("Bool", "Raw")));
+    assert_eq!(Value::Bool(true).as_str(), Err(ConversionError::new("Str", "Bool")));
+}
+
+#[test]
+fn try_from_int_in_range() {
+    assert_eq!(u8::try_from(&Value::Int(255)), Ok(255));
+    assert_eq!(i32::try_from(&Value::Int(-5)), Ok(-5));
+}
+
+#[test]
+fn try_from_int_out_of_range() {
+    assert_eq!(u8::try_from(&Value::Int(256)), Err(ConversionError::out_of_range("u8", "Int", String::from("256"))));
+    assert_eq!(u64::try_from(&Value::Int(-1)), Err(ConversionError::out_of_range("u64", "Int", String::from("-1"))));
+    assert_eq!(u8::try_from(&Value::Str(String::from("1"))), Err(ConversionError::new("Int", "Str")));
+}
+
+#[test]
+fn try_from_other_types() {
+    assert_eq!(f64::try_from(&Value::Float(0.5)), Ok(0.5));
+    assert_eq!(bool::try_from(&Value::Bool(false)), Ok(false));
+    assert_eq!(String::try_from(&Value::Str(String::from("abc"))), Ok(String::from("abc")));
+    assert_eq!(String::try_from(&Value::Int(1)), Err(ConversionError::new("Str", "Int")));
+    assert_eq!(bool::try_from(&Value::Int(1)), Err(ConversionError::new("Bool", "Int")));
+}
+
+#[test]
+fn from_rust_types() {
+    assert_eq!(Value::from(42u8), Value::Int(42));
+    assert_eq!(Value::from(-42i64), Value::Int(-42));
+    assert_eq!(Value::from(0.5f32), Value::Float(0.5));
+    assert_eq!(Value::from(true), Value::Bool(true));
+    assert_eq!(Value::from("abc"), Value::Str(String::from("abc")));
+    assert_eq!(Value::from(Duration::from_secs(3)), Value::Duration(Duration::from_secs(3)));
+}
+
+#[test]
+fn lenient_as_i64() {
+    assert_eq!(Value::Int(42).lenient().as_i64(), Ok(42));
+    assert_eq!(Value::Float(42.0).lenient().as_i64(), Ok(42));
+    assert_eq!(Value::Raw(String::from(" 42 ")).lenient().as_i64(), Ok(42));
+    assert_eq!(Value::Float(1e19).lenient().as_i64(), Err(ConversionError::new("Int", "Float")));
+    assert_eq!(Value::Raw(String::from("abc")).lenient().as_i64(), Err(ConversionError::new("Int", "Raw")));
+}
+
+#[test]
+fn lenient_as_f64() {
+    assert_eq!(Value::Int(2).lenient().as_f64(), Ok(2.0));
+    assert_eq!(Value::Str(String::from("2.5")).lenient().as_f64(), Ok(2.5));
+    assert_eq!(Value::Bool(true).lenient().as_f64(), Err(ConversionError::new("Float", "Bool")));
+}
+
+#[test]
+fn lenient_as_bool() {
+    assert_eq!(Value::Int(0).lenient().as_bool(), Ok(false));
+    assert_eq!(Value::Raw(String::from("Yes")).lenient().as_bool(), Ok(true));
+    assert_eq!(Value::Int(2).lenient().as_bool(), Err(ConversionError::new("Bool", "Int")));
+}
+
+#[test]
+fn lenient_as_string() {
+    assert_eq!(Value::Int(2).lenient().as_string(), "2");
+    assert_eq!(Value::Bool(false).lenient().as_string(), "off");
+}
@@ -0,0 +1,362 @@
+//! Dates and times of day as defined by the [RFC 3339](https://tools.ietf.org/html/rfc3339 "RFC 3339")
+//! 
+//! # See
+//! `Date` for a calendar date like `2020-04-28`
+//! 
+//! `Time` for a time of day like `12:30:00`
+//! 
+//! `DateTime` for a date and a time of day with an optional offset like `2020-04-28T12:30:00+02:00`
+
+use std::fmt::{self, Display, Formatter};
+
+/// A calendar date in the proleptic Gregorian calendar, written as `YYYY-MM-DD`
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::datetime::Date;
+/// 
+/// let date = Date::parse("2020-02-29").unwrap();
+/// assert_eq!((date.year(), date.month(), date.day()), (2020, 2, 29));
+/// assert_eq!(format!("{}", date), "2020-02-29");
+/// 
+/// assert!(Date::parse("2021-02-29").is_none()); // 2021 is not a leap year
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a new `Date`
+    /// 
+    /// # Panics
+    /// Panics if `year`, `month` and `day` do not form a valid date between the years 0 and 9999
+    pub fn new(year: u16, month: u8, day: u8) -> Date {
+        assert!(year <= 9999, "`year` must be written with four digits");
+        assert!((1..=12).contains(&month), "`month` must be between 1 and 12");
+        assert!(day >= 1 && day <= days_in_month(year, month), "`day` must be a valid day of `month`");
+
+        Date {
+            year,
+            month,
+            day,
+        }
+    }
+
+    /// Reads a date written as `YYYY-MM-DD`
+    /// 
+    /// # Return value
+    /// `Some(date)` if `content` is a valid date
+    /// 
+    /// `None` otherwise
+    pub fn parse(content: &str) -> Option<Date> {
+        let bytes = content.as_bytes();
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return None;
+        }
+
+        let year = parse_digits(&content[..4])? as u16;
+        let month = parse_digits(&content[5..7])? as u8;
+        let day = parse_digits(&content[8..])? as u8;
+
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Date::new(year, month, day))
+    }
+
+    /// Returns the year
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, from 1 to 31
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+
+/// A time of day written as `hh:mm:ss` with an optional fraction of second like `hh:mm:ss.fff`
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::datetime::Time;
+/// 
+/// let time = Time::parse("23:59:07.25").unwrap();
+/// assert_eq!((time.hour(), time.minute(), time.second()), (23, 59, 7));
+/// assert_eq!(time.nanosecond(), 250_000_000);
+/// assert_eq!(format!("{}", time), "23:59:07.25");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+impl Time {
+    /// Creates a new `Time`
+    /// 
+    /// # Panics
+    /// Panics if `hour`, `minute`, `second` or `nanosecond` is out of range. A `second` of 60 is allowed for leap seconds
+    pub fn new(hour: u8, minute: u8, second: u8, nanosecond: u32) -> Time {
+        assert!(hour < 24, "`hour` must be lower than 24");
+        assert!(minute < 60, "`minute` must be lower than 60");
+        assert!(second <= 60, "`second` must be lower than or equal to 60");
+        assert!(nanosecond < 1_000_000_000, "`nanosecond` must be lower than one second");
+
+        Time {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        }
+    }
+
+    /// Reads a time of day written as `hh:mm:ss` or `hh:mm:ss.fff` with at most nine digits after the point
+    /// 
+    /// # Return value
+    /// `Some(time)` if `content` is a valid time of day
+    /// 
+    /// `None` otherwise
+    pub fn parse(content: &str) -> Option<Time> {
+        let bytes = content.as_bytes();
+        if bytes.len() < 8 || bytes[2] != b':' || bytes[5] != b':' || !content.is_char_boundary(8) {
+            return None;
+        }
+
+        let hour = parse_digits(&content[..2])? as u8;
+        let minute = parse_digits(&content[3..5])? as u8;
+        let second = parse_digits(&content[6..8])? as u8;
+
+        let nanosecond = match &content[8..] {
+            ""       => 0,
+            fraction => {
+                let digits = fraction.strip_prefix('.')?;
+                if digits.is_empty() || digits.len() > 9 {
+                    return None;
+                }
+
+                parse_digits(digits)? * 10u32.pow(9 - digits.len() as u32)
+            },
+        };
+
+        if hour >= 24 || minute >= 60 || second > 60 {
+            return None;
+        }
+
+        Some(Time::new(hour, minute, second, nanosecond))
+    }
+
+    /// Returns the hour, from 0 to 23
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute, from 0 to 59
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second, from 0 to 60 (for leap seconds)
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the fraction of second as a number of nanoseconds
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+}
+
+impl Display for Time {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+
+        if self.nanosecond != 0 {
+            let fraction = format!("{:09}", self.nanosecond);
+            write!(formatter, ".{}", fraction.trim_end_matches('0'))?;
+        }
+
+        Ok(())
+    }
+}
+
+
+/// A date and a time of day, separated by a `T` (or a space when parsing), optionally followed by an offset from UTC which is either `Z` or `+hh:mm` / `-hh:mm`
+/// 
+/// A `DateTime` without offset is a local date and time
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::datetime::DateTime;
+/// 
+/// let expiry = DateTime::parse("2020-04-28T12:30:00+02:00").unwrap();
+/// assert_eq!(expiry.offset(), Some(120));
+/// assert_eq!(format!("{}", expiry), "2020-04-28T12:30:00+02:00");
+/// 
+/// let local = DateTime::parse("2020-04-28 12:30:00").unwrap();
+/// assert_eq!(local.offset(), None);
+/// assert_eq!(format!("{}", local), "2020-04-28T12:30:00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    date: Date,
+    time: Time,
+    offset: Option<i16>,
+}
+
+impl DateTime {
+    /// Creates a new `DateTime`
+    /// 
+    /// # Parameters
+    /// `date` the calendar date
+    /// 
+    /// `time` the time of day
+    /// 
+    /// `offset` the offset from UTC in minutes or `None` for a local date and time
+    /// 
+    /// # Panics
+    /// Panics if `offset` is not strictly between -24 and +24 hours
+    pub fn new(date: Date, time: Time, offset: Option<i16>) -> DateTime {
+        if let Some(offset) = offset {
+            assert!(offset.abs() < 24 * 60, "`offset` must be strictly between -24 and +24 hours");
+        }
+
+        DateTime {
+            date,
+            time,
+            offset,
+        }
+    }
+
+    /// Reads a date and a time of day as defined in the RFC 3339. The offset is optional
+    /// 
+    /// # Return value
+    /// `Some(datetime)` if `content` is a valid date and time
+    /// 
+    /// `None` otherwise
+    pub fn parse(content: &str) -> Option<DateTime> {
+        if content.len() < 19 || !content.is_char_boundary(10) {
+            return None;
+        }
+
+        let date = Date::parse(&content[..10])?;
+        let rest = match content[10..].chars().next() {
+            Some('T') | Some('t') | Some(' ') => &content[11..],
+            _                                 => return None,
+        };
+
+        let (time, offset) = if let Some(time) = rest.strip_suffix('Z').or_else(|| rest.strip_suffix('z')) {
+            (time, Some(0))
+        } else if rest.len() > 6 && rest.is_char_boundary(rest.len() - 6) {
+            let (time, offset) = rest.split_at(rest.len() - 6);
+            match parse_offset(offset) {
+                Some(offset) => (time, Some(offset)),
+                None         => (rest, None),
+            }
+        } else {
+            (rest, None)
+        };
+
+        Some(DateTime::new(date, Time::parse(time)?, offset))
+    }
+
+    /// Returns the calendar date
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Returns the time of day
+    pub fn time(&self) -> Time {
+        self.time
+    }
+
+    /// Returns the offset from UTC in minutes or `None` for a local date and time
+    pub fn offset(&self) -> Option<i16> {
+        self.offset
+    }
+}
+
+impl Display for DateTime {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{}T{}", self.date, self.time)?;
+
+        match self.offset {
+            None         => Ok(()),
+            Some(0)      => formatter.write_str("Z"),
+            Some(offset) => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.abs();
+                write!(formatter, "{}{:02}:{:02}", sign, offset / 60, offset % 60)
+            },
+        }
+    }
+}
+
+
+/// Returns `true` if `year` is a leap year in the Gregorian calendar
+fn is_leap_year(year: u16) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days in the `month`th month of `year`
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2                       => 28,
+        4 | 6 | 9 | 11          => 30,
+        _                       => 31,
+    }
+}
+
+/// Reads a non-empty string of ASCII digits. Returns `None` if any other character is found
+fn parse_digits(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    digits.parse().ok()
+}
+
+/// Reads an offset written as `+hh:mm` or `-hh:mm` and returns it in minutes
+fn parse_offset(offset: &str) -> Option<i16> {
+    let bytes = offset.as_bytes();
+    if bytes.len() != 6 || bytes[3] != b':' {
+        return None;
+    }
+
+    let sign = match bytes[0] {
+        b'+' => 1,
+        b'-' => -1,
+        _    => return None,
+    };
+    let hours = parse_digits(&offset[1..3])? as i16;
+    let minutes = parse_digits(&offset[4..])? as i16;
+
+    if hours >= 24 || minutes >= 60 {
+        return None;
+    }
+
+    Some(sign * (hours * 60 + minutes))
+}
+
+
+#[cfg(test)]
+mod tests;
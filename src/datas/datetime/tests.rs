@@ -0,0 +1,80 @@
+use crate::datas::datetime::*;
+
+#[test]
+fn date_parse_ok() {
+    assert_eq!(Date::parse("2020-04-28"), Some(Date::new(2020, 4, 28)));
+    assert_eq!(Date::parse("2000-02-29"), Some(Date::new(2000, 2, 29)));
+}
+
+#[test]
+fn date_parse_invalid() {
+    assert_eq!(Date::parse("1900-02-29"), None);
+    assert_eq!(Date::parse("2020-13-01"), None);
+    assert_eq!(Date::parse("2020-04-31"), None);
+    assert_eq!(Date::parse("2020-00-10"), None);
+    assert_eq!(Date::parse("20-04-28"), None);
+    assert_eq!(Date::parse("2020/04/28"), None);
+    assert_eq!(Date::parse("+020-04-28"), None);
+}
+
+#[test]
+#[should_panic]
+fn date_new_panics() {
+    let _date = Date::new(2019, 2, 29);
+}
+
+#[test]
+fn date_format() {
+    assert_eq!(format!("{}", Date::new(33, 1, 2)), "0033-01-02");
+}
+
+#[test]
+fn time_parse_ok() {
+    assert_eq!(Time::parse("00:00:00"), Some(Time::new(0, 0, 0, 0)));
+    assert_eq!(Time::parse("23:59:60"), Some(Time::new(23, 59, 60, 0)));
+    assert_eq!(Time::parse("12:30:15.000000001"), Some(Time::new(12, 30, 15, 1)));
+}
+
+#[test]
+fn time_parse_invalid() {
+    assert_eq!(Time::parse("24:00:00"), None);
+    assert_eq!(Time::parse("12:60:00"), None);
+    assert_eq!(Time::parse("12:30"), None);
+    assert_eq!(Time::parse("12:30:00."), None);
+    assert_eq!(Time::parse("12:30:00.1234567891"), None);
+    assert_eq!(Time::parse("12:30:0\u{263a}"), None);
+}
+
+#[test]
+fn time_format() {
+    assert_eq!(format!("{}", Time::new(1, 2, 3, 0)), "01:02:03");
+    assert_eq!(format!("{}", Time::new(1, 2, 3, 120_000)), "01:02:03.00012");
+}
+
+#[test]
+fn datetime_parse_offsets() {
+    let date = Date::new(2020, 4, 28);
+    let time = Time::new(12, 30, 0, 0);
+
+    assert_eq!(DateTime::parse("2020-04-28T12:30:00"), Some(DateTime::new(date, time, None)));
+    assert_eq!(DateTime::parse("2020-04-28T12:30:00Z"), Some(DateTime::new(date, time, Some(0))));
+    assert_eq!(DateTime::parse("2020-04-28t12:30:00z"), Some(DateTime::new(date, time, Some(0))));
+    assert_eq!(DateTime::parse("2020-04-28T12:30:00+05:30"), Some(DateTime::new(date, time, Some(330))));
+    assert_eq!(DateTime::parse("2020-04-28T12:30:00-01:00"), Some(DateTime::new(date, time, Some(-60))));
+}
+
+#[test]
+fn datetime_parse_invalid() {
+    assert_eq!(DateTime::parse("2020-04-28"), None);
+    assert_eq!(DateTime::parse("2020-04-28_12:30:00"), None);
+    assert_eq!(DateTime::parse("2020-04-28T12:30:00+24:00"), None);
+    assert_eq!(DateTime::parse("2020-04-28T12:30:00+0200"), None);
+    assert_eq!(DateTime::parse("2020-04-28\u{263a}12:30:00"), None);
+}
+
+#[test]
+fn datetime_format_round_trip() {
+    for i in &["2020-04-28T12:30:00", "2020-04-28T12:30:00Z", "2020-04-28T12:30:00.5-09:30"] {
+        assert_eq!(format!("{}", DateTime::parse(i).unwrap()), *i);
+    }
+}
@@ -0,0 +1,423 @@
+//! The data of a file parsed with [`Dialect::DesktopEntry`](../../parse/enum.Dialect.html "parse::Dialect"), like a `.desktop` file, where a key may be localized like `Name[fr]`
+//! 
+//! # See
+//! `DesktopKey` for the identity of a key, `Locale` for its locale and `DesktopEntry` to look up its values
+
+use std::fmt::{self, Display, Formatter};
+
+/// A locale of the form `lang_COUNTRY.ENCODING@MODIFIER`, where `_COUNTRY`, `.ENCODING` and `@MODIFIER` are optional
+/// 
+/// The encoding is kept but ignored when matching a locale, as required by the Desktop Entry Specification
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::desktop::Locale;
+/// 
+/// let locale = Locale::parse("de_DE.UTF-8@euro").unwrap();
+/// 
+/// assert_eq!(locale.lang(), "de");
+/// assert_eq!(locale.country(), Some("DE"));
+/// assert_eq!(locale.encoding(), Some("UTF-8"));
+/// assert_eq!(locale.modifier(), Some("euro"));
+/// assert_eq!(format!("{}", locale), "de_DE.UTF-8@euro");
+/// 
+/// assert!(Locale::parse("de_").is_none());
+/// assert!(Locale::parse("d e").is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    lang: String,
+    country: Option<String>,
+    encoding: Option<String>,
+    modifier: Option<String>,
+}
+
+impl Locale {
+    /// Reads a locale of the form `lang_COUNTRY.ENCODING@MODIFIER`
+    /// 
+    /// # Return value
+    /// `Some(locale)` if `text` is a valid locale, whose parts are made of latin alphanumeric characters and `-`
+    /// 
+    /// `None` otherwise
+    pub fn parse(text: &str) -> Option<Locale> {
+        let (text, modifier) = match text.find('@') {
+            Some(index) => (&text[..index], Some(&text[index + 1..])),
+            None        => (text, None),
+        };
+        let (text, encoding) = match text.find('.') {
+            Some(index) => (&text[..index], Some(&text[index + 1..])),
+            None        => (text, None),
+        };
+        let (lang, country) = match text.find('_') {
+            Some(index) => (&text[..index], Some(&text[index + 1..])),
+            None        => (text, None),
+        };
+
+        let is_valid = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+        if !is_valid(lang) || !country.is_none_or(is_valid) || !encoding.is_none_or(is_valid) || !modifier.is_none_or(is_valid) {
+            return None;
+        }
+
+        Some(Locale {
+            lang: String::from(lang),
+            country: country.map(String::from),
+            encoding: encoding.map(String::from),
+            modifier: modifier.map(String::from),
+        })
+    }
+
+    /// Returns the language of the locale
+    pub fn lang(&self) -> &str {
+        &self.lang
+    }
+
+    /// Returns the country of the locale if any
+    pub fn country(&self) -> Option<&str> {
+        self.country.as_deref()
+    }
+
+    /// Returns the encoding of the locale if any
+    pub fn encoding(&self) -> Option<&str> {
+        self.encoding.as_deref()
+    }
+
+    /// Returns the modifier of the locale if any
+    pub fn modifier(&self) -> Option<&str> {
+        self.modifier.as_deref()
+    }
+
+    /// Returns `true` if `self` and `other` are the same locale, ignoring their encoding
+    fn matches(&self, other: &Locale) -> bool {
+        self.lang == other.lang && self.country == other.country && self.modifier == other.modifier
+    }
+
+    /// Returns the locales to look up for `self`, from the most specific to the least specific one
+    /// 
+    /// For `lang_COUNTRY@MODIFIER`, they are `lang_COUNTRY@MODIFIER`, `lang_COUNTRY`, `lang@MODIFIER` and `lang`
+    fn fallbacks(&self) -> Vec<Locale> {
+        let locale = |country: &Option<String>, modifier: &Option<String>| Locale {
+            lang: self.lang.clone(),
+            country: country.clone(),
+            encoding: None,
+            modifier: modifier.clone(),
+        };
+
+        let mut fallbacks = Vec::with_capacity(4);
+        if self.country.is_some() && self.modifier.is_some() {
+            fallbacks.push(locale(&self.country, &self.modifier));
+        }
+        if self.country.is_some() {
+            fallbacks.push(locale(&self.country, &None));
+        }
+        if self.modifier.is_some() {
+            fallbacks.push(locale(&None, &self.modifier));
+        }
+        fallbacks.push(locale(&None, &None));
+
+        fallbacks
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str(&self.lang)?;
+        if let Some(country) = &self.country {
+            write!(formatter, "_{}", country)?;
+        }
+        if let Some(encoding) = &self.encoding {
+            write!(formatter, ".{}", encoding)?;
+        }
+        if let Some(modifier) = &self.modifier {
+            write!(formatter, "@{}", modifier)?;
+        }
+
+        Ok(())
+    }
+}
+
+
+/// The identifier of a variable of a Desktop Entry file, made of a group, a key and an optional locale
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::desktop::{DesktopKey, Locale};
+/// 
+/// let key = DesktopKey::new(String::from("Desktop Entry"), String::from("Name"), Locale::parse("fr"));
+/// 
+/// assert_eq!(key.group(), "Desktop Entry");
+/// assert_eq!(key.key(), "Name");
+/// assert_eq!(key.locale().map(|locale| locale.lang()), Some("fr"));
+/// assert_eq!(format!("{}", key), "Desktop Entry.Name[fr]");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DesktopKey {
+    group: String,
+    key: String,
+    locale: Option<Locale>,
+}
+
+impl DesktopKey {
+    /// Creates an identifier with a valid group name, a valid key and an optional locale
+    /// 
+    /// # Panics
+    /// Panics if `group` is invalid according to [`DesktopKey::is_valid_group`](struct.DesktopKey.html#method.is_valid_group "datas::desktop::DesktopKey::is_valid_group") or if `key` is invalid according to [`DesktopKey::is_valid_key`](struct.DesktopKey.html#method.is_valid_key "datas::desktop::DesktopKey::is_valid_key")
+    pub fn new(group: String, key: String, locale: Option<Locale>) -> DesktopKey {
+        assert!(DesktopKey::is_valid_group(&group));
+        assert!(DesktopKey::is_valid_key(&key));
+
+        DesktopKey {
+            group,
+            key,
+            locale,
+        }
+    }
+
+    /// Returns `true` if the given string is a valid group name and `false` otherwise
+    /// 
+    /// A valid group name is a non-empty string of printable ASCII characters except `[` and `]`
+    /// 
+    /// # Examples
+    /// ```
+    /// use mininip::datas::desktop::DesktopKey;
+    /// 
+    /// assert!(DesktopKey::is_valid_group("Desktop Entry"));
+    /// assert!(DesktopKey::is_valid_group("Desktop Action new-window"));
+    /// assert!(!DesktopKey::is_valid_group("[nested]"));
+    /// assert!(!DesktopKey::is_valid_group("Entrée"));
+    /// ```
+    pub fn is_valid_group(group: &str) -> bool {
+        !group.is_empty() && group.chars().all(|c| (c.is_ascii_graphic() || c == ' ') && c != '[' && c != ']')
+    }
+
+    /// Returns `true` if the given string is a valid key and `false` otherwise
+    /// 
+    /// A valid key is a non-empty string of latin alphanumeric characters and `-`
+    /// 
+    /// # Examples
+    /// ```
+    /// use mininip::datas::desktop::DesktopKey;
+    /// 
+    /// assert!(DesktopKey::is_valid_key("Name"));
+    /// assert!(DesktopKey::is_valid_key("X-GNOME-Autostart-enabled"));
+    /// assert!(!DesktopKey::is_valid_key("Name[fr]"));
+    /// assert!(!DesktopKey::is_valid_key("snake_case"));
+    /// ```
+    pub fn is_valid_key(key: &str) -> bool {
+        !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    }
+
+    /// Returns the name of the group
+    pub fn group(&self) -> &str {
+        &self.group
+    }
+
+    /// Returns the name of the key, without its locale
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the locale of the key if it is localized
+    pub fn locale(&self) -> Option<&Locale> {
+        self.locale.as_ref()
+    }
+}
+
+impl Display for DesktopKey {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{}.{}", self.group, self.key)?;
+        if let Some(locale) = &self.locale {
+            write!(formatter, "[{}]", locale)?;
+        }
+
+        Ok(())
+    }
+}
+
+
+/// The variables of a Desktop Entry file in the order they were declared, with their values as written
+/// 
+/// The values are unescaped on lookup since the escape `\;` only means something in a list
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::desktop::Locale;
+/// use mininip::parse::{Dialect, Parser};
+/// 
+/// let mut parser = Parser::with_dialect(Dialect::DesktopEntry);
+/// parser.parse_content("[Desktop Entry]\nName=Files\nName[fr]=Fichiers\nName[de_DE]=Dateien\nKeywords=folder;manager\\;explorer;\nTerminal=false\n").unwrap();
+/// let entry = parser.desktop_entry();
+/// 
+/// assert_eq!(entry.get("Desktop Entry", "Name"), Some(String::from("Files")));
+/// assert_eq!(entry.get_localized("Desktop Entry", "Name", &Locale::parse("fr_CA.UTF-8").unwrap()), Some(String::from("Fichiers")));
+/// assert_eq!(entry.get_localized("Desktop Entry", "Name", &Locale::parse("de").unwrap()), Some(String::from("Files")));
+/// assert_eq!(entry.get_list("Desktop Entry", "Keywords"), Some(vec![String::from("folder"), String::from("manager;explorer")]));
+/// assert_eq!(entry.get_bool("Desktop Entry", "Terminal"), Some(false));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DesktopEntry {
+    entries: Vec<(DesktopKey, String)>,
+}
+
+impl DesktopEntry {
+    /// Creates an empty `DesktopEntry`
+    pub fn new() -> DesktopEntry {
+        DesktopEntry {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns every variable in the order they were declared, with their values as written
+    pub fn entries(&self) -> &[(DesktopKey, String)] {
+        &self.entries
+    }
+
+    /// Consumes `self` and returns every variable in the order they were declared, with their values as written
+    pub fn into_entries(self) -> Vec<(DesktopKey, String)> {
+        self.entries
+    }
+
+    /// Returns the names of the groups in the order of their first variable
+    pub fn groups(&self) -> Vec<&str> {
+        let mut groups = Vec::new();
+        for (key, _) in &self.entries {
+            if !groups.contains(&key.group()) {
+                groups.push(key.group());
+            }
+        }
+
+        groups
+    }
+
+    /// Changes the value of `key` to `value`, which must be written with its escape sequences. A new key is added after the other ones
+    pub fn insert(&mut self, key: DesktopKey, value: String) {
+        match self.entries.iter_mut().find(|(i, _)| *i == key) {
+            Some((_, old)) => *old = value,
+            None           => self.entries.push((key, value)),
+        }
+    }
+
+    /// Returns the value of `key` in `group` for exactly `locale` as written, with its escape sequences
+    pub fn get_raw(&self, group: &str, key: &str, locale: Option<&Locale>) -> Option<&str> {
+        self.entries.iter()
+                    .find(|(i, _)| i.group == group && i.key == key && match (&i.locale, locale) {
+                        (Some(a), Some(b)) => a.matches(b),
+                        (None, None)       => true,
+                        _                  => false,
+                    })
+                    .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the value of `key` in `group` for the best match of `locale`, as written
+    /// 
+    /// For `lang_COUNTRY@MODIFIER`, the locales looked up are `lang_COUNTRY@MODIFIER`, `lang_COUNTRY`, `lang@MODIFIER`, `lang` and finally the value which is not localized
+    fn get_raw_localized(&self, group: &str, key: &str, locale: &Locale) -> Option<&str> {
+        locale.fallbacks()
+              .iter()
+              .find_map(|locale| self.get_raw(group, key, Some(locale)))
+              .or_else(|| self.get_raw(group, key, None))
+    }
+
+    /// Returns the value of `key` in `group` which is not localized, unescaped
+    pub fn get(&self, group: &str, key: &str) -> Option<String> {
+        self.get_raw(group, key, None).map(unescape)
+    }
+
+    /// Returns the value of `key` in `group` for the best match of `locale`, unescaped
+    /// 
+    /// For `lang_COUNTRY@MODIFIER`, the locales looked up are `lang_COUNTRY@MODIFIER`, `lang_COUNTRY`, `lang@MODIFIER`, `lang` and finally the value which is not localized. The encoding of `locale` is ignored
+    pub fn get_localized(&self, group: &str, key: &str, locale: &Locale) -> Option<String> {
+        self.get_raw_localized(group, key, locale).map(unescape)
+    }
+
+    /// Returns the value of `key` in `group` which is not localized as a boolean
+    /// 
+    /// # Return value
+    /// `Some(value)` if the value is `true` or `false`
+    /// 
+    /// `None` if `key` is not defined or is not a boolean
+    pub fn get_bool(&self, group: &str, key: &str) -> Option<bool> {
+        match self.get_raw(group, key, None) {
+            Some("true")  => Some(true),
+            Some("false") => Some(false),
+            _             => None,
+        }
+    }
+
+    /// Returns the value of `key` in `group` which is not localized as a list of strings separated by `;`, where `\;` is a semicolon in a string
+    pub fn get_list(&self, group: &str, key: &str) -> Option<Vec<String>> {
+        self.get_raw(group, key, None).map(split_list)
+    }
+
+    /// Returns the value of `key` in `group` for the best match of `locale` as a list of strings separated by `;`
+    /// 
+    /// # See
+    /// [`get_localized`](struct.DesktopEntry.html#method.get_localized "datas::desktop::DesktopEntry::get_localized") for the locales looked up
+    pub fn get_localized_list(&self, group: &str, key: &str, locale: &Locale) -> Option<Vec<String>> {
+        self.get_raw_localized(group, key, locale).map(split_list)
+    }
+}
+
+/// Returns the index of the first escape sequence of `value` which is not allowed by the Desktop Entry Specification, which are `\s`, `\n`, `\t`, `\r`, `\\` and `\;`
+pub(crate) fn find_invalid_escape(value: &str) -> Option<usize> {
+    let mut iter = value.char_indices();
+    while let Some((n, c)) = iter.next() {
+        if c == '\\' {
+            match iter.next() {
+                Some((_, 's')) | Some((_, 'n')) | Some((_, 't')) | Some((_, 'r')) | Some((_, '\\')) | Some((_, ';')) => {},
+                _                                                                                                  => return Some(n),
+            }
+        }
+    }
+
+    None
+}
+
+/// Replaces the escape sequences of `value` by the characters they stand for
+fn unescape(value: &str) -> String {
+    let mut new = String::with_capacity(value.len());
+    let mut iter = value.chars();
+    while let Some(c) = iter.next() {
+        if c != '\\' {
+            new.push(c);
+            continue;
+        }
+
+        match iter.next() {
+            Some('s')   => new.push(' '),
+            Some('n')   => new.push('\n'),
+            Some('t')   => new.push('\t'),
+            Some('r')   => new.push('\r'),
+            Some(other) => new.push(other),
+            None        => new.push('\\'),
+        }
+    }
+
+    new
+}
+
+/// Splits `value` at each `;` which is not escaped and unescapes each string. The last `;` is optional
+fn split_list(value: &str) -> Vec<String> {
+    let mut list = Vec::new();
+    let mut begin = 0;
+    let mut escaped = false;
+    for (n, c) in value.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == ';' {
+            list.push(unescape(&value[begin..n]));
+            begin = n + 1;
+        }
+    }
+
+    if begin < value.len() {
+        list.push(unescape(&value[begin..]));
+    }
+
+    list
+}
+
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,111 @@
+use crate::datas::desktop::*;
+
+fn locale(text: &str) -> Locale {
+    Locale::parse(text).unwrap()
+}
+
+fn entry() -> DesktopEntry {
+    let mut entry = DesktopEntry::new();
+    let group = String::from("Desktop Entry");
+    let mut insert = |key: &str, locale: Option<&str>, value: &str| {
+        entry.insert(DesktopKey::new(group.clone(), String::from(key), locale.map(self::locale)), String::from(value));
+    };
+
+    insert("Name", None, "Default");
+    insert("Name", Some("sr"), "sr");
+    insert("Name", Some("sr_YU"), "sr_YU");
+    insert("Name", Some("sr@Latn"), "sr@Latn");
+    insert("Name", Some("sr_YU@Latn"), "sr_YU@Latn");
+    insert("Comment", Some("fr.UTF-8"), "Commentaire");
+
+    entry
+}
+
+#[test]
+fn locale_parse() {
+    let full = locale("sr_YU.UTF-8@Latn");
+    assert_eq!((full.lang(), full.country(), full.encoding(), full.modifier()), ("sr", Some("YU"), Some("UTF-8"), Some("Latn")));
+
+    let short = locale("fr");
+    assert_eq!((short.lang(), short.country(), short.encoding(), short.modifier()), ("fr", None, None, None));
+
+    for text in &["", "_FR", "fr_", "fr.", "fr@", "fr FR", "fr_FR_FR", "fé"] {
+        assert!(Locale::parse(text).is_none(), "{:?} should not be accepted", text);
+    }
+}
+
+#[test]
+fn desktop_entry_fallbacks() {
+    let entry = entry();
+    let name = |text: &str| entry.get_localized("Desktop Entry", "Name", &locale(text));
+
+    assert_eq!(name("sr_YU@Latn"), Some(String::from("sr_YU@Latn")));
+    assert_eq!(name("sr_YU.UTF-8@Latn"), Some(String::from("sr_YU@Latn")));
+    assert_eq!(name("sr_YU"), Some(String::from("sr_YU")));
+    assert_eq!(name("sr_CS@Latn"), Some(String::from("sr@Latn")));
+    assert_eq!(name("sr_CS"), Some(String::from("sr")));
+    assert_eq!(name("sr@Cyrl"), Some(String::from("sr")));
+    assert_eq!(name("fr_FR"), Some(String::from("Default")));
+}
+
+#[test]
+fn desktop_entry_locale_encoding_ignored() {
+    let entry = entry();
+
+    assert_eq!(entry.get_localized("Desktop Entry", "Comment", &locale("fr_FR")), Some(String::from("Commentaire")));
+    assert_eq!(entry.get_raw("Desktop Entry", "Comment", Some(&locale("fr.ISO-8859-1"))), Some("Commentaire"));
+    assert_eq!(entry.get("Desktop Entry", "Comment"), None);
+}
+
+#[test]
+fn desktop_entry_unescape() {
+    let mut entry = DesktopEntry::new();
+    let key = DesktopKey::new(String::from("A"), String::from("Exec"), None);
+    entry.insert(key, String::from(r"\s lead\ttab\nline\r\\ and \;"));
+
+    assert_eq!(entry.get("A", "Exec"), Some(String::from("  lead\ttab\nline\r\\ and ;")));
+}
+
+#[test]
+fn desktop_entry_lists() {
+    let mut entry = DesktopEntry::new();
+    let mut insert = |key: &str, value: &str| entry.insert(DesktopKey::new(String::from("A"), String::from(key), None), String::from(value));
+    insert("Trailing", "a;b;");
+    insert("NoTrailing", "a;b");
+    insert("Escaped", r"a\;b;c\\;d\s");
+    insert("Empty", "");
+    insert("EmptyItems", ";;a");
+
+    let list = |key: &str| entry.get_list("A", key).unwrap();
+    assert_eq!(list("Trailing"), vec!["a", "b"]);
+    assert_eq!(list("NoTrailing"), vec!["a", "b"]);
+    assert_eq!(list("Escaped"), vec!["a;b", "c\\", "d "]);
+    assert!(list("Empty").is_empty());
+    assert_eq!(list("EmptyItems"), vec!["", "", "a"]);
+    assert_eq!(entry.get_list("A", "Missing"), None);
+}
+
+#[test]
+fn desktop_entry_insert_replaces() {
+    let mut entry = DesktopEntry::new();
+    let key = |group: &str, name: &str| DesktopKey::new(String::from(group), String::from(name), None);
+    entry.insert(key("A", "x"), String::from("1"));
+    entry.insert(key("B", "y"), String::from("2"));
+    entry.insert(key("A", "x"), String::from("3"));
+
+    assert_eq!(entry.groups(), vec!["A", "B"]);
+    assert_eq!(entry.into_entries(), vec![(key("A", "x"), String::from("3")), (key("B", "y"), String::from("2"))]);
+}
+
+#[test]
+fn desktop_entry_bool() {
+    let mut entry = DesktopEntry::new();
+    let key = |name: &str| DesktopKey::new(String::from("A"), String::from(name), None);
+    entry.insert(key("Yes"), String::from("true"));
+    entry.insert(key("No"), String::from("false"));
+    entry.insert(key("Other"), String::from("1"));
+
+    assert_eq!(entry.get_bool("A", "Yes"), Some(true));
+    assert_eq!(entry.get_bool("A", "No"), Some(false));
+    assert_eq!(entry.get_bool("A", "Other"), None);
+}
@@ -0,0 +1,192 @@
+//! The encodings of the INI files, which are detected from their byte order mark when they are read and chosen when they are written
+//! 
+//! # See
+//! [`Parser::parse_bytes`](../../parse/struct.Parser.html#method.parse_bytes "parse::Parser::parse_bytes") and [`Dumper::generate_bytes`](../../dump/struct.Dumper.html#method.generate_bytes "dump::Dumper::generate_bytes")
+
+use crate::errors::EncodingError;
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+
+/// The characters of Windows-1252 from `0x80` to `0x9F`, where it differs from Latin-1. The bytes `0x81`, `0x8D`, `0x8F`, `0x90` and `0x9D` are not defined
+const WINDOWS_1252: [Option<char>; 32] = [
+    Some('\u{20ac}'), None,             Some('\u{201a}'), Some('\u{0192}'), Some('\u{201e}'), Some('\u{2026}'), Some('\u{2020}'), Some('\u{2021}'),
+    Some('\u{02c6}'), Some('\u{2030}'), Some('\u{0160}'), Some('\u{2039}'), Some('\u{0152}'), None,             Some('\u{017d}'), None,
+    None,             Some('\u{2018}'), Some('\u{2019}'), Some('\u{201c}'), Some('\u{201d}'), Some('\u{2022}'), Some('\u{2013}'), Some('\u{2014}'),
+    Some('\u{02dc}'), Some('\u{2122}'), Some('\u{0161}'), Some('\u{203a}'), Some('\u{0153}'), None,             Some('\u{017e}'), Some('\u{0178}'),
+];
+
+/// The encoding of an INI file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Encoding {
+    /// UTF-8, the default one
+    #[default]
+    Utf8,
+    /// UTF-16 little endian, whose byte order mark is `FF FE`
+    Utf16Le,
+    /// UTF-16 big endian, whose byte order mark is `FE FF`
+    Utf16Be,
+    /// ISO-8859-1, where each byte is the character of the same code. It has no byte order mark
+    Latin1,
+    /// The ANSI code page of Western Europe on Windows, which is Latin-1 with printable characters from `0x80` to `0x9F`. It has no byte order mark
+    Windows1252,
+}
+
+impl Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Encoding::Utf8        => "UTF-8",
+            Encoding::Utf16Le     => "UTF-16LE",
+            Encoding::Utf16Be     => "UTF-16BE",
+            Encoding::Latin1      => "Latin-1",
+            Encoding::Windows1252 => "Windows-1252",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl Encoding {
+    /// Detects the encoding of `bytes` from its byte order mark
+    /// 
+    /// # Return value
+    /// `Some((encoding, length))` with the encoding detected and the length of the byte order mark
+    /// 
+    /// `None` if `bytes` does not start with a byte order mark
+    pub fn detect(bytes: &[u8]) -> Option<(Encoding, usize)> {
+        match bytes {
+            [0xEF, 0xBB, 0xBF, ..] => Some((Encoding::Utf8, 3)),
+            [0xFF, 0xFE, ..]       => Some((Encoding::Utf16Le, 2)),
+            [0xFE, 0xFF, ..]       => Some((Encoding::Utf16Be, 2)),
+            _                      => None,
+        }
+    }
+
+    /// Returns the byte order mark of this encoding, which is empty for Latin-1 and Windows-1252
+    pub fn bom(self) -> &'static [u8] {
+        match self {
+            Encoding::Utf8        => &[0xEF, 0xBB, 0xBF],
+            Encoding::Utf16Le     => &[0xFF, 0xFE],
+            Encoding::Utf16Be     => &[0xFE, 0xFF],
+            Encoding::Latin1
+            | Encoding::Windows1252 => &[],
+        }
+    }
+
+    /// Decodes `bytes`, which must not start with a byte order mark
+    /// 
+    /// # Return value
+    /// `Ok(text)` in case of success
+    /// 
+    /// `Err(error)` if `bytes` is not valid in this encoding, with the offset of the first invalid byte
+    pub fn decode(self, bytes: &[u8]) -> Result<String, EncodingError> {
+        let from_utf16 = |unit: fn([u8; 2]) -> u16| {
+            let units = bytes.chunks(2).map(|pair| match pair {
+                [first, second] => Some(unit([*first, *second])),
+                _               => None,
+            });
+
+            let mut text = String::with_capacity(bytes.len() / 2);
+            let mut offset = 0;
+            for c in char::decode_utf16(units.map_while(|unit| unit)) {
+                match c {
+                    Ok(c)  => {
+                        text.push(c);
+                        offset += c.len_utf16() * 2;
+                    },
+                    Err(_) => return Err(EncodingError::new(self, offset, None)),
+                }
+            }
+
+            if offset < bytes.len() {
+                return Err(EncodingError::new(self, offset, None));
+            }
+            Ok(text)
+        };
+
+        match self {
+            Encoding::Utf8        => match std::str::from_utf8(bytes) {
+                Ok(text) => Ok(String::from(text)),
+                Err(err) => Err(EncodingError::new(self, err.valid_up_to(), None)),
+            },
+            Encoding::Utf16Le     => from_utf16(u16::from_le_bytes),
+            Encoding::Utf16Be     => from_utf16(u16::from_be_bytes),
+            Encoding::Latin1      => Ok(bytes.iter().map(|&byte| char::from(byte)).collect()),
+            Encoding::Windows1252 => bytes.iter()
+                                          .enumerate()
+                                          .map(|(offset, &byte)| match byte {
+                                              0x80..=0x9F => WINDOWS_1252[usize::from(byte - 0x80)].ok_or(EncodingError::new(self, offset, None)),
+                                              byte        => Ok(char::from(byte)),
+                                          })
+                                          .collect(),
+        }
+    }
+
+    /// Encodes `text`, starting with the byte order mark of this encoding if `bom` is `true`
+    /// 
+    /// # Return value
+    /// `Ok(bytes)` in case of success
+    /// 
+    /// `Err(error)` if a character of `text` can't be written in this encoding, with its offset in `text`
+    pub fn encode(self, text: &str, bom: bool) -> Result<Vec<u8>, EncodingError> {
+        let mut bytes = Vec::with_capacity(text.len() + 3);
+        if bom {
+            bytes.extend_from_slice(self.bom());
+        }
+
+        match self {
+            Encoding::Utf8        => bytes.extend_from_slice(text.as_bytes()),
+            Encoding::Utf16Le     => bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes)),
+            Encoding::Utf16Be     => bytes.extend(text.encode_utf16().flat_map(u16::to_be_bytes)),
+            Encoding::Latin1
+            | Encoding::Windows1252 => for (offset, c) in text.char_indices() {
+                match self.single_byte(c) {
+                    Some(byte) => bytes.push(byte),
+                    None       => return Err(EncodingError::new(self, offset, Some(c))),
+                }
+            },
+        }
+
+        Ok(bytes)
+    }
+
+    /// Returns the byte of `c` in Latin-1 or in Windows-1252, or `None` if it can't be written in this encoding
+    fn single_byte(self, c: char) -> Option<u8> {
+        match u8::try_from(c) {
+            Ok(byte) if self == Encoding::Latin1 || !(0x80..=0x9F).contains(&byte) => Some(byte),
+            Err(_) if self == Encoding::Windows1252                                => WINDOWS_1252.iter()
+                                                                                                  .position(|&known| known == Some(c))
+                                                                                                  .map(|index| index as u8 + 0x80),
+            _                                                                      => None,
+        }
+    }
+}
+
+/// Decodes `bytes` with the encoding of its byte order mark if any, or with `fallback` otherwise
+/// 
+/// # Return value
+/// `Ok((text, encoding, bom))` in case of success with `text` without its byte order mark, `encoding` as the encoding used and `bom` as whether `bytes` starts with a byte order mark
+/// 
+/// `Err(error)` if `bytes` is not valid in this encoding, with the offset of the first invalid byte in `bytes`
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::encoding::{self, Encoding};
+/// 
+/// assert_eq!(encoding::decode(b"\xEF\xBB\xBFkey=1", Encoding::Latin1).unwrap(), (String::from("key=1"), Encoding::Utf8, true));
+/// assert_eq!(encoding::decode(b"caf\xE9", Encoding::Latin1).unwrap(), (String::from("caf\u{e9}"), Encoding::Latin1, false));
+/// 
+/// let err = encoding::decode(b"caf\xE9", Encoding::Utf8).unwrap_err();
+/// assert_eq!(format!("{}", err), "Invalid UTF-8 data at byte 3");
+/// ```
+pub fn decode(bytes: &[u8], fallback: Encoding) -> Result<(String, Encoding, bool), EncodingError> {
+    let (encoding, bom) = Encoding::detect(bytes).unwrap_or((fallback, 0));
+
+    match encoding.decode(&bytes[bom..]) {
+        Ok(text) => Ok((text, encoding, bom != 0)),
+        Err(err) => Err(EncodingError::new(encoding, err.offset() + bom, None)),
+    }
+}
+
+
+#[cfg(test)]
+mod tests;
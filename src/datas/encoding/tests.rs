@@ -0,0 +1,54 @@
+use crate::datas::encoding::*;
+
+#[test]
+fn encoding_round_trip() {
+    for &(encoding, text) in &[(Encoding::Utf8, "[a]\nb=☺ 日本語\n"), (Encoding::Utf16Le, "[a]\nb=☺ \u{1f600}\n"), (Encoding::Utf16Be, "[a]\nb=☺ \u{1f600}\n"), (Encoding::Latin1, "[a]\nb=café\u{80}\n"), (Encoding::Windows1252, "[a]\nb=café €\u{178}\n")] {
+        for &bom in &[false, true] {
+            let bytes = encoding.encode(text, bom).unwrap();
+            let expected_bom = bom && !encoding.bom().is_empty();
+
+            assert_eq!(decode(&bytes, encoding).unwrap(), (String::from(text), encoding, expected_bom), "for {}", encoding);
+        }
+    }
+}
+
+#[test]
+fn encoding_bom_wins() {
+    let bytes = Encoding::Utf16Be.encode("key=1", true).unwrap();
+
+    assert_eq!(Encoding::detect(&bytes), Some((Encoding::Utf16Be, 2)));
+    assert_eq!(decode(&bytes, Encoding::Windows1252).unwrap(), (String::from("key=1"), Encoding::Utf16Be, true));
+    assert_eq!(Encoding::detect(b"key=1"), None);
+}
+
+#[test]
+fn encoding_windows_1252() {
+    assert_eq!(Encoding::Windows1252.decode(b"\x80 \x93q\x94 \xe9").unwrap(), "€ \u{201c}q\u{201d} é");
+    assert_eq!(Encoding::Latin1.decode(b"\x80").unwrap(), "\u{80}");
+    assert_eq!(Encoding::Windows1252.encode("\u{2122}", false).unwrap(), b"\x99");
+}
+
+#[test]
+fn encoding_decode_errors() {
+    let expect = |bytes: &[u8], fallback: Encoding, expected: &str| {
+        match decode(bytes, fallback) {
+            Err(err) => assert_eq!(format!("{}", err), expected),
+            Ok(text) => panic!("{:?} should be rejected, got {:?}", bytes, text),
+        }
+    };
+
+    expect(b"\xEF\xBB\xBFa=\xff", Encoding::Latin1, "Invalid UTF-8 data at byte 5");
+    expect(b"\xFF\xFEa\x00=", Encoding::Utf8, "Invalid UTF-16LE data at byte 4");
+    expect(b"\xFE\xFF\x00a\xD8\x00\x00=", Encoding::Utf8, "Invalid UTF-16BE data at byte 4");
+    expect(b"a=\x81", Encoding::Windows1252, "Invalid Windows-1252 data at byte 2");
+}
+
+#[test]
+fn encoding_encode_errors() {
+    let err = Encoding::Windows1252.encode("a=\u{80}", true).unwrap_err();
+    assert_eq!(err.encoding(), Encoding::Windows1252);
+    assert_eq!(err.offset(), 2);
+    assert_eq!(err.character(), Some('\u{80}'));
+
+    assert_eq!(Encoding::Latin1.encode("€", false).unwrap_err().offset(), 0);
+}
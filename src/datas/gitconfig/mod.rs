@@ -0,0 +1,222 @@
+//! The data of a file parsed with [`Dialect::GitConfig`](../../parse/enum.Dialect.html "parse::Dialect"), like a `.gitconfig`, where a key may be declared in a subsection and may have several values
+//! 
+//! # See
+//! `GitIdentifier` for the identity of a key and `GitConfig` to look up its values
+
+use std::fmt::{self, Display, Formatter};
+use crate::datas::Value;
+use crate::datas::multimap::OrderedMultimap;
+
+/// The identifier of a variable of a git configuration, made of a section, an optional subsection and a key
+/// 
+/// The section and the key are case-insensitive so they are stored in lowercase, while the subsection is case-sensitive
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::gitconfig::GitIdentifier;
+/// 
+/// let ident = GitIdentifier::new(String::from("Remote"), Some(String::from("Origin")), String::from("URL"));
+/// 
+/// assert_eq!(ident.section(), "remote");
+/// assert_eq!(ident.subsection(), Some("Origin"));
+/// assert_eq!(ident.key(), "url");
+/// assert_eq!(format!("{}", ident), "remote.Origin.url");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GitIdentifier {
+    section: String,
+    subsection: Option<String>,
+    key: String,
+}
+
+impl GitIdentifier {
+    /// Creates an identifier with a valid section name, any subsection and a valid key, converting the section and the key to lowercase
+    /// 
+    /// # Panics
+    /// Panics if `section` is invalid according to [`GitIdentifier::is_valid_section`](struct.GitIdentifier.html#method.is_valid_section "datas::gitconfig::GitIdentifier::is_valid_section"), if `subsection` contains a line feed or a null character or if `key` is invalid according to [`GitIdentifier::is_valid_key`](struct.GitIdentifier.html#method.is_valid_key "datas::gitconfig::GitIdentifier::is_valid_key")
+    pub fn new(section: String, subsection: Option<String>, key: String) -> GitIdentifier {
+        assert!(GitIdentifier::is_valid_section(&section));
+        if let Some(subsection) = &subsection {
+            assert!(GitIdentifier::is_valid_subsection(subsection));
+        }
+        assert!(GitIdentifier::is_valid_key(&key));
+
+        GitIdentifier {
+            section: section.to_lowercase(),
+            subsection,
+            key: key.to_lowercase(),
+        }
+    }
+
+    /// Returns `true` if the given string is a valid section name and `false` otherwise
+    /// 
+    /// A valid section name is a non-empty string of latin alphanumeric characters, `-` and `.`
+    /// 
+    /// # Examples
+    /// ```
+    /// use mininip::datas::gitconfig::GitIdentifier;
+    /// 
+    /// assert!(GitIdentifier::is_valid_section("core"));
+    /// assert!(GitIdentifier::is_valid_section("diff-so-fancy"));
+    /// assert!(!GitIdentifier::is_valid_section(""));
+    /// assert!(!GitIdentifier::is_valid_section("with space"));
+    /// ```
+    pub fn is_valid_section(section: &str) -> bool {
+        !section.is_empty() && section.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+    }
+
+    /// Returns `true` if the given string is a valid subsection name and `false` otherwise
+    /// 
+    /// A subsection name may contain any character except a line feed and a null character
+    pub fn is_valid_subsection(subsection: &str) -> bool {
+        !subsection.contains(['\n', '\0'])
+    }
+
+    /// Returns `true` if the given string is a valid key and `false` otherwise
+    /// 
+    /// A valid key is a string of latin alphanumeric characters and `-` starting with a latin alphabetic character
+    /// 
+    /// # Examples
+    /// ```
+    /// use mininip::datas::gitconfig::GitIdentifier;
+    /// 
+    /// assert!(GitIdentifier::is_valid_key("autocrlf"));
+    /// assert!(GitIdentifier::is_valid_key("log-format2"));
+    /// assert!(!GitIdentifier::is_valid_key("2fa"));
+    /// assert!(!GitIdentifier::is_valid_key("snake_case"));
+    /// ```
+    pub fn is_valid_key(key: &str) -> bool {
+        let mut iter = key.chars();
+        match iter.next() {
+            Some(c) if c.is_ascii_alphabetic() => iter.all(|c| c.is_ascii_alphanumeric() || c == '-'),
+            _                                  => false,
+        }
+    }
+
+    /// Returns the name of the section, in lowercase
+    pub fn section(&self) -> &str {
+        &self.section
+    }
+
+    /// Returns the name of the subsection if any
+    pub fn subsection(&self) -> Option<&str> {
+        match &self.subsection {
+            Some(val) => Some(val),
+            None      => None,
+        }
+    }
+
+    /// Returns the name of the key, in lowercase
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns `true` if this identifier belongs to `section` and `subsection`, ignoring the case of `section`
+    fn is_in(&self, section: &str, subsection: Option<&str>) -> bool {
+        self.section.eq_ignore_ascii_case(section) && self.subsection() == subsection
+    }
+}
+
+impl Display for GitIdentifier {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str(&self.section)?;
+        formatter.write_str(".")?;
+        if let Some(subsection) = &self.subsection {
+            formatter.write_str(subsection)?;
+            formatter.write_str(".")?;
+        }
+
+        formatter.write_str(&self.key)
+    }
+}
+
+
+/// The variables of a git configuration in the order they were declared. A key may be declared several times, like the `fetch` refspecs of a remote
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::Value;
+/// use mininip::parse::{Dialect, Parser};
+/// 
+/// let mut parser = Parser::with_dialect(Dialect::GitConfig);
+/// parser.parse_content("[remote \"origin\"]\n\turl = https://example.org/repo.git\n\tfetch = +refs/heads/*:refs/remotes/origin/*\n\tfetch = +refs/tags/*:refs/tags/*\n").unwrap();
+/// let config = parser.git_config();
+/// 
+/// let fetch = config.get_all("remote", Some("origin"), "fetch");
+/// assert_eq!(fetch.len(), 2);
+/// assert_eq!(fetch[1], &Value::Raw(String::from("+refs/tags/*:refs/tags/*")));
+/// assert_eq!(config.get("remote", Some("origin"), "URL"), Some(&Value::Raw(String::from("https://example.org/repo.git"))));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GitConfig {
+    entries: OrderedMultimap<GitIdentifier>,
+}
+
+impl GitConfig {
+    /// Creates an empty `GitConfig`
+    pub fn new() -> GitConfig {
+        GitConfig {
+            entries: OrderedMultimap::new(),
+        }
+    }
+
+    /// Returns every variable in the order they were declared
+    pub fn entries(&self) -> &[(GitIdentifier, Value)] {
+        self.entries.as_slice()
+    }
+
+    /// Consumes `self` and returns every variable in the order they were declared
+    pub fn into_entries(self) -> Vec<(GitIdentifier, Value)> {
+        self.entries.into_vec()
+    }
+
+    /// Returns the sections and subsections declared, in the order of their first variable
+    pub fn sections(&self) -> Vec<(&str, Option<&str>)> {
+        let mut sections = Vec::new();
+        for (ident, _) in self.entries.as_slice() {
+            let section = (ident.section(), ident.subsection());
+            if !sections.contains(&section) {
+                sections.push(section);
+            }
+        }
+
+        sections
+    }
+
+    /// Adds a new value to `ident`, after the ones it may already have
+    pub fn add(&mut self, ident: GitIdentifier, value: Value) {
+        self.entries.add(ident, value);
+    }
+
+    /// Replaces every value of `ident` by `value`, which takes the place of the first one
+    pub fn set(&mut self, ident: GitIdentifier, value: Value) {
+        self.entries.set(ident, value);
+    }
+
+    /// Removes every value of `ident` and returns them
+    pub fn remove_all(&mut self, ident: &GitIdentifier) -> Vec<Value> {
+        self.entries.remove_all(ident)
+    }
+
+    /// Returns the last value of `key` in `section` and `subsection`, which is the one git uses when a single value is expected
+    /// 
+    /// `section` and `key` are case-insensitive
+    pub fn get(&self, section: &str, subsection: Option<&str>, key: &str) -> Option<&Value> {
+        self.get_all(section, subsection, key).pop()
+    }
+
+    /// Returns every value of `key` in `section` and `subsection` in the order they were declared
+    /// 
+    /// `section` and `key` are case-insensitive
+    pub fn get_all(&self, section: &str, subsection: Option<&str>, key: &str) -> Vec<&Value> {
+        self.entries.as_slice()
+                    .iter()
+                    .filter(|(ident, _)| ident.is_in(section, subsection) && ident.key.eq_ignore_ascii_case(key))
+                    .map(|(_, value)| value)
+                    .collect()
+    }
+}
+
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,70 @@
+use crate::datas::gitconfig::*;
+use crate::datas::Value;
+
+fn ident(section: &str, subsection: Option<&str>, key: &str) -> GitIdentifier {
+    GitIdentifier::new(String::from(section), subsection.map(String::from), String::from(key))
+}
+
+#[test]
+fn git_identifier_lowercase() {
+    let ident = ident("Core", None, "AutoCRLF");
+
+    assert_eq!(ident.section(), "core");
+    assert_eq!(ident.subsection(), None);
+    assert_eq!(ident.key(), "autocrlf");
+    assert_eq!(format!("{}", ident), "core.autocrlf");
+}
+
+#[test]
+fn git_identifier_subsection() {
+    assert!(GitIdentifier::is_valid_subsection("any \"thing\" \\ at all"));
+    assert!(!GitIdentifier::is_valid_subsection("two\nlines"));
+    assert!(!GitIdentifier::is_valid_subsection("null\0"));
+}
+
+#[test]
+#[should_panic]
+fn git_identifier_invalid_key() {
+    ident("core", None, "auto_crlf");
+}
+
+#[test]
+fn git_config_get_all() {
+    let mut config = GitConfig::new();
+    config.add(ident("remote", Some("origin"), "fetch"), Value::Raw(String::from("a")));
+    config.add(ident("remote", Some("Origin"), "fetch"), Value::Raw(String::from("b")));
+    config.add(ident("remote", Some("origin"), "fetch"), Value::Raw(String::from("c")));
+
+    assert_eq!(config.get_all("Remote", Some("origin"), "FETCH"), vec![&Value::Raw(String::from("a")), &Value::Raw(String::from("c"))]);
+    assert_eq!(config.get("remote", Some("origin"), "fetch"), Some(&Value::Raw(String::from("c"))));
+    assert_eq!(config.get("remote", None, "fetch"), None);
+    assert_eq!(config.sections(), vec![("remote", Some("origin")), ("remote", Some("Origin"))]);
+}
+
+#[test]
+fn git_config_set() {
+    let mut config = GitConfig::new();
+    config.add(ident("a", None, "x"), Value::Raw(String::from("1")));
+    config.add(ident("a", None, "y"), Value::Raw(String::from("2")));
+    config.add(ident("a", None, "x"), Value::Raw(String::from("3")));
+    config.set(ident("a", None, "x"), Value::Raw(String::from("4")));
+    config.set(ident("a", None, "z"), Value::Raw(String::from("5")));
+
+    let entries = config.entries();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0], (ident("a", None, "x"), Value::Raw(String::from("4"))));
+    assert_eq!(entries[1], (ident("a", None, "y"), Value::Raw(String::from("2"))));
+    assert_eq!(entries[2], (ident("a", None, "z"), Value::Raw(String::from("5"))));
+}
+
+#[test]
+fn git_config_remove_all() {
+    let mut config = GitConfig::new();
+    config.add(ident("a", None, "x"), Value::Raw(String::from("1")));
+    config.add(ident("a", None, "y"), Value::Raw(String::from("2")));
+    config.add(ident("a", None, "x"), Value::Raw(String::from("3")));
+
+    let removed = config.remove_all(&ident("a", None, "x"));
+    assert_eq!(removed, vec![Value::Raw(String::from("1")), Value::Raw(String::from("3"))]);
+    assert_eq!(config.into_entries(), vec![(ident("a", None, "y"), Value::Raw(String::from("2")))]);
+}
@@ -0,0 +1,304 @@
+//! Mapping of the Rust types from and into the data of an INI file without serde
+//! 
+//! # See
+//! `FromIni` to read a Rust type from a key or a section
+//! 
+//! `ToIni` to write a Rust type into a key or a section
+//! 
+//! The `mininip_derive` crate (enabled by the `derive` feature) to implement both traits on a structure
+
+use std::any;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::PathBuf;
+use std::time::Duration;
+use crate::datas::{Identifier, Value};
+use crate::datas::convert::{format_list_item, split_list};
+use crate::datas::datetime::{Date, Time, DateTime};
+use crate::datas::tree::Tree;
+use crate::errors::{ConversionError, MappingError};
+
+/// A type which can be read from the data of an INI file
+/// 
+/// A scalar type only implements `from_value` while a structure (usually derived with `#[derive(FromIni)]`) implements `from_key` and `from_section` to read its fields from a whole section
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::{FromIni, Identifier};
+/// use mininip::parse::Parser;
+/// use std::net::IpAddr;
+/// 
+/// let mut parser = Parser::new();
+/// parser.parse_content("[server]\naddress = 127.0.0.1\nports = 80, 443\n").unwrap();
+/// let data = parser.data();
+/// 
+/// let address = Identifier::new(Some(String::from("server")), String::from("address"));
+/// assert_eq!(IpAddr::from_key(&data, &address), Ok("127.0.0.1".parse().unwrap()));
+/// 
+/// let ports = Identifier::new(Some(String::from("server")), String::from("ports"));
+/// assert_eq!(Vec::<u16>::from_key(&data, &ports), Ok(vec![80, 443]));
+/// 
+/// let timeout = Identifier::new(Some(String::from("server")), String::from("timeout"));
+/// assert_eq!(Option::<u32>::from_key(&data, &timeout), Ok(None));
+/// ```
+pub trait FromIni: Sized {
+    /// Converts the value of a key into `Self`
+    fn from_value(value: &Value) -> Result<Self, ConversionError>;
+
+    /// Reads the key `ident` in `data`
+    /// 
+    /// # Return value
+    /// `Ok(value)` in case of success
+    /// 
+    /// `Err(error)` if the key is missing or can't be converted
+    fn from_key(data: &HashMap<Identifier, Value>, ident: &Identifier) -> Result<Self, MappingError> {
+        match data.get(ident) {
+            Some(value) => Self::from_value(value).map_err(|err| MappingError::InvalidValue(ident.clone(), err)),
+            None        => Err(MappingError::MissingKey(ident.clone())),
+        }
+    }
+
+    /// Reads the keys of the section `section` (or the global keys if `section` is `None`) in `data`
+    /// 
+    /// # Return value
+    /// `Ok(value)` in case of success
+    /// 
+    /// `Err(error)` in case of failure. The default implementation always returns `MappingError::NotASection`
+    fn from_section(_data: &HashMap<Identifier, Value>, _section: Option<&str>) -> Result<Self, MappingError> {
+        Err(MappingError::NotASection(any::type_name::<Self>()))
+    }
+
+    /// Reads `Self` from the global keys of `tree`
+    fn from_tree(tree: &Tree) -> Result<Self, MappingError> {
+        Self::from_section(tree.get_data(), None)
+    }
+}
+
+/// A type which can be written into the data of an INI file
+/// 
+/// A scalar type only implements `to_value` while a structure (usually derived with `#[derive(ToIni)]`) implements `to_key` and `to_section` to write its fields into a whole section
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::{Identifier, ToIni, Value};
+/// use std::collections::HashMap;
+/// 
+/// let mut data = HashMap::new();
+/// let ports = Identifier::new(None, String::from("ports"));
+/// vec![80u16, 443].to_key(&mut data, ports.clone()).unwrap();
+/// 
+/// assert_eq!(data[&ports], Value::Raw(String::from("80, 443")));
+/// ```
+pub trait ToIni {
+    /// Converts `self` into the value of a key
+    /// 
+    /// # Return value
+    /// `Ok(Some(value))` in case of success
+    /// 
+    /// `Ok(None)` if there is nothing to write, like for `Option::None`
+    /// 
+    /// `Err(error)` if `self` can't be represented as a `Value`
+    fn to_value(&self) -> Result<Option<Value>, ConversionError>;
+
+    /// Writes `self` into `data` as the key `ident`
+    /// 
+    /// # Return value
+    /// `Ok(())` in case of success
+    /// 
+    /// `Err(error)` if `self` can't be written
+    fn to_key(&self, data: &mut HashMap<Identifier, Value>, ident: Identifier) -> Result<(), MappingError> {
+        match self.to_value() {
+            Ok(Some(value)) => {
+                data.insert(ident, value);
+                Ok(())
+            },
+            Ok(None)        => Ok(()),
+            Err(err)        => Err(MappingError::InvalidValue(ident, err)),
+        }
+    }
+
+    /// Writes `self` into `data` as the keys of the section `section` (or as global keys if `section` is `None`)
+    /// 
+    /// # Return value
+    /// `Ok(())` in case of success
+    /// 
+    /// `Err(error)` in case of failure. The default implementation always returns `MappingError::NotASection`
+    fn to_section(&self, _data: &mut HashMap<Identifier, Value>, _section: Option<&str>) -> Result<(), MappingError> {
+        Err(MappingError::NotASection(any::type_name::<Self>()))
+    }
+
+    /// Writes `self` as the global keys of a new `Tree`
+    fn to_tree(&self) -> Result<Tree, MappingError> {
+        let mut data = HashMap::new();
+        self.to_section(&mut data, None)?;
+
+        Ok(Tree::from(data))
+    }
+}
+
+
+/// Implements `FromIni` and `ToIni` for a type convertible with `TryFrom<&Value>` and `From<T> for Value`
+macro_rules! mapping_via_value {
+    ($($type:ty),*) => {
+        $(
+            impl FromIni for $type {
+                fn from_value(value: &Value) -> Result<$type, ConversionError> {
+                    <$type>::try_from(value)
+                }
+            }
+
+            impl ToIni for $type {
+                fn to_value(&self) -> Result<Option<Value>, ConversionError> {
+                    Ok(Some(Value::from(self.clone())))
+                }
+            }
+        )*
+    };
+}
+
+mapping_via_value!(i8, i16, i32, i64, u8, u16, u32, bool, String, Duration, Date, Time, DateTime);
+
+/// Implements `FromIni` and `ToIni` for a floating-point type, which also accepts an `Int` since a float without fractional part is written as an integer
+macro_rules! mapping_float {
+    ($($float:ty),*) => {
+        $(
+            impl FromIni for $float {
+                fn from_value(value: &Value) -> Result<$float, ConversionError> {
+                    match value {
+                        Value::Int(int) => Ok(*int as $float),
+                        _               => <$float>::try_from(value),
+                    }
+                }
+            }
+
+            impl ToIni for $float {
+                fn to_value(&self) -> Result<Option<Value>, ConversionError> {
+                    Ok(Some(Value::from(*self)))
+                }
+            }
+        )*
+    };
+}
+
+mapping_float!(f32, f64);
+
+/// Implements `FromIni` and `ToIni` for an integer type which may not fit in an `i64`
+macro_rules! mapping_big_int {
+    ($($int:ty),*) => {
+        $(
+            impl FromIni for $int {
+                fn from_value(value: &Value) -> Result<$int, ConversionError> {
+                    <$int>::try_from(value)
+                }
+            }
+
+            impl ToIni for $int {
+                fn to_value(&self) -> Result<Option<Value>, ConversionError> {
+                    match i64::try_from(*self) {
+                        Ok(value) => Ok(Some(Value::Int(value))),
+                        Err(_)    => Err(ConversionError::out_of_range("Int", stringify!($int), self.to_string())),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+mapping_big_int!(isize, u64, usize);
+
+/// Implements `FromIni` and `ToIni` for a type written as a string, using `FromStr` and `Display`
+macro_rules! mapping_via_str {
+    ($($type:ident),*) => {
+        $(
+            impl FromIni for $type {
+                fn from_value(value: &Value) -> Result<$type, ConversionError> {
+                    value.as_str()?
+                         .parse()
+                         .map_err(|_| ConversionError::new(stringify!($type), value.type_name()))
+                }
+            }
+
+            impl ToIni for $type {
+                fn to_value(&self) -> Result<Option<Value>, ConversionError> {
+                    Ok(Some(Value::Str(format!("{}", self))))
+                }
+            }
+        )*
+    };
+}
+
+mapping_via_str!(IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr);
+
+impl FromIni for PathBuf {
+    fn from_value(value: &Value) -> Result<PathBuf, ConversionError> {
+        Ok(PathBuf::from(value.as_str()?))
+    }
+}
+
+impl ToIni for PathBuf {
+    fn to_value(&self) -> Result<Option<Value>, ConversionError> {
+        match self.to_str() {
+            Some(path) => Ok(Some(Value::Str(String::from(path)))),
+            None       => Err(ConversionError::new("Str", "PathBuf")),
+        }
+    }
+}
+
+impl<T: FromIni> FromIni for Option<T> {
+    fn from_value(value: &Value) -> Result<Option<T>, ConversionError> {
+        T::from_value(value).map(Some)
+    }
+
+    fn from_key(data: &HashMap<Identifier, Value>, ident: &Identifier) -> Result<Option<T>, MappingError> {
+        match T::from_key(data, ident) {
+            Ok(value)                         => Ok(Some(value)),
+            Err(MappingError::MissingKey(_))  => Ok(None),
+            Err(err)                          => Err(err),
+        }
+    }
+}
+
+impl<T: ToIni> ToIni for Option<T> {
+    fn to_value(&self) -> Result<Option<Value>, ConversionError> {
+        match self {
+            Some(value) => value.to_value(),
+            None        => Ok(None),
+        }
+    }
+
+    fn to_key(&self, data: &mut HashMap<Identifier, Value>, ident: Identifier) -> Result<(), MappingError> {
+        match self {
+            Some(value) => value.to_key(data, ident),
+            None        => Ok(()),
+        }
+    }
+}
+
+impl<T: FromIni> FromIni for Vec<T> {
+    fn from_value(value: &Value) -> Result<Vec<T>, ConversionError> {
+        split_list(value).iter()
+                         .map(|(_, item)| T::from_value(item))
+                         .collect()
+    }
+}
+
+impl<T: ToIni> ToIni for Vec<T> {
+    fn to_value(&self) -> Result<Option<Value>, ConversionError> {
+        let mut items = Vec::with_capacity(self.len());
+        for i in self {
+            if let Some(value) = i.to_value()? {
+                match format_list_item(&value) {
+                    Some(item) => items.push(item),
+                    None       => return Err(ConversionError::new("list item", value.type_name())),
+                }
+            }
+        }
+
+        Ok(Some(Value::Raw(items.join(", "))))
+    }
+}
+
+
+#[cfg(test)]
+mod tests;
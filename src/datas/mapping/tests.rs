@@ -0,0 +1,117 @@
+use crate::datas::{FromIni, Identifier, ToIni, Value};
+use crate::datas::tree::Tree;
+use crate::errors::{ConversionError, MappingError};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+
+fn ident(section: Option<&str>, name: &str) -> Identifier {
+    Identifier::new(section.map(String::from), String::from(name))
+}
+
+#[test]
+fn from_key_scalars() {
+    let mut data = HashMap::new();
+    data.insert(ident(None, "port"), Value::Int(8080));
+    data.insert(ident(None, "ratio"), Value::Float(0.5));
+    data.insert(ident(None, "verbose"), Value::Bool(true));
+    data.insert(ident(None, "name"), Value::Str(String::from("demo")));
+    data.insert(ident(None, "path"), Value::Raw(String::from("/tmp/demo")));
+
+    assert_eq!(u16::from_key(&data, &ident(None, "port")), Ok(8080));
+    assert_eq!(f64::from_key(&data, &ident(None, "ratio")), Ok(0.5));
+    assert_eq!(bool::from_key(&data, &ident(None, "verbose")), Ok(true));
+    assert_eq!(String::from_key(&data, &ident(None, "name")), Ok(String::from("demo")));
+    assert_eq!(PathBuf::from_key(&data, &ident(None, "path")), Ok(PathBuf::from("/tmp/demo")));
+}
+
+#[test]
+fn from_key_addresses() {
+    let mut data = HashMap::new();
+    data.insert(ident(None, "ip"), Value::Raw(String::from("::1")));
+    data.insert(ident(None, "socket"), Value::Str(String::from("127.0.0.1:80")));
+    data.insert(ident(None, "bad"), Value::Raw(String::from("localhost")));
+
+    assert_eq!(IpAddr::from_key(&data, &ident(None, "ip")), Ok("::1".parse().unwrap()));
+    assert_eq!(SocketAddr::from_key(&data, &ident(None, "socket")), Ok("127.0.0.1:80".parse().unwrap()));
+    assert_eq!(IpAddr::from_key(&data, &ident(None, "bad")), Err(MappingError::InvalidValue(ident(None, "bad"), ConversionError::new("IpAddr", "Raw"))));
+}
+
+#[test]
+fn from_key_errors() {
+    let mut data = HashMap::new();
+    data.insert(ident(None, "big"), Value::Int(300));
+
+    assert_eq!(u8::from_key(&data, &ident(None, "big")), Err(MappingError::InvalidValue(ident(None, "big"), ConversionError::out_of_range("u8", "Int", String::from("300")))));
+    assert_eq!(u8::from_key(&data, &ident(None, "missing")), Err(MappingError::MissingKey(ident(None, "missing"))));
+}
+
+#[test]
+fn from_key_option() {
+    let mut data = HashMap::new();
+    data.insert(ident(None, "present"), Value::Int(1));
+    data.insert(ident(None, "invalid"), Value::Bool(true));
+
+    assert_eq!(Option::<i32>::from_key(&data, &ident(None, "present")), Ok(Some(1)));
+    assert_eq!(Option::<i32>::from_key(&data, &ident(None, "missing")), Ok(None));
+    assert!(Option::<i32>::from_key(&data, &ident(None, "invalid")).is_err());
+}
+
+#[test]
+fn from_value_list() {
+    assert_eq!(Vec::<i64>::from_value(&Value::Raw(String::from("1, 2 ,3"))), Ok(vec![1, 2, 3]));
+    assert_eq!(Vec::<String>::from_value(&Value::Raw(String::from("a,b"))), Ok(vec![String::from("a"), String::from("b")]));
+    assert_eq!(Vec::<i64>::from_value(&Value::Raw(String::new())), Ok(vec![]));
+    assert_eq!(Vec::<i64>::from_value(&Value::Int(7)), Ok(vec![7]));
+    assert!(Vec::<i64>::from_value(&Value::Raw(String::from("1, a"))).is_err());
+    assert_eq!(Vec::<String>::from_value(&Value::Raw(String::from("'42', \"yes\", ''"))), Ok(vec![String::from("42"), String::from("yes"), String::new()]));
+}
+
+#[test]
+fn to_key_scalars() {
+    let mut data = HashMap::new();
+    8080u16.to_key(&mut data, ident(None, "port")).unwrap();
+    String::from("demo").to_key(&mut data, ident(Some("app"), "name")).unwrap();
+    None::<i32>.to_key(&mut data, ident(None, "missing")).unwrap();
+    "127.0.0.1".parse::<IpAddr>().unwrap().to_key(&mut data, ident(None, "ip")).unwrap();
+
+    assert_eq!(data.len(), 3);
+    assert_eq!(data[&ident(None, "port")], Value::Int(8080));
+    assert_eq!(data[&ident(Some("app"), "name")], Value::Str(String::from("demo")));
+    assert_eq!(data[&ident(None, "ip")], Value::Str(String::from("127.0.0.1")));
+}
+
+#[test]
+fn to_key_errors() {
+    let mut data = HashMap::new();
+
+    assert_eq!(u64::MAX.to_key(&mut data, ident(None, "big")), Err(MappingError::InvalidValue(ident(None, "big"), ConversionError::out_of_range("Int", "u64", u64::MAX.to_string()))));
+    assert!(vec![String::from("a,b")].to_key(&mut data, ident(None, "list")).is_err());
+    assert!(data.is_empty());
+}
+
+#[test]
+fn list_round_trip() {
+    let list = vec![1.5, -2.0];
+    let value = list.to_value().unwrap().unwrap();
+
+    assert_eq!(value, Value::Raw(String::from("1.5, -2")));
+    assert_eq!(Vec::<f64>::from_value(&value), Ok(list));
+}
+
+#[test]
+fn string_list_round_trip() {
+    let list = vec![String::from("a"), String::from("yes"), String::from("42"), String::from("1.5")];
+    let value = list.to_value().unwrap().unwrap();
+    let read = Value::parse(&value.dump()).unwrap();
+
+    assert_eq!(Vec::<String>::from_value(&read), Ok(list));
+}
+
+#[test]
+fn scalar_is_not_a_section() {
+    let tree = Tree::from(HashMap::new());
+
+    assert_eq!(i32::from_tree(&tree), Err(MappingError::NotASection("i32")));
+    assert_eq!(42i32.to_tree().map(|_| ()), Err(MappingError::NotASection("i32")));
+}
@@ -1,8 +1,10 @@
 //! The basic datas structures like [`Identifier`](datas/struct.Identifier.html "Identifier") and [`Value`](datas/enum.Value.html "Value")
 
 use std::fmt::{self, Display, Formatter};
+use std::time::Duration;
 use crate::{parse, dump};
 use crate::errors::{Error, error_kinds::*};
+use datetime::{Date, Time, DateTime};
 
 /// The value of a INI variable
 /// 
@@ -11,7 +13,12 @@ use crate::errors::{Error, error_kinds::*};
 /// - `Str`: a quoted string written inside non-escaped quotes like that `"Hello world!"` or that `'Hello world!'`
 /// - `Int`: a 64 bytes-sized integer
 /// - `Float`: a 64 bytes-sized floating-point number
-/// - `Bool`: a boolean (currently either `on` or `off`)
+/// - `Bool`: a boolean (see [BoolVocabulary](struct.BoolVocabulary.html "datas::BoolVocabulary") for the words recognized as booleans)
+/// - `Duration`: a duration written as an integer followed by a unit like `30s` or `250ms` (see [parse_duration](../parse/fn.parse_duration.html "parse::parse_duration"))
+/// - `Size`: a size in bytes written as an integer followed by a SI or IEC unit like `2GB` or `64KiB` (see [parse_size](../parse/fn.parse_size.html "parse::parse_size"))
+/// - `Date`: a calendar date like `2020-04-28` (see [Date](datetime/struct.Date.html "datas::datetime::Date"))
+/// - `Time`: a time of day like `12:30:00` (see [Time](datetime/struct.Time.html "datas::datetime::Time"))
+/// - `DateTime`: a date and a time of day with an optional offset like `2020-04-28T12:30:00Z` (see [DateTime](datetime/struct.DateTime.html "datas::datetime::DateTime"))
 /// 
 /// Each type is represented as an enum variant
 #[derive(Debug, Clone, PartialEq)]
@@ -21,17 +28,27 @@ pub enum Value {
     Int(i64),
     Float(f64),
     Bool(bool),
+    Duration(Duration),
+    Size(u64),
+    Date(Date),
+    Time(Time),
+    DateTime(DateTime),
 }
 
 impl Display for Value {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         match self {
-            Value::Raw(string)   => string.fmt(formatter),
-            Value::Str(string)   => string.fmt(formatter),
-            Value::Int(number)   => number.fmt(formatter),
-            Value::Float(number) => number.fmt(formatter),
-            Value::Bool(true)    => "on".fmt(formatter),
-            Value::Bool(false)   => "off".fmt(formatter),
+            Value::Raw(string)        => string.fmt(formatter),
+            Value::Str(string)        => string.fmt(formatter),
+            Value::Int(number)        => number.fmt(formatter),
+            Value::Float(number)      => number.fmt(formatter),
+            Value::Bool(true)         => "on".fmt(formatter),
+            Value::Bool(false)        => "off".fmt(formatter),
+            Value::Duration(duration) => dump::dump_duration(duration).fmt(formatter),
+            Value::Size(size)         => dump::dump_size(*size).fmt(formatter),
+            Value::Date(date)         => date.fmt(formatter),
+            Value::Time(time)         => time.fmt(formatter),
+            Value::DateTime(datetime) => datetime.fmt(formatter),
         }
     }
 }
@@ -49,24 +66,52 @@ impl Value {
     /// `Ok(value)` with `value` as the new object
     /// 
     /// `Err(error)` when an error occurs while parsing `content` with `error` as the error code
+    /// 
+    /// # See
+    /// See [`parse_with_vocabulary`](enum.Value.html#method.parse_with_vocabulary "datas::Value::parse_with_vocabulary") to recognize other booleans than the default ones
     pub fn parse(content: &str) -> Result<Value, Error> {
+        Value::parse_with(content, TextMode::Ascii)
+    }
+
+    /// Builds a new [`Value`](enum.Value.html "datas::Value") from `content` like [`parse`](enum.Value.html#method.parse "datas::Value::parse") does, but allows the non-ASCII characters not to be escaped if `mode` is `TextMode::Utf8`
+    /// 
+    /// # Examples
+    /// ```
+    /// use mininip::datas::{TextMode, Value};
+    /// 
+    /// assert_eq!(Value::parse_with("'café'", TextMode::Utf8).unwrap(), Value::Str(String::from("café")));
+    /// assert!(Value::parse_with("'café'", TextMode::Ascii).is_err());
+    /// ```
+    pub fn parse_with(content: &str, mode: TextMode) -> Result<Value, Error> {
+        Value::parse_with_vocabulary(content, &BoolVocabulary::default(), mode)
+    }
+
+    /// Builds a new [`Value`](enum.Value.html "datas::Value") from `content`, an INI-formatted string whose non-ASCII characters are read according to `mode`, using `vocabulary` to recognize booleans
+    /// 
+    /// # Return value
+    /// `Ok(value)` with `value` as the new object
+    /// 
+    /// `Err(error)` when an error occurs while parsing `content` with `error` as the error code
+    /// 
+    /// # Examples
+    /// ```
+    /// use mininip::datas::{BoolVocabulary, TextMode, Value};
+    /// 
+    /// let vocabulary = BoolVocabulary::new(vec![String::from("1")], vec![String::from("0")], true);
+    /// 
+    /// assert_eq!(Value::parse_with_vocabulary("1", &vocabulary, TextMode::Ascii).unwrap(), Value::Bool(true));
+    /// assert_eq!(Value::parse_with_vocabulary("TRUE", &vocabulary, TextMode::Ascii).unwrap(), Value::Raw(String::from("TRUE")));
+    /// assert_eq!(Value::parse("TRUE").unwrap(), Value::Bool(true));
+    /// ```
+    pub fn parse_with_vocabulary(content: &str, vocabulary: &BoolVocabulary, mode: TextMode) -> Result<Value, Error> {
         let effective = content.trim();
 
         if effective.starts_with("'") || effective.starts_with("\"") {
-            let quote = &effective[..1];
-
-            if !effective.ends_with(quote) {
-                let err = ExpectedToken::new(String::from(content), content.len(), String::from(quote));
-                Err(Error::from(err))
-            } else {
-                Ok(Value::Str(parse::parse_str(&effective[1..effective.len() - 1])?))
-            }
+            Ok(Value::Str(Value::parse_quoted(content, mode)?))
         }
 
-        else if effective == "on" || effective == "enabled" || effective == "y" || effective == "yes" {
-            Ok(Value::Bool(true))
-        } else if effective == "off" || effective == "disabled" || effective == "n" || effective == "no" {
-            Ok(Value::Bool(false))
+        else if let Some(value) = vocabulary.parse(effective) {
+            Ok(Value::Bool(value))
         }
 
         else if let Ok(value) = effective.parse::<i64>() {
@@ -77,8 +122,127 @@ impl Value {
             Ok(Value::Float(value))
         }
 
+        // The colons of a time may be escaped or not
+        else if let Some(value) = Value::parse_datetime(&effective.replace("\\:", ":")) {
+            Ok(value)
+        }
+
+        else if let Some(value) = parse::parse_duration(effective)? {
+            Ok(Value::Duration(value))
+        }
+
+        else if let Some(value) = parse::parse_size(effective)? {
+            Ok(Value::Size(value))
+        }
+
         else {
-            Ok(Value::Raw(parse::parse_str(effective)?))
+            Ok(Value::Raw(parse::parse_str_with(effective, mode)?))
+        }
+    }
+
+    /// Builds a new [`Value`](enum.Value.html "datas::Value") of type `value_type` from `content`, an INI-formatted string whose non-ASCII characters are read according to `mode`, instead of guessing its type
+    /// 
+    /// A `Str` or a `Raw` is read as written (without its quotes if any) while the other types must be written as such, except an `Int` which is accepted as a `Float`
+    /// 
+    /// # Return value
+    /// `Ok(value)` with `value` as the new object
+    /// 
+    /// `Err(error)` when an error occurs while parsing `content` or if it is not written as a `value_type`
+    /// 
+    /// # Examples
+    /// ```
+    /// use mininip::datas::{BoolVocabulary, TextMode, Value, ValueType};
+    /// 
+    /// let vocabulary = BoolVocabulary::default();
+    /// 
+    /// assert_eq!(Value::parse_as("1.10", ValueType::Str, &vocabulary, TextMode::Ascii).unwrap(), Value::Str(String::from("1.10")));
+    /// assert_eq!(Value::parse_as("01234", ValueType::Raw, &vocabulary, TextMode::Ascii).unwrap(), Value::Raw(String::from("01234")));
+    /// assert_eq!(Value::parse_as("2", ValueType::Float, &vocabulary, TextMode::Ascii).unwrap(), Value::Float(2.0));
+    /// assert!(Value::parse_as("yes", ValueType::Int, &vocabulary, TextMode::Ascii).is_err());
+    /// ```
+    pub fn parse_as(content: &str, value_type: ValueType, vocabulary: &BoolVocabulary, mode: TextMode) -> Result<Value, Error> {
+        match value_type {
+            ValueType::Str => return Ok(Value::Str(Value::parse_string(content, mode)?)),
+            ValueType::Raw => return Ok(Value::Raw(Value::parse_string(content, mode)?)),
+            _              => (),
+        }
+
+        match (value_type, Value::parse_with_vocabulary(content, vocabulary, mode)?) {
+            (ValueType::Float, Value::Int(value)) => Ok(Value::Float(value as f64)),
+            (_, value) if value.value_type() == value_type => Ok(value),
+            (_, value) => Err(Error::from(WrongType::new(String::from(content), value_type.name(), value.type_name()))),
+        }
+    }
+
+    /// Builds a new `Str` if `content` is quoted or a new `Raw` otherwise, without guessing any other type. The non-ASCII characters of `content` are read according to `mode`
+    /// 
+    /// # Return value
+    /// `Ok(value)` with `value` as the new object
+    /// 
+    /// `Err(error)` when an error occurs while parsing `content` with `error` as the error code
+    pub fn parse_uninferred(content: &str, mode: TextMode) -> Result<Value, Error> {
+        let effective = content.trim();
+
+        if effective.starts_with("'") || effective.starts_with("\"") {
+            Ok(Value::Str(Value::parse_quoted(content, mode)?))
+        } else {
+            Ok(Value::Raw(parse::parse_str_with(effective, mode)?))
+        }
+    }
+
+    /// Reads `content` as a string, removing its quotes if any
+    fn parse_string(content: &str, mode: TextMode) -> Result<String, Error> {
+        let effective = content.trim();
+
+        if effective.starts_with("'") || effective.starts_with("\"") {
+            Value::parse_quoted(content, mode)
+        } else {
+            parse::parse_str_with(effective, mode)
+        }
+    }
+
+    /// Reads a quoted string, checking it ends with the same quote as the one it starts with
+    fn parse_quoted(content: &str, mode: TextMode) -> Result<String, Error> {
+        let effective = content.trim();
+        let quote = &effective[..1];
+
+        if effective.len() < 2 || !effective.ends_with(quote) {
+            let err = ExpectedToken::new(String::from(content), content.len(), String::from(quote));
+            Err(Error::from(err))
+        } else {
+            parse::parse_str_with(&effective[1..effective.len() - 1], mode)
+        }
+    }
+
+    /// Returns the name of the variant of `self`, such as `"Int"` for a `Value::Int`
+    pub fn type_name(&self) -> &'static str {
+        self.value_type().name()
+    }
+
+    /// Returns the variant of `self` as a [`ValueType`](enum.ValueType.html "datas::ValueType")
+    pub fn value_type(&self) -> ValueType {
+        match self {
+            Value::Raw(_)      => ValueType::Raw,
+            Value::Str(_)      => ValueType::Str,
+            Value::Int(_)      => ValueType::Int,
+            Value::Float(_)    => ValueType::Float,
+            Value::Bool(_)     => ValueType::Bool,
+            Value::Duration(_) => ValueType::Duration,
+            Value::Size(_)     => ValueType::Size,
+            Value::Date(_)     => ValueType::Date,
+            Value::Time(_)     => ValueType::Time,
+            Value::DateTime(_) => ValueType::DateTime,
+        }
+    }
+
+    /// Reads either a `DateTime`, a `Date` or a `Time`. Returns `None` if `content` is none of them
+    fn parse_datetime(content: &str) -> Option<Value> {
+        if let Some(datetime) = DateTime::parse(content) {
+            Some(Value::DateTime(datetime))
+        } else if let Some(date) = Date::parse(content) {
+            Some(Value::Date(date))
+        } else {
+            Time::parse(content).map(Value::Time)
         }
     }
 
@@ -99,7 +263,10 @@ impl Value {
     /// - `Str` is backed up with two quotes `'` or `"` around its value once escaped
     /// - `Int` is backed up as is
     /// - `Float` is backed up as is
-    /// - `Bool` is backed up as two different values: `true` and `false`
+    /// - `Bool` is backed up as two different values: `on` and `off` (see [`dump_with_style`](enum.Value.html#method.dump_with_style "datas::Value::dump_with_style") to use other ones)
+    /// - `Duration` is backed up with the biggest unit representing it exactly (see [`dump_duration`](../dump/fn.dump_duration.html "dump::dump_duration"))
+    /// - `Size` is backed up with the biggest unit representing it exactly (see [`dump_size`](../dump/fn.dump_size.html "dump::dump_size"))
+    /// - `Date`, `Time` and `DateTime` are backed up as defined in the RFC 3339. Their colons are not escaped
     /// 
     /// # Examples
     /// ```
@@ -111,17 +278,461 @@ impl Value {
     /// assert_eq!(dumped, "'tr\\x0000e8s_content\\=\\x00263a \\; the symbol of hapiness'"); // Notice the quotes here
     /// ```
     pub fn dump(&self) -> String {
+        self.dump_with(TextMode::Ascii)
+    }
+
+    /// Formats `self` to be dumped in an INI file like [`dump`](enum.Value.html#method.dump "datas::Value::dump") does, but writes the non-ASCII characters as is if `mode` is `TextMode::Utf8`
+    /// 
+    /// # Examples
+    /// ```
+    /// use mininip::datas::{TextMode, Value};
+    /// 
+    /// assert_eq!(Value::Str(String::from("café = ☺")).dump_with(TextMode::Utf8), "'café \\= ☺'");
+    /// assert_eq!(Value::Raw(String::from("café")).dump_with(TextMode::Ascii), "caf\\x0000e9");
+    /// ```
+    pub fn dump_with(&self, mode: TextMode) -> String {
+        self.dump_with_style(BoolStyle::default(), mode)
+    }
+
+    /// Formats `self` to be dumped in an INI file like [`dump_with`](enum.Value.html#method.dump_with "datas::Value::dump_with") does, but spells the booleans according to `style`
+    /// 
+    /// # Examples
+    /// ```
+    /// use mininip::datas::{BoolStyle, TextMode, Value};
+    /// 
+    /// assert_eq!(Value::Bool(true).dump_with_style(BoolStyle::TrueFalse, TextMode::Ascii), "true");
+    /// assert_eq!(Value::Bool(false).dump_with_style(BoolStyle::YesNo, TextMode::Ascii), "no");
+    /// assert_eq!(Value::Int(1).dump_with_style(BoolStyle::YesNo, TextMode::Ascii), "1");
+    /// ```
+    pub fn dump_with_style(&self, style: BoolStyle, mode: TextMode) -> String {
+        match self {
+            Value::Raw(string)        => dump::dump_str_with(string, mode),
+            Value::Str(string)        => format!("'{}'", dump::dump_str_with(string, mode)),
+            Value::Int(number)        => format!("{}", number),
+            Value::Float(number)      => format!("{}", number),
+            Value::Bool(value)        => String::from(style.spell(*value)),
+            Value::Duration(duration) => dump::dump_duration(duration),
+            Value::Size(size)         => dump::dump_size(*size),
+            Value::Date(date)         => format!("{}", date),
+            Value::Time(time)         => format!("{}", time),
+            Value::DateTime(datetime) => format!("{}", datetime),
+        }
+    }
+}
+
+
+/// The type of a [`Value`](enum.Value.html "datas::Value") without its content, which is the name of its variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueType {
+    Raw,
+    Str,
+    Int,
+    Float,
+    Bool,
+    Duration,
+    Size,
+    Date,
+    Time,
+    DateTime,
+}
+
+impl ValueType {
+    /// Returns the name of the variant, such as `"Int"` for `ValueType::Int`
+    pub fn name(self) -> &'static str {
         match self {
-            Value::Raw(string)   => format!("{}", dump::dump_str(&string)),
-            Value::Str(string)   => format!("'{}'", dump::dump_str(&string)),
-            Value::Int(number)   => format!("{}", number),
-            Value::Float(number) => format!("{}", number),
-            Value::Bool(true)    => String::from("on"),
-            Value::Bool(false)   => String::from("off"),
+            ValueType::Raw      => "Raw",
+            ValueType::Str      => "Str",
+            ValueType::Int      => "Int",
+            ValueType::Float    => "Float",
+            ValueType::Bool     => "Bool",
+            ValueType::Duration => "Duration",
+            ValueType::Size     => "Size",
+            ValueType::Date     => "Date",
+            ValueType::Time     => "Time",
+            ValueType::DateTime => "DateTime",
         }
     }
 }
 
+impl Display for ValueType {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
+
+
+/// The source text of a value as written in an INI file, kept alongside the [`Value`](enum.Value.html "datas::Value") it was parsed into
+/// 
+/// It is used to write back a value unchanged with its original spelling, like `0x10` or `yes`, instead of its normalized one
+/// 
+/// # See
+/// [`Parser::set_keep_lexemes`](../parse/struct.Parser.html#method.set_keep_lexemes "parse::Parser::set_keep_lexemes") to keep the lexemes while parsing
+/// 
+/// [`Dumper::set_lexemes`](../dump/struct.Dumper.html#method.set_lexemes "dump::Dumper::set_lexemes") to reuse them while dumping
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::{BoolStyle, Lexeme, TextMode, Value};
+/// 
+/// let lexeme = Lexeme::new(String::from("yes"), Value::Bool(true));
+/// assert_eq!(lexeme.dump(&Value::Bool(true), BoolStyle::default(), TextMode::Ascii), "yes");
+/// assert_eq!(lexeme.dump(&Value::Bool(false), BoolStyle::default(), TextMode::Ascii), "off");
+/// 
+/// let lexeme = Lexeme::new(String::from("\"abc\""), Value::Str(String::from("abc")));
+/// assert_eq!(lexeme.quote(), Some('"'));
+/// assert_eq!(lexeme.dump(&Value::Str(String::from("def")), BoolStyle::default(), TextMode::Ascii), "\"def\"");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lexeme {
+    text: String,
+    quote: Option<char>,
+    value: Value,
+}
+
+impl Lexeme {
+    /// Creates a new `Lexeme`
+    /// 
+    /// # Parameters
+    /// `text` the value as written in the file, without the surrounding spaces nor the comment
+    /// 
+    /// `value` the value `text` was parsed into
+    pub fn new(text: String, value: Value) -> Lexeme {
+        let quote = match text.chars().next() {
+            Some(c) if c == '\'' || c == '"' => Some(c),
+            _                                => None,
+        };
+
+        Lexeme {
+            text,
+            quote,
+            value,
+        }
+    }
+
+    /// Returns the value as written in the file
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the quote surrounding the value if any
+    pub fn quote(&self) -> Option<char> {
+        self.quote
+    }
+
+    /// Returns the value the text was parsed into
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// Formats `value` as it should be written in the place of `self`
+    /// 
+    /// # Return value
+    /// The original text if `value` did not change
+    /// 
+    /// Otherwise, the dump of `value` with `style` for a boolean, with the original quote for a `Str` and with its non-ASCII characters written according to `mode`
+    pub fn dump(&self, value: &Value, style: BoolStyle, mode: TextMode) -> String {
+        match (value, self.quote) {
+            _ if *value == self.value         => self.text.clone(),
+            (Value::Str(string), Some(quote)) => format!("{}{}{}", quote, dump::dump_str_with(string, mode), quote),
+            _                                 => value.dump_with_style(style, mode),
+        }
+    }
+}
+
+
+/// The words recognized as booleans by [`Value::parse_with_vocabulary`](enum.Value.html#method.parse_with_vocabulary "datas::Value::parse_with_vocabulary")
+/// 
+/// The default vocabulary is case-insensitive and recognizes
+/// - `true`, `on`, `enabled`, `y` and `yes` as `true`
+/// - `false`, `off`, `disabled`, `n` and `no` as `false`
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::BoolVocabulary;
+/// 
+/// let mut vocabulary = BoolVocabulary::default();
+/// vocabulary.add_true(String::from("1"));
+/// vocabulary.add_false(String::from("0"));
+/// 
+/// assert_eq!(vocabulary.parse("Yes"), Some(true));
+/// assert_eq!(vocabulary.parse("0"), Some(false));
+/// assert_eq!(vocabulary.parse("maybe"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoolVocabulary {
+    truthy: Vec<String>,
+    falsy: Vec<String>,
+    case_sensitive: bool,
+}
+
+impl Default for BoolVocabulary {
+    fn default() -> Self {
+        let truthy = ["true", "on", "enabled", "y", "yes"];
+        let falsy = ["false", "off", "disabled", "n", "no"];
+
+        BoolVocabulary::new(truthy.iter().map(|s| String::from(*s)).collect(),
+                            falsy.iter().map(|s| String::from(*s)).collect(),
+                            false)
+    }
+}
+
+impl BoolVocabulary {
+    /// Creates a new vocabulary recognizing the words in `truthy` as `true` and the ones in `falsy` as `false`. The comparison ignores the ASCII case unless `case_sensitive` is `true`
+    /// 
+    /// # Panics
+    /// Panics if a word is both in `truthy` and `falsy`
+    pub fn new(truthy: Vec<String>, falsy: Vec<String>, case_sensitive: bool) -> BoolVocabulary {
+        let mut vocabulary = BoolVocabulary {
+            truthy: Vec::new(),
+            falsy: Vec::new(),
+            case_sensitive,
+        };
+
+        for i in truthy {
+            vocabulary.add_true(i);
+        }
+        for i in falsy {
+            vocabulary.add_false(i);
+        }
+
+        vocabulary
+    }
+
+    /// Creates a vocabulary which does not recognize any boolean
+    pub fn empty() -> BoolVocabulary {
+        BoolVocabulary::new(Vec::new(), Vec::new(), true)
+    }
+
+    /// Adds a word recognized as `true`
+    /// 
+    /// # Panics
+    /// Panics if `word` is already recognized as `false`
+    pub fn add_true(&mut self, word: String) {
+        assert_ne!(self.parse(&word), Some(false), "`word` is already recognized as `false`");
+
+        self.truthy.push(word);
+    }
+
+    /// Adds a word recognized as `false`
+    /// 
+    /// # Panics
+    /// Panics if `word` is already recognized as `true`
+    pub fn add_false(&mut self, word: String) {
+        assert_ne!(self.parse(&word), Some(true), "`word` is already recognized as `true`");
+
+        self.falsy.push(word);
+    }
+
+    /// Returns `true` if the case matters when recognizing a word and `false` otherwise
+    pub fn is_case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    /// Returns the boolean corresponding to `word`
+    /// 
+    /// # Return value
+    /// `Some(value)` if `word` is a known word with `value` as its meaning
+    /// 
+    /// `None` otherwise
+    pub fn parse(&self, word: &str) -> Option<bool> {
+        let matches = |known: &String| if self.case_sensitive {
+            known == word
+        } else {
+            known.eq_ignore_ascii_case(word)
+        };
+
+        if self.truthy.iter().any(matches) {
+            Some(true)
+        } else if self.falsy.iter().any(matches) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+
+/// The way a `Value::Bool` is spelled when dumped (see [`Value::dump_with_style`](enum.Value.html#method.dump_with_style "datas::Value::dump_with_style"))
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoolStyle {
+    /// `on` and `off`, the default one
+    #[default]
+    OnOff,
+    /// `true` and `false`
+    TrueFalse,
+    /// `yes` and `no`
+    YesNo,
+    /// `enabled` and `disabled`
+    EnabledDisabled,
+}
+
+impl BoolStyle {
+    /// Returns the spelling of `value` in this style
+    pub fn spell(self, value: bool) -> &'static str {
+        match (self, value) {
+            (BoolStyle::OnOff, true)            => "on",
+            (BoolStyle::OnOff, false)           => "off",
+            (BoolStyle::TrueFalse, true)        => "true",
+            (BoolStyle::TrueFalse, false)       => "false",
+            (BoolStyle::YesNo, true)            => "yes",
+            (BoolStyle::YesNo, false)           => "no",
+            (BoolStyle::EnabledDisabled, true)  => "enabled",
+            (BoolStyle::EnabledDisabled, false) => "disabled",
+        }
+    }
+}
+
+
+/// How the non-ASCII characters of the values are written in an INI file
+/// 
+/// # See
+/// [`parse_str_with`](../parse/fn.parse_str_with.html "parse::parse_str_with") and [`dump_str_with`](../dump/fn.dump_str_with.html "dump::dump_str_with") for a single string
+/// 
+/// [`Parser::set_text_mode`](../parse/struct.Parser.html#method.set_text_mode "parse::Parser::set_text_mode") and [`Dumper::set_text_mode`](../dump/struct.Dumper.html#method.set_text_mode "dump::Dumper::set_text_mode") for a whole file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextMode {
+    /// Every non-ASCII character is escaped as `\x??????`, the default one
+    #[default]
+    Ascii,
+    /// The non-ASCII characters are written as is, so the file must be read and written as UTF-8. The `\x??????` escapes are still read
+    Utf8,
+}
+
+
+/// The sequence of characters ending the lines of an INI file
+/// 
+/// # See
+/// [`Parser::line_ending`](../parse/struct.Parser.html#method.line_ending "parse::Parser::line_ending") to get the one of a file and [`Dumper::set_line_ending`](../dump/struct.Dumper.html#method.set_line_ending "dump::Dumper::set_line_ending") to write it back
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineEnding {
+    /// `\n`, used on Unix, the default one
+    #[default]
+    Lf,
+    /// `\r\n`, used on Windows
+    CrLf,
+    /// `\r`, used on the classic Mac OS
+    Cr,
+}
+
+impl LineEnding {
+    /// Returns the text of this line ending
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf   => "\n",
+            LineEnding::CrLf => "\r\n",
+            LineEnding::Cr   => "\r",
+        }
+    }
+
+    /// Finds the first line ending of `content`
+    /// 
+    /// # Return value
+    /// `Some((index, ending))` with `index` as the index of the line ending in `content` and `ending` as the line ending found
+    /// 
+    /// `None` if `content` is a single line
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::datas::LineEnding;
+    /// 
+    /// assert_eq!(LineEnding::find("a = 1\r\nb = 2\n"), Some((5, LineEnding::CrLf)));
+    /// assert_eq!(LineEnding::find("a = 1\rb = 2"), Some((5, LineEnding::Cr)));
+    /// assert_eq!(LineEnding::find("a = 1"), None);
+    /// ```
+    pub fn find(content: &str) -> Option<(usize, LineEnding)> {
+        let index = content.find(['\n', '\r'])?;
+        let ending = match &content[index..] {
+            rest if rest.starts_with("\r\n") => LineEnding::CrLf,
+            rest if rest.starts_with('\r')   => LineEnding::Cr,
+            _                               => LineEnding::Lf,
+        };
+
+        Some((index, ending))
+    }
+}
+
+/// The rule telling which identifiers are valid (see [`Identifier::with_policy`](struct.Identifier.html#method.with_policy "datas::Identifier::with_policy"))
+/// 
+/// New rules may be added in a minor version, so this enum is `non_exhaustive`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum IdentifierPolicy {
+    /// The rule of [`Identifier::is_valid`](struct.Identifier.html#method.is_valid "datas::Identifier::is_valid"), which only allows ASCII characters, the default one
+    #[default]
+    Ascii,
+    /// The same rule extended to Unicode (requires the `unicode` feature). The first character may be any `XID_Start` character and the following ones any `XID_Continue` character, so `größe` and `名前` are valid
+    /// 
+    /// The identifiers are normalized to NFC so that equivalent spellings, like a precomposed `é` and an `e` followed by a combining accent, are the same identifier
+    /// 
+    /// This rule is unsupported without the `unicode` feature, in which case no identifier is valid with it
+    UnicodeXid,
+    /// The rule of the `configparser` module of Python, followed by [`Dialect::ConfigParser`](../parse/enum.Dialect.html "parse::Dialect"). Any non-empty string which does not contain a line feed is valid, so `_private`, `1st` and the section name of `[a ]` are valid
+    /// 
+    /// Like Python, the parser keeps the whitespaces around a section name but trims the ones around a key, so a key starting or ending with a whitespace can't be read back
+    ConfigParser,
+    /// The rule of the keys of the `.env` files read by [`dotenv::parse_content`](../dotenv/fn.parse_content.html "dotenv::parse_content"), which is `[A-Za-z_][A-Za-z0-9_.]*`, so `_JAVA_OPTIONS` is valid while `my key` and `a:b` are not
+    Dotenv,
+}
+
+impl IdentifierPolicy {
+    /// Returns `true` if `ident`, already normalized by [`normalize`](enum.IdentifierPolicy.html#method.normalize "datas::IdentifierPolicy::normalize"), is a valid identifier according to this policy
+    /// 
+    /// It always returns `false` with [`UnicodeXid`](enum.IdentifierPolicy.html#variant.UnicodeXid "datas::IdentifierPolicy::UnicodeXid") if the `unicode` feature is disabled (see [`is_supported`](enum.IdentifierPolicy.html#method.is_supported "datas::IdentifierPolicy::is_supported"))
+    pub fn is_valid(self, ident: &str) -> bool {
+        match self {
+            IdentifierPolicy::Ascii        => Identifier::is_valid(ident),
+            #[cfg(feature = "unicode")]
+            IdentifierPolicy::UnicodeXid   => {
+                let mut iter = ident.chars();
+                match iter.next() {
+                    None    => return false,
+                    Some(c) => if !unicode_ident::is_xid_start(c) && c != '.' && c != '$' && c != ':' {
+                        return false;
+                    },
+                }
+
+                iter.all(|c| unicode_ident::is_xid_continue(c) || "~-.:$ ".contains(c))
+            },
+            #[cfg(not(feature = "unicode"))]
+            IdentifierPolicy::UnicodeXid   => false,
+            IdentifierPolicy::ConfigParser => !ident.is_empty() && !ident.contains(['\n', '\r']),
+            IdentifierPolicy::Dotenv       => {
+                let mut iter = ident.chars();
+                match iter.next() {
+                    Some(c) if c.is_ascii_alphabetic() || c == '_' => iter.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.'),
+                    _                                              => false,
+                }
+            },
+        }
+    }
+
+    /// Returns `ident` as stored by an identifier following this policy, which is its NFC form with `UnicodeXid` and `ident` unchanged otherwise
+    pub fn normalize(self, ident: &str) -> String {
+        match self {
+            IdentifierPolicy::Ascii
+            | IdentifierPolicy::ConfigParser
+            | IdentifierPolicy::Dotenv       => String::from(ident),
+            #[cfg(feature = "unicode")]
+            IdentifierPolicy::UnicodeXid     => unicode_normalization::UnicodeNormalization::nfc(ident).collect(),
+            #[cfg(not(feature = "unicode"))]
+            IdentifierPolicy::UnicodeXid     => String::from(ident),
+        }
+    }
+
+    /// Returns `false` if this policy needs a feature which is disabled, which is the case of `UnicodeXid` without the `unicode` feature, and `true` otherwise
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::datas::IdentifierPolicy;
+    /// 
+    /// assert!(IdentifierPolicy::Ascii.is_supported());
+    /// assert_eq!(IdentifierPolicy::UnicodeXid.is_supported(), cfg!(feature = "unicode"));
+    /// ```
+    pub const fn is_supported(self) -> bool {
+        cfg!(feature = "unicode") || !matches!(self, IdentifierPolicy::UnicodeXid)
+    }
+}
+
 
 /// The identifier of a variable, which is its identity. Of course, this type is `Hash` because it may be used as a key in a `HashMap`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -147,6 +758,28 @@ impl Identifier {
         }
     }
 
+    /// Creates an identifier following `policy`, after normalizing `section` and `name` with [`IdentifierPolicy::normalize`](enum.IdentifierPolicy.html#method.normalize "datas::IdentifierPolicy::normalize")
+    /// 
+    /// # Panics
+    /// Panics if either `section` or `name` is an invalid identifier according to `policy`
+    /// 
+    /// # Notes
+    /// [`change_name`](struct.Identifier.html#method.change_name "datas::Identifier::change_name") and [`change_section`](struct.Identifier.html#method.change_section "datas::Identifier::change_section") still follow the ASCII rule, so an identifier with non-ASCII characters must be created again to be renamed
+    pub fn with_policy(section: Option<String>, name: String, policy: IdentifierPolicy) -> Identifier {
+        let section = section.map(|section| policy.normalize(&section));
+        let name = policy.normalize(&name);
+
+        if let Some(section) = &section {
+            assert!(policy.is_valid(section));
+        }
+        assert!(policy.is_valid(&name));
+
+        Identifier {
+            section,
+            name,
+        }
+    }
+
     /// Returns `true` if the given string is a valid identifier and `false` otherwise
     /// 
     /// A valid identifier is defined as a string of latin alphanumeric characters and any of `_`, `~`, `-`, `.`, `:`, `$` and space starting with a latin alphabetic one or any of `.`, `$` or `:`. All of these characters must be ASCII
@@ -154,6 +787,8 @@ impl Identifier {
     /// # Notes
     /// Since the INI file format is not really normalized, this definition may evolve in the future. In fact, I will avoid when possible to make a stronger rule, in order to keep backward compatibility
     /// 
+    /// This function is a `const fn` so that the derive macros of the `derive` feature check the key names at compile time
+    /// 
     /// # Examples
     /// ```
     /// use mininip::datas::Identifier;
@@ -167,27 +802,31 @@ impl Identifier {
     /// assert!(!Identifier::is_valid("é_is_unicode"));
     /// assert!(!Identifier::is_valid(" starts_with_a_space"));
     /// ```
-    pub fn is_valid(ident: &str) -> bool {
-        let mut iter = ident.chars();
-        match iter.next() {
+    pub const fn is_valid(ident: &str) -> bool {
+        // The identifier is checked byte by byte since it is a `const fn`. Any byte of a non-ASCII character is rejected
+        let bytes = ident.as_bytes();
+        if bytes.is_empty() {
             // An empty string is not allowed
-            None    => return false,
+            return false;
+        }
 
-            // The first character must be a letter, a point, a dollar sign or a colon
-            Some(c) => if !c.is_ascii() || !c.is_alphabetic() && c != '.' && c != '$' && c != ':' {
-                return false;
-            },
+        // The first character must be a letter, a point, a dollar sign or a colon
+        let first = bytes[0];
+        if !first.is_ascii_alphabetic() && first != b'.' && first != b'$' && first != b':' {
+            return false;
         }
 
-        for i in iter {
+        let mut index = 1;
+        while index < bytes.len() {
             // The following ones may be numeric characters, underscores, tildes, dashs or spaces
-            if !i.is_ascii() || !i.is_alphanumeric()
-                             && i != '_' && i != '~' 
-                             && i != '-' && i != '.'
-                             && i != ':' && i != '$'
-                             && i != ' ' {
+            let i = bytes[index];
+            if !i.is_ascii_alphanumeric() && i != b'_' && i != b'~'
+                                          && i != b'-' && i != b'.'
+                                          && i != b':' && i != b'$'
+                                          && i != b' ' {
                 return false;
             }
+            index += 1;
         }
 
         true
@@ -201,7 +840,7 @@ impl Identifier {
     /// Returns the section of the variable which may be a named section as `Some(name)` or the "global scope" wich is `None`
     pub fn section(&self) -> Option<&str> {
         match &self.section {
-            Some(val) => Some(&val),
+            Some(val) => Some(val),
             None      => None,
         }
     }
@@ -232,7 +871,7 @@ impl Identifier {
 impl Display for Identifier {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         if let Some(section) = &self.section {
-            formatter.write_str(&section)?;
+            formatter.write_str(section)?;
             formatter.write_str(".")?;
         }
 
@@ -242,6 +881,24 @@ impl Display for Identifier {
 
 
 pub mod tree;
+pub mod datetime;
+pub mod configparser;
+pub mod desktop;
+pub mod encoding;
+pub mod gitconfig;
+pub mod php;
+pub mod profile;
+pub mod systemd;
+
+mod convert;
+pub use convert::Lenient;
+mod multimap;
+mod mapping;
+pub use mapping::{FromIni, ToIni};
+#[cfg(feature = "derive")]
+pub use mininip_derive::{FromIni, ToIni};
+#[cfg(feature = "serde")]
+pub(crate) use convert::{format_list_item, split_list};
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,73 @@
+//! The storage of [`GitConfig`](../gitconfig/struct.GitConfig.html "datas::gitconfig::GitConfig") and [`UnitFile`](../systemd/struct.UnitFile.html "datas::systemd::UnitFile"), where a key may be assigned several times
+
+use crate::datas::Value;
+
+/// The assignments of a file in the order they were declared, where a key may be assigned several times
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OrderedMultimap<K> {
+    entries: Vec<(K, Value)>,
+}
+
+impl<K> Default for OrderedMultimap<K> {
+    fn default() -> Self {
+        OrderedMultimap::new()
+    }
+}
+
+impl<K> OrderedMultimap<K> {
+    /// Creates an empty `OrderedMultimap`
+    pub fn new() -> OrderedMultimap<K> {
+        OrderedMultimap {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns every assignment in the order they were declared
+    pub fn as_slice(&self) -> &[(K, Value)] {
+        &self.entries
+    }
+
+    /// Consumes `self` and returns every assignment in the order they were declared
+    pub fn into_vec(self) -> Vec<(K, Value)> {
+        self.entries
+    }
+
+    /// Adds an assignment of `key` after the ones it may already have
+    pub fn add(&mut self, key: K, value: Value) {
+        self.entries.push((key, value));
+    }
+}
+
+impl<K: PartialEq> OrderedMultimap<K> {
+    /// Replaces every assignment of `key` by a single one, which takes the place of the first one
+    pub fn set(&mut self, key: K, value: Value) {
+        match self.entries.iter().position(|(i, _)| *i == key) {
+            Some(index) => {
+                // Every assignment of `key` is after the first one so `index` is still right once they are removed
+                self.remove_all(&key);
+                self.entries.insert(index, (key, value));
+            },
+            None        => self.add(key, value),
+        }
+    }
+
+    /// Removes every assignment of `key` and returns their values in the order they were declared
+    pub fn remove_all(&mut self, key: &K) -> Vec<Value> {
+        let mut removed = Vec::new();
+        let mut kept = Vec::with_capacity(self.entries.len());
+        for (i, value) in self.entries.drain(..) {
+            if i == *key {
+                removed.push(value);
+            } else {
+                kept.push((i, value));
+            }
+        }
+
+        self.entries = kept;
+        removed
+    }
+}
+
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,32 @@
+use super::OrderedMultimap;
+use crate::datas::Value;
+
+fn raw(text: &str) -> Value {
+    Value::Raw(String::from(text))
+}
+
+#[test]
+fn multimap_set_keeps_first_place() {
+    let mut map = OrderedMultimap::new();
+    map.add("a", raw("1"));
+    map.add("b", raw("2"));
+    map.add("a", raw("3"));
+    map.set("a", raw("4"));
+
+    assert_eq!(map.as_slice(), &[("a", raw("4")), ("b", raw("2"))]);
+
+    map.set("c", raw("5"));
+    assert_eq!(map.into_vec(), vec![("a", raw("4")), ("b", raw("2")), ("c", raw("5"))]);
+}
+
+#[test]
+fn multimap_remove_all() {
+    let mut map = OrderedMultimap::new();
+    map.add("a", raw("1"));
+    map.add("b", raw("2"));
+    map.add("a", raw("3"));
+
+    assert_eq!(map.remove_all(&"a"), vec![raw("1"), raw("3")]);
+    assert_eq!(map.remove_all(&"a"), Vec::new());
+    assert_eq!(map.as_slice(), &[("b", raw("2"))]);
+}
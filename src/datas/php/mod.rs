@@ -0,0 +1,181 @@
+//! The data of a file parsed with [`Dialect::Php`](../../parse/enum.Dialect.html "parse::Dialect"), which has the shape of the array documented for `parse_ini_file($path, true)` in PHP
+//! 
+//! # See
+//! `PhpArray` for this array and `PhpValue` for its elements
+
+use std::fmt::{self, Display, Formatter};
+use crate::datas::Value;
+
+/// The key of an element of a `PhpArray`, which is either an integer or a string like in PHP
+/// 
+/// A string written like a decimal integer which fits in an `i64` is an integer key, like in PHP
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::php::PhpKey;
+/// 
+/// assert_eq!(PhpKey::from("42"), PhpKey::Index(42));
+/// assert_eq!(PhpKey::from("-7"), PhpKey::Index(-7));
+/// assert_eq!(PhpKey::from("042"), PhpKey::Name(String::from("042")));
+/// assert_eq!(PhpKey::from("name"), PhpKey::Name(String::from("name")));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PhpKey {
+    Index(i64),
+    Name(String),
+}
+
+impl From<&str> for PhpKey {
+    fn from(key: &str) -> PhpKey {
+        let digits = key.strip_prefix('-').unwrap_or(key);
+        let canonical = match digits.as_bytes() {
+            [b'0']             => !key.starts_with('-'),
+            [b'1'..=b'9', ..]  => digits.bytes().all(|c| c.is_ascii_digit()),
+            _                  => false,
+        };
+
+        match key.parse() {
+            Ok(index) if canonical => PhpKey::Index(index),
+            _                      => PhpKey::Name(String::from(key)),
+        }
+    }
+}
+
+impl From<i64> for PhpKey {
+    fn from(index: i64) -> PhpKey {
+        PhpKey::Index(index)
+    }
+}
+
+impl Display for PhpKey {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            PhpKey::Index(index) => index.fmt(formatter),
+            PhpKey::Name(name)   => name.fmt(formatter),
+        }
+    }
+}
+
+
+/// An element of a `PhpArray`, which is either a value or another array
+#[derive(Debug, Clone, PartialEq)]
+pub enum PhpValue {
+    Value(Value),
+    Array(PhpArray),
+}
+
+impl PhpValue {
+    /// Returns the value if `self` is not an array
+    pub fn as_value(&self) -> Option<&Value> {
+        match self {
+            PhpValue::Value(value) => Some(value),
+            PhpValue::Array(_)     => None,
+        }
+    }
+
+    /// Returns the array if `self` is an array
+    pub fn as_array(&self) -> Option<&PhpArray> {
+        match self {
+            PhpValue::Value(_)     => None,
+            PhpValue::Array(array) => Some(array),
+        }
+    }
+}
+
+
+/// An ordered map like the arrays of PHP, whose keys are integers or strings
+/// 
+/// The file parsed is an array of sections, which are arrays of values or of arrays built by `key[] = value` and `key[name] = value`
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::Value;
+/// use mininip::datas::php::{PhpArray, PhpKey, PhpValue};
+/// 
+/// let mut array = PhpArray::new();
+/// array.push(PhpValue::Value(Value::Str(String::from("a"))));
+/// array.insert(PhpKey::Index(5), PhpValue::Value(Value::Str(String::from("b"))));
+/// array.push(PhpValue::Value(Value::Str(String::from("c"))));
+/// 
+/// let keys: Vec<&PhpKey> = array.entries().iter().map(|(key, _)| key).collect();
+/// assert_eq!(keys, vec![&PhpKey::Index(0), &PhpKey::Index(5), &PhpKey::Index(6)]);
+/// assert_eq!(array.get(6), Some(&PhpValue::Value(Value::Str(String::from("c")))));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PhpArray {
+    entries: Vec<(PhpKey, PhpValue)>,
+    /// The key of the next element pushed, which is `None` until an integer key is used
+    next_index: Option<i64>,
+}
+
+impl PhpArray {
+    /// Creates an empty `PhpArray`
+    pub fn new() -> PhpArray {
+        PhpArray {
+            entries: Vec::new(),
+            next_index: None,
+        }
+    }
+
+    /// Returns the number of elements of the array
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the array does not have any element
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the elements of the array in order
+    pub fn entries(&self) -> &[(PhpKey, PhpValue)] {
+        &self.entries
+    }
+
+    /// Consumes `self` and returns the elements of the array in order
+    pub fn into_entries(self) -> Vec<(PhpKey, PhpValue)> {
+        self.entries
+    }
+
+    /// Returns the element of `key`, which may be an integer, a `&str` or a `PhpKey`
+    pub fn get<T: Into<PhpKey>>(&self, key: T) -> Option<&PhpValue> {
+        let key = key.into();
+
+        self.entries.iter()
+                    .find(|(i, _)| *i == key)
+                    .map(|(_, value)| value)
+    }
+
+    /// Returns a mutable reference to the element of `key`, which may be an integer, a `&str` or a `PhpKey`
+    pub fn get_mut<T: Into<PhpKey>>(&mut self, key: T) -> Option<&mut PhpValue> {
+        let key = key.into();
+
+        self.entries.iter_mut()
+                    .find(|(i, _)| *i == key)
+                    .map(|(_, value)| value)
+    }
+
+    /// Changes the element of `key` to `value`. Like in PHP, an existing element keeps its position and a new one is added at the end
+    pub fn insert(&mut self, key: PhpKey, value: PhpValue) {
+        if let PhpKey::Index(index) = key {
+            if self.next_index.is_none_or(|next| index >= next) {
+                self.next_index = Some(index.saturating_add(1));
+            }
+        }
+
+        match self.entries.iter_mut().find(|(i, _)| *i == key) {
+            Some((_, old)) => *old = value,
+            None           => self.entries.push((key, value)),
+        }
+    }
+
+    /// Adds `value` at the end with an integer key following the greatest one used, like `$array[] = $value` in PHP
+    pub fn push(&mut self, value: PhpValue) {
+        let index = self.next_index.unwrap_or(0);
+        self.insert(PhpKey::Index(index), value);
+    }
+}
+
+
+#[cfg(test)]
+mod tests;
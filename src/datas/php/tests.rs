@@ -0,0 +1,205 @@
+use crate::datas::Value;
+use crate::datas::php::*;
+use crate::errors::Error;
+use crate::parse::{Dialect, Parser};
+use std::collections::HashMap;
+
+fn str_value(text: &str) -> PhpValue {
+    PhpValue::Value(Value::Str(String::from(text)))
+}
+
+/// Parses `content` with the constants and variables of the `generate.php` script of the fixtures
+fn php(content: &str) -> Result<PhpArray, Error> {
+    let mut constants = HashMap::new();
+    constants.insert(String::from("BIRD"), String::from("Dodo bird"));
+    let mut variables = HashMap::new();
+    variables.insert(String::from("MININIP_HOME"), String::from("/home/mininip"));
+
+    let mut parser = Parser::with_dialect(Dialect::Php);
+    parser.set_php_constants(constants);
+    parser.set_php_variables(variables);
+    parser.parse_content(content)?;
+
+    Ok(parser.php_array())
+}
+
+/// Writes `text` like `var_export` in PHP
+fn export(text: &str) -> String {
+    format!("'{}'", text.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+/// Renders `array` like the `generate.php` script of the fixtures
+fn render(array: &PhpArray, path: &str) -> String {
+    let mut result = String::new();
+    for (key, value) in array.entries() {
+        let key_path = match key {
+            PhpKey::Index(index) => format!("{}[{}]", path, index),
+            PhpKey::Name(name)   => format!("{}[{}]", path, export(name)),
+        };
+
+        match value {
+            PhpValue::Array(array) if array.is_empty() => result.push_str(&format!("{} = array()\n", key_path)),
+            PhpValue::Array(array)                     => result.push_str(&render(array, &key_path)),
+            PhpValue::Value(Value::Str(text))          => result.push_str(&format!("{} = {}\n", key_path, export(text))),
+            PhpValue::Value(value)                     => panic!("Every value is a string with the PHP syntax, got {:?}", value),
+        }
+    }
+
+    result
+}
+
+// The expected results of the fixtures are written by hand from the PHP manual, not by PHP itself (see `fixtures/php/generate.php`)
+#[test]
+fn php_fixture_docs() {
+    let array = php(include_str!("../../../fixtures/php/docs.ini")).unwrap();
+
+    assert_eq!(render(&array, ""), include_str!("../../../fixtures/php/docs.expected"));
+}
+
+#[test]
+fn php_fixture_values() {
+    let array = php(include_str!("../../../fixtures/php/values.ini")).unwrap();
+
+    assert_eq!(render(&array, ""), include_str!("../../../fixtures/php/values.expected"));
+}
+
+#[test]
+fn php_fixture_arrays() {
+    let array = php(include_str!("../../../fixtures/php/arrays.ini")).unwrap();
+
+    assert_eq!(render(&array, ""), include_str!("../../../fixtures/php/arrays.expected"));
+}
+
+#[test]
+fn php_key_from_str() {
+    assert_eq!(PhpKey::from("0"), PhpKey::Index(0));
+    assert_eq!(PhpKey::from("-0"), PhpKey::Name(String::from("-0")));
+    assert_eq!(PhpKey::from("9223372036854775807"), PhpKey::Index(i64::MAX));
+    assert_eq!(PhpKey::from("9223372036854775808"), PhpKey::Name(String::from("9223372036854775808")));
+    assert_eq!(PhpKey::from("+1"), PhpKey::Name(String::from("+1")));
+    assert_eq!(PhpKey::from("1.5"), PhpKey::Name(String::from("1.5")));
+    assert_eq!(PhpKey::from(""), PhpKey::Name(String::new()));
+    assert_eq!(format!("{}", PhpKey::from("-12")), "-12");
+}
+
+#[test]
+fn php_array_push_after_negative_index() {
+    let mut array = PhpArray::new();
+    array.insert(PhpKey::Index(-5), str_value("a"));
+    array.push(str_value("b"));
+    array.insert(PhpKey::Index(2), str_value("c"));
+    array.insert(PhpKey::Index(1), str_value("d"));
+    array.push(str_value("e"));
+
+    let keys: Vec<&PhpKey> = array.entries().iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![&PhpKey::Index(-5), &PhpKey::Index(-4), &PhpKey::Index(2), &PhpKey::Index(1), &PhpKey::Index(3)]);
+}
+
+#[test]
+fn php_array_insert_keeps_position() {
+    let mut array = PhpArray::new();
+    array.insert(PhpKey::from("a"), str_value("1"));
+    array.insert(PhpKey::from("b"), str_value("2"));
+    array.insert(PhpKey::from("a"), str_value("3"));
+
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.entries()[0], (PhpKey::from("a"), str_value("3")));
+    assert_eq!(array.get("b").and_then(PhpValue::as_value), Some(&Value::Str(String::from("2"))));
+    assert_eq!(array.get("a").and_then(PhpValue::as_array), None);
+}
+
+#[test]
+fn php_parse_line_joins_quoted_lines() {
+    let mut parser = Parser::with_dialect(Dialect::Php);
+    parser.parse_line("text = \"a ; b").unwrap();
+    parser.parse_line("c\" ; d").unwrap();
+    parser.parse_line("raw = 'x").unwrap();
+    parser.parse_line("y'").unwrap();
+
+    let array = parser.php_array();
+    assert_eq!(array.get("text"), Some(&str_value("a ; b\nc")));
+    assert_eq!(array.get("raw"), Some(&str_value("x\ny")));
+}
+
+#[test]
+fn php_ignored_lines() {
+    let array = php("; comment\n\nlabel\nlabel ; comment\n[section]\nkey = value\n").unwrap();
+
+    assert_eq!(render(&array, ""), "['section']['key'] = 'value'\n");
+}
+
+#[test]
+fn php_quoted_section_and_offset() {
+    let array = php("[\"my section\"]\nmap[\"${MININIP_HOME}\"] = a\nmap[ BIRD ] = b\n[ 42 ]\nx = y\n").unwrap();
+
+    assert_eq!(render(&array, ""), "\
+['my section']['map']['/home/mininip'] = 'a'
+['my section']['map']['BIRD'] = 'b'
+[42]['x'] = 'y'
+");
+}
+
+#[test]
+fn php_keywords_only_alone() {
+    let array = php("a = true story\nb = 'true'\nc = \"\" null\n").unwrap();
+
+    assert_eq!(array.get("a"), Some(&str_value("true story")));
+    assert_eq!(array.get("b"), Some(&str_value("true")));
+    assert_eq!(array.get("c"), Some(&str_value(" null")));
+}
+
+#[test]
+fn php_unterminated_quotes() {
+    match php("a = \"open\nb = c\n") {
+        Err(Error::ExpectedToken(_)) => (),
+        other                        => panic!("Expected an ExpectedToken error, got {:?}", other),
+    }
+    match php("a = ${OPEN\n") {
+        Err(Error::ExpectedToken(_)) => (),
+        other                        => panic!("Expected an ExpectedToken error, got {:?}", other),
+    }
+    match php("[section\n") {
+        Err(Error::ExpectedToken(_)) => (),
+        other                        => panic!("Expected an ExpectedToken error, got {:?}", other),
+    }
+}
+
+#[test]
+fn php_unsupported_operators() {
+    for content in &["level = E_ALL & ~E_NOTICE\n", "a = b = c\n", "a = hello!\n"] {
+        match php(content) {
+            Err(Error::UnexpectedToken(_)) => (),
+            other                          => panic!("Expected an UnexpectedToken error for {:?}, got {:?}", content, other),
+        }
+    }
+}
+
+#[test]
+fn php_invalid_keys() {
+    for content in &["yes = 1\n", "a{b} = 1\n", "Null[] = 1\n", "[section] extra\n"] {
+        match php(content) {
+            Err(Error::UnexpectedToken(_)) => (),
+            other                          => panic!("Expected an UnexpectedToken error for {:?}, got {:?}", content, other),
+        }
+    }
+    match php("= 1\n") {
+        Err(Error::ExpectedIdentifier(_)) => (),
+        other                             => panic!("Expected an ExpectedIdentifier error, got {:?}", other),
+    }
+    match php("[]\n") {
+        Err(Error::ExpectedIdentifier(_)) => (),
+        other                             => panic!("Expected an ExpectedIdentifier error, got {:?}", other),
+    }
+    match php("a[] b\n") {
+        Err(Error::ExpectedToken(_)) => (),
+        other                        => panic!("Expected an ExpectedToken error, got {:?}", other),
+    }
+}
+
+#[test]
+fn php_other_dialects_have_no_array() {
+    let mut parser = Parser::new();
+    parser.parse_content("a = 1\n").unwrap();
+
+    assert!(parser.php_array().is_empty());
+}
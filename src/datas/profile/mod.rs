@@ -0,0 +1,294 @@
+//! The data of a file parsed with [`Dialect::WindowsProfile`](../../parse/enum.Dialect.html "parse::Dialect"), looked up and modified like `GetPrivateProfileString` and `WritePrivateProfileString` do on Windows
+//! 
+//! # See
+//! `Profile` to look up and change a value and [`Encoding`](../encoding/enum.Encoding.html "datas::encoding::Encoding") for the encodings of the files
+
+use crate::datas::LineEnding;
+use crate::datas::encoding::Encoding;
+use crate::errors::EncodingError;
+
+/// A line of a section of a `Profile`
+#[derive(Debug, Clone, PartialEq)]
+enum ProfileLine {
+    /// A key, with its value as written if the line has a `=`
+    Entry(String, Option<String>),
+    /// A comment or an empty line, kept to be written back
+    Comment(String),
+}
+
+/// A section of a `Profile`, whose name is `None` for the lines before the first section
+#[derive(Debug, Clone, PartialEq)]
+struct ProfileSection {
+    name: Option<String>,
+    lines: Vec<ProfileLine>,
+}
+
+/// The content of a profile file of Windows, with its sections, keys, comments and encoding in the order they were read
+/// 
+/// Like the profile API of Windows, the lookups ignore the case of the sections and of the keys and return the first match, so a section or a key declared twice is hidden by the first one. The changes keep the rest of the file as is
+/// 
+/// # Example
+/// ```
+/// use mininip::parse::{Dialect, Parser};
+/// 
+/// let mut parser = Parser::with_dialect(Dialect::WindowsProfile);
+/// parser.parse_content("[Settings]\nPath = \"C:\\Program Files\\App\" \nPath=ignored\n; comment\nTitle=a ; b\n").unwrap();
+/// let mut profile = parser.profile();
+/// 
+/// assert_eq!(profile.get(Some("SETTINGS"), "path"), Some("C:\\Program Files\\App"));
+/// assert_eq!(profile.get_raw(Some("Settings"), "Path"), Some("\"C:\\Program Files\\App\""));
+/// assert_eq!(profile.get(Some("Settings"), "Title"), Some("a ; b"));
+/// 
+/// profile.set(Some("settings"), "title", "c");
+/// profile.set(Some("Window"), "Width", "640");
+/// assert_eq!(profile.generate(), "[Settings]\nPath=\"C:\\Program Files\\App\"\nPath=ignored\n; comment\ntitle=c\n[Window]\nWidth=640\n");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    sections: Vec<ProfileSection>,
+    encoding: Encoding,
+    bom: bool,
+    line_ending: LineEnding,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Profile::new()
+    }
+}
+
+impl Profile {
+    /// Creates an empty `Profile` encoded in UTF-8 without byte order mark with the Windows line endings `\r\n`
+    pub fn new() -> Profile {
+        Profile {
+            sections: Vec::new(),
+            encoding: Encoding::Utf8,
+            bom: false,
+            line_ending: LineEnding::CrLf,
+        }
+    }
+
+    /// Returns the encoding of the file read, which is used to write it back
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Returns whether the file read starts with a byte order mark, which is written back if so
+    pub fn bom(&self) -> bool {
+        self.bom
+    }
+
+    /// Changes the encoding used to write the file and whether it starts with a byte order mark. Windows only detects UTF-16 from its byte order mark
+    pub fn set_encoding(&mut self, encoding: Encoding, bom: bool) {
+        self.encoding = encoding;
+        self.bom = bom;
+    }
+
+    /// Returns the line ending of the file read, which is used to write it back
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    /// Changes the line ending used to write the file
+    pub fn set_line_ending(&mut self, ending: LineEnding) {
+        self.line_ending = ending;
+    }
+
+    /// Returns the names of the sections in the order they were declared, including the ones declared twice, like `GetPrivateProfileSectionNames`
+    pub fn section_names(&self) -> Vec<&str> {
+        self.sections.iter()
+                     .filter_map(|section| section.name.as_deref())
+                     .collect()
+    }
+
+    /// Returns the keys of the first section named `section`, ignoring the case, or the keys before any section if `section` is `None`
+    pub fn keys(&self, section: Option<&str>) -> Vec<&str> {
+        match self.find_section(section) {
+            Some(index) => self.sections[index].lines.iter()
+                                                     .filter_map(|line| match line {
+                                                         ProfileLine::Entry(key, _) => Some(key.as_str()),
+                                                         ProfileLine::Comment(_)    => None,
+                                                     })
+                                                     .collect(),
+            None        => Vec::new(),
+        }
+    }
+
+    /// Returns the value of the first `key` of the first section named `section` as written, ignoring the case of both names
+    /// 
+    /// A key without `=` has an empty value. The keys before any section are in the section `None`
+    pub fn get_raw(&self, section: Option<&str>, key: &str) -> Option<&str> {
+        let section = &self.sections[self.find_section(section)?];
+        section.lines.iter().find_map(|line| match line {
+            ProfileLine::Entry(name, value) if eq_ignore_case(name, key) => Some(value.as_deref().unwrap_or("")),
+            _                                                          => None,
+        })
+    }
+
+    /// Returns the value of `key` in `section` like `GetPrivateProfileString`, which is the value of [`get_raw`](struct.Profile.html#method.get_raw "datas::profile::Profile::get_raw") without the double or single quotes surrounding it
+    pub fn get(&self, section: Option<&str>, key: &str) -> Option<&str> {
+        self.get_raw(section, key).map(strip_quotes)
+    }
+
+    /// Changes the value of `key` in `section` like `WritePrivateProfileString`
+    /// 
+    /// The first `key` of the first section named `section` is changed in place, ignoring the case. Otherwise, the key is added at the end of this section, which is added at the end of the file if it does not exist. The keys before any section are in the section `None`
+    pub fn set(&mut self, section: Option<&str>, key: &str, value: &str) {
+        let index = match self.find_section(section) {
+            Some(index) => index,
+            None        => {
+                let section = ProfileSection {
+                    name: section.map(String::from),
+                    lines: Vec::new(),
+                };
+                // The keys before any section are at the beginning of the file
+                if section.name.is_none() {
+                    self.sections.insert(0, section);
+                    0
+                } else {
+                    self.sections.push(section);
+                    self.sections.len() - 1
+                }
+            },
+        };
+
+        let lines = &mut self.sections[index].lines;
+        for line in lines.iter_mut() {
+            if let ProfileLine::Entry(name, old) = line {
+                if eq_ignore_case(name, key) {
+                    *name = String::from(key);
+                    *old = Some(String::from(value));
+                    return;
+                }
+            }
+        }
+        lines.push(ProfileLine::Entry(String::from(key), Some(String::from(value))));
+    }
+
+    /// Removes the first `key` of the first section named `section`, ignoring the case, like `WritePrivateProfileString` with a null value
+    /// 
+    /// # Return value
+    /// The value removed as written, if any
+    pub fn remove_key(&mut self, section: Option<&str>, key: &str) -> Option<String> {
+        let section = self.find_section(section)?;
+        let lines = &mut self.sections[section].lines;
+        let index = lines.iter().position(|line| matches!(line, ProfileLine::Entry(name, _) if eq_ignore_case(name, key)))?;
+
+        match lines.remove(index) {
+            ProfileLine::Entry(_, value) => Some(value.unwrap_or_default()),
+            ProfileLine::Comment(_)      => unreachable!("The line removed is an entry"),
+        }
+    }
+
+    /// Removes every section named `section`, ignoring the case, like `WritePrivateProfileString` with a null key
+    /// 
+    /// # Return value
+    /// `true` if a section was removed
+    pub fn remove_section(&mut self, section: Option<&str>) -> bool {
+        let len = self.sections.len();
+        self.sections.retain(|i| !eq_section(i.name.as_deref(), section));
+
+        self.sections.len() != len
+    }
+
+    /// Returns the content of the file with its [`line_ending`](struct.Profile.html#method.line_ending "datas::profile::Profile::line_ending")
+    pub fn generate(&self) -> String {
+        let mut result = String::new();
+        for section in &self.sections {
+            if let Some(name) = &section.name {
+                result.push('[');
+                result.push_str(name);
+                result.push(']');
+                result.push_str(self.line_ending.as_str());
+            }
+
+            for line in &section.lines {
+                match line {
+                    ProfileLine::Entry(key, value) => {
+                        result.push_str(key);
+                        if let Some(value) = value {
+                            result.push('=');
+                            result.push_str(value);
+                        }
+                    },
+                    ProfileLine::Comment(comment)  => result.push_str(comment),
+                }
+                result.push_str(self.line_ending.as_str());
+            }
+        }
+
+        result
+    }
+
+    /// Returns the content of the file encoded with [`encoding`](struct.Profile.html#method.encoding "datas::profile::Profile::encoding"), starting with its byte order mark if [`bom`](struct.Profile.html#method.bom "datas::profile::Profile::bom") is `true`
+    /// 
+    /// # Return value
+    /// `Ok(bytes)` in case of success
+    /// 
+    /// `Err(error)` if a character can't be written in this encoding (see [`Encoding::encode`](../encoding/enum.Encoding.html#method.encode "datas::encoding::Encoding::encode"))
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodingError> {
+        self.encoding.encode(&self.generate(), self.bom)
+    }
+
+    /// Starts a new section named `name`, where the next lines are added
+    pub(crate) fn push_section(&mut self, name: String) {
+        self.sections.push(ProfileSection {
+            name: Some(name),
+            lines: Vec::new(),
+        });
+    }
+
+    /// Adds a key to the last section, with its value as written if the line has a `=`
+    pub(crate) fn push_entry(&mut self, key: String, value: Option<String>) {
+        self.last_lines().push(ProfileLine::Entry(key, value));
+    }
+
+    /// Adds a comment or an empty line to the last section
+    pub(crate) fn push_comment(&mut self, comment: String) {
+        self.last_lines().push(ProfileLine::Comment(comment));
+    }
+
+    /// Returns the lines of the last section, adding the section `None` if there is none
+    fn last_lines(&mut self) -> &mut Vec<ProfileLine> {
+        if self.sections.is_empty() {
+            self.sections.push(ProfileSection {
+                name: None,
+                lines: Vec::new(),
+            });
+        }
+
+        &mut self.sections.last_mut().expect("There is at least one section").lines
+    }
+
+    /// Returns the index of the first section named `section`, ignoring the case
+    fn find_section(&self, section: Option<&str>) -> Option<usize> {
+        self.sections.iter().position(|i| eq_section(i.name.as_deref(), section))
+    }
+}
+
+/// Returns `true` if `a` and `b` are the same ignoring the case, like the names of the profile API of Windows
+fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a.chars().flat_map(char::to_lowercase).eq(b.chars().flat_map(char::to_lowercase))
+}
+
+/// Returns `true` if `a` and `b` are the same section name ignoring the case
+fn eq_section(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => eq_ignore_case(a, b),
+        (None, None)       => true,
+        _                  => false,
+    }
+}
+
+/// Removes the double or single quotes surrounding `value`, like `GetPrivateProfileString`
+fn strip_quotes(value: &str) -> &str {
+    let bytes = value.as_bytes();
+    match bytes {
+        [first, .., last] if first == last && (*first == b'"' || *first == b'\'') => &value[1..value.len() - 1],
+        _                                                                        => value,
+    }
+}
+
+
+#[cfg(test)]
+mod tests;
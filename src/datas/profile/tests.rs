@@ -0,0 +1,136 @@
+use crate::datas::profile::*;
+use crate::datas::encoding::Encoding;
+use crate::parse::{parse_profile_bytes, Dialect, Parser};
+
+fn profile(content: &str) -> Profile {
+    let mut parser = Parser::with_dialect(Dialect::WindowsProfile);
+    parser.parse_content(content).unwrap();
+
+    parser.profile()
+}
+
+#[test]
+fn profile_fixture_vendor() {
+    let profile = parse_profile_bytes(include_bytes!("../../../fixtures/windows/vendor.ini")).unwrap();
+
+    assert_eq!(profile.encoding(), Encoding::Utf8);
+    assert!(!profile.bom());
+    assert_eq!(profile.section_names(), vec!["Settings", "Window", "settings"]);
+    assert_eq!(profile.get(Some("settings"), "PATH"), Some("C:\\Program Files\\Vendor App"));
+    assert_eq!(profile.get_raw(Some("settings"), "PATH"), Some("\"C:\\Program Files\\Vendor App\""));
+    assert_eq!(profile.get(Some("Settings"), "Title"), Some("Vendor ; App"));
+    assert_eq!(profile.get(Some("Settings"), "Empty"), Some(""));
+    assert_eq!(profile.get(Some("Settings"), "NoValue"), Some(""));
+    assert_eq!(profile.get(Some("Settings"), "Note"), Some("a ; not a comment"));
+    assert_eq!(profile.get(Some("Settings"), "Hidden"), None);
+    assert_eq!(profile.get(Some("WINDOW"), "width"), Some("640"));
+    assert_eq!(profile.get(Some("settings"), "[Broken"), None);
+    assert_eq!(profile.get(Some("Missing"), "Width"), None);
+    assert_eq!(profile.keys(Some("settings")), vec!["Path", "Title", "Empty", "NoValue", "Note", "path"]);
+    assert_eq!(profile.keys(None), Vec::<&str>::new());
+}
+
+#[test]
+fn profile_fixture_utf16le() {
+    let bytes = include_bytes!("../../../fixtures/windows/utf16le.ini");
+    let mut profile = parse_profile_bytes(bytes).unwrap();
+
+    assert_eq!(profile.encoding(), Encoding::Utf16Le);
+    assert!(profile.bom());
+    assert_eq!(profile.get(Some("GÉNÉRAL"), "nom"), Some("Élodie"));
+    assert_eq!(profile.get(Some("Général"), "Ville"), Some("Zürich"));
+    assert_eq!(profile.to_bytes().unwrap(), bytes.to_vec());
+
+    profile.set(Some("général"), "Ville", "Genève");
+    let expected = Encoding::Utf16Le.encode("[Général]\r\nNom=\"Élodie\"\r\nVille=Genève\r\n", true).unwrap();
+    assert_eq!(profile.to_bytes().unwrap(), expected);
+}
+
+#[test]
+fn profile_encodings() {
+    for &(encoding, bom) in &[(Encoding::Utf8, false), (Encoding::Utf8, true), (Encoding::Utf16Le, true), (Encoding::Utf16Be, true)] {
+        let bytes = encoding.encode("[a]\r\nb=☺\r\n", bom).unwrap();
+        let profile = parse_profile_bytes(&bytes).unwrap();
+
+        assert_eq!(profile.encoding(), encoding);
+        assert_eq!(profile.bom(), bom);
+        assert_eq!(profile.get(Some("A"), "B"), Some("☺"));
+        assert_eq!(profile.to_bytes().unwrap(), bytes);
+    }
+
+    let mut profile = profile("[a]\r\nb=☺\r\n");
+    profile.set_encoding(Encoding::Latin1, false);
+    assert!(profile.to_bytes().is_err());
+}
+
+#[test]
+fn profile_invalid_encoding() {
+    let cases = [(&[0xFF, 0xFE, b'a'][..], Encoding::Utf16Le), (&[0xFF, 0xFE, 0x00, 0xD8][..], Encoding::Utf16Le), (&[b'a', 0xFF][..], Encoding::Utf8)];
+    for (bytes, encoding) in cases.iter() {
+        let err = parse_profile_bytes(bytes).unwrap_err();
+
+        assert_eq!(err.encoding(), *encoding);
+    }
+}
+
+#[test]
+fn profile_strip_quotes() {
+    let profile = profile("[s]\na=\"quoted\"\nb='single'\nc=\"mismatched'\nd=\"\ne=\"\"\nf= \"in\" side \n");
+
+    assert_eq!(profile.get(Some("s"), "a"), Some("quoted"));
+    assert_eq!(profile.get(Some("s"), "b"), Some("single"));
+    assert_eq!(profile.get(Some("s"), "c"), Some("\"mismatched'"));
+    assert_eq!(profile.get(Some("s"), "d"), Some("\""));
+    assert_eq!(profile.get(Some("s"), "e"), Some(""));
+    assert_eq!(profile.get(Some("s"), "f"), Some("\"in\" side"));
+}
+
+#[test]
+fn profile_keys_before_any_section() {
+    let mut profile = profile("; header\nversion=1\n[s]\nk=v\n");
+
+    assert_eq!(profile.get(None, "VERSION"), Some("1"));
+    assert_eq!(profile.keys(None), vec!["version"]);
+
+    let mut empty = Profile::new();
+    empty.set(Some("s"), "k", "v");
+    empty.set(None, "version", "2");
+    assert_eq!(empty.generate(), "version=2\r\n[s]\r\nk=v\r\n");
+
+    assert!(profile.remove_section(None));
+    assert_eq!(profile.generate(), "[s]\nk=v\n");
+}
+
+#[test]
+fn profile_set_and_remove() {
+    let mut profile = profile("[A]\nx=1\n\n; keep me\ny=2\n[b]\nz=3\n[a]\nx=4\n");
+
+    profile.set(Some("a"), "X", "5");
+    profile.set(Some("a"), "new", "6");
+    assert_eq!(profile.remove_key(Some("A"), "y"), Some(String::from("2")));
+    assert_eq!(profile.remove_key(Some("A"), "y"), None);
+    assert_eq!(profile.remove_key(Some("missing"), "y"), None);
+    assert_eq!(profile.generate(), "[A]\nX=5\n\n; keep me\nnew=6\n[b]\nz=3\n[a]\nx=4\n");
+
+    assert!(profile.remove_section(Some("a")));
+    assert!(!profile.remove_section(Some("a")));
+    assert_eq!(profile.generate(), "[b]\nz=3\n");
+}
+
+#[test]
+fn profile_line_endings() {
+    use crate::datas::LineEnding;
+
+    for &(content, ending) in &[("[a]\nb=1\n", LineEnding::Lf), ("[a]\r\nb=1\r\n", LineEnding::CrLf), ("[a]\rb=1\r", LineEnding::Cr)] {
+        let mut profile = profile(content);
+        assert_eq!(profile.line_ending(), ending);
+        assert_eq!(profile.get(Some("a"), "b"), Some("1"));
+        assert_eq!(profile.generate(), content);
+
+        profile.set(Some("a"), "c", "2");
+        assert_eq!(profile.generate(), format!("{}c=2{}", content, ending.as_str()));
+    }
+
+    assert_eq!(Profile::new().line_ending(), LineEnding::CrLf);
+    assert_eq!(profile("[a]").line_ending(), LineEnding::CrLf);
+}
@@ -0,0 +1,134 @@
+//! The data of a unit file parsed with [`Dialect::Systemd`](../../parse/enum.Dialect.html "parse::Dialect"), where a key may be assigned several times to build a list
+//! 
+//! # See
+//! `UnitFile` to look up a value or a list
+
+use crate::datas::{Identifier, Value};
+use crate::datas::multimap::OrderedMultimap;
+
+/// The variables of a systemd unit file in the order they were declared
+/// 
+/// A key assigned several times builds a list, like `ExecStartPre=`, and an empty assignment resets this list. A key which is not a list only keeps its last value. The section names and the keys are case-sensitive
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::{Identifier, Value};
+/// use mininip::dump::Dumper;
+/// use mininip::parse::{Dialect, Parser};
+/// 
+/// let mut parser = Parser::with_dialect(Dialect::Systemd);
+/// parser.parse_content("[Service]\nExecStartPre=/bin/true\nExecStart=/usr/bin/app \\\n    --verbose\nEnvironment=A=1\nEnvironment=\nEnvironment=B=2\n").unwrap();
+/// let mut unit = parser.unit_file();
+/// 
+/// assert_eq!(unit.get("Service", "ExecStart"), Some(&Value::Raw(String::from("/usr/bin/app      --verbose"))));
+/// assert_eq!(unit.get_list("Service", "Environment"), vec![&Value::Raw(String::from("B=2"))]);
+/// 
+/// let exec_start = Identifier::new(Some(String::from("Service")), String::from("ExecStart"));
+/// unit.set(exec_start, Value::Raw(String::from("/usr/bin/app --quiet")));
+/// 
+/// let mut dumper = Dumper::with_dialect(Dialect::Systemd);
+/// for (ident, value) in unit.into_entries() {
+///     dumper.dump(ident, value);
+/// }
+/// 
+/// let expected = "\
+/// [Service]\n\
+/// ExecStartPre=/bin/true\n\
+/// ExecStart=/usr/bin/app --quiet\n\
+/// Environment=A=1\n\
+/// Environment=\n\
+/// Environment=B=2\n";
+/// assert_eq!(dumper.generate(), expected);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnitFile {
+    entries: OrderedMultimap<Identifier>,
+}
+
+impl UnitFile {
+    /// Creates an empty `UnitFile`
+    pub fn new() -> UnitFile {
+        UnitFile {
+            entries: OrderedMultimap::new(),
+        }
+    }
+
+    /// Returns every assignment in the order they were declared, including the empty ones
+    pub fn entries(&self) -> &[(Identifier, Value)] {
+        self.entries.as_slice()
+    }
+
+    /// Consumes `self` and returns every assignment in the order they were declared, including the empty ones
+    pub fn into_entries(self) -> Vec<(Identifier, Value)> {
+        self.entries.into_vec()
+    }
+
+    /// Returns the names of the sections in the order of their first assignment
+    pub fn sections(&self) -> Vec<&str> {
+        let mut sections = Vec::new();
+        for (ident, _) in self.entries.as_slice() {
+            if let Some(section) = ident.section() {
+                if !sections.contains(&section) {
+                    sections.push(section);
+                }
+            }
+        }
+
+        sections
+    }
+
+    /// Adds an assignment of `ident` after the ones it may already have. An empty `value` resets the list
+    pub fn add(&mut self, ident: Identifier, value: Value) {
+        self.entries.add(ident, value);
+    }
+
+    /// Replaces every assignment of `ident` by a single one, which takes the place of the first one
+    pub fn set(&mut self, ident: Identifier, value: Value) {
+        self.entries.set(ident, value);
+    }
+
+    /// Removes every assignment of `ident` and returns their values
+    pub fn remove_all(&mut self, ident: &Identifier) -> Vec<Value> {
+        self.entries.remove_all(ident)
+    }
+
+    /// Returns the last value of `key` in `section`
+    /// 
+    /// # Return value
+    /// `Some(value)` if `key` is assigned
+    /// 
+    /// `None` if `key` is not assigned or if its last assignment is empty, which resets it
+    pub fn get(&self, section: &str, key: &str) -> Option<&Value> {
+        self.get_list(section, key).pop()
+    }
+
+    /// Returns the values of `key` in `section` assigned since its last empty assignment, in the order they were declared
+    pub fn get_list(&self, section: &str, key: &str) -> Vec<&Value> {
+        let mut list = Vec::new();
+        for (ident, value) in self.entries.as_slice() {
+            if ident.section() != Some(section) || ident.name() != key {
+                continue;
+            }
+
+            if is_reset(value) {
+                list.clear();
+            } else {
+                list.push(value);
+            }
+        }
+
+        list
+    }
+}
+
+/// Returns `true` if `value` is an empty assignment, which resets a list
+fn is_reset(value: &Value) -> bool {
+    match value {
+        Value::Raw(text) | Value::Str(text) => text.is_empty(),
+        _                                   => false,
+    }
+}
+
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,76 @@
+use crate::datas::systemd::*;
+use crate::datas::{Identifier, Value};
+
+fn ident(section: &str, key: &str) -> Identifier {
+    Identifier::new(Some(String::from(section)), String::from(key))
+}
+
+fn raw(value: &str) -> Value {
+    Value::Raw(String::from(value))
+}
+
+#[test]
+fn unit_file_get_list() {
+    let mut unit = UnitFile::new();
+    unit.add(ident("Service", "Environment"), raw("A=1"));
+    unit.add(ident("Service", "Environment"), raw("B=2"));
+    unit.add(ident("Service", "environment"), raw("C=3"));
+
+    assert_eq!(unit.get_list("Service", "Environment"), vec![&raw("A=1"), &raw("B=2")]);
+    assert_eq!(unit.get("Service", "Environment"), Some(&raw("B=2")));
+    assert_eq!(unit.get("service", "Environment"), None);
+}
+
+#[test]
+fn unit_file_reset() {
+    let mut unit = UnitFile::new();
+    unit.add(ident("Service", "ExecStart"), raw("/bin/a"));
+    unit.add(ident("Service", "ExecStart"), raw(""));
+    unit.add(ident("Service", "ExecStart"), raw("/bin/b"));
+    unit.add(ident("Service", "User"), raw("root"));
+    unit.add(ident("Service", "User"), raw(""));
+
+    assert_eq!(unit.get_list("Service", "ExecStart"), vec![&raw("/bin/b")]);
+    assert_eq!(unit.get("Service", "User"), None);
+    assert_eq!(unit.entries().len(), 5);
+}
+
+#[test]
+fn unit_file_set() {
+    let mut unit = UnitFile::new();
+    unit.add(ident("Unit", "Description"), raw("d"));
+    unit.add(ident("Service", "ExecStart"), raw(""));
+    unit.add(ident("Service", "Type"), raw("simple"));
+    unit.add(ident("Service", "ExecStart"), raw("/bin/a"));
+    unit.set(ident("Service", "ExecStart"), raw("/bin/b"));
+    unit.set(ident("Install", "WantedBy"), raw("multi-user.target"));
+
+    assert_eq!(unit.into_entries(), vec![
+        (ident("Unit", "Description"), raw("d")),
+        (ident("Service", "ExecStart"), raw("/bin/b")),
+        (ident("Service", "Type"), raw("simple")),
+        (ident("Install", "WantedBy"), raw("multi-user.target")),
+    ]);
+}
+
+#[test]
+fn unit_file_sections() {
+    let mut unit = UnitFile::new();
+    unit.add(Identifier::new(None, String::from("Global")), raw("1"));
+    unit.add(ident("Unit", "A"), raw("1"));
+    unit.add(ident("Service", "B"), raw("1"));
+    unit.add(ident("Unit", "C"), raw("1"));
+
+    assert_eq!(unit.sections(), vec!["Unit", "Service"]);
+}
+
+#[test]
+fn unit_file_remove_all() {
+    let mut unit = UnitFile::new();
+    unit.add(ident("Service", "ExecStartPre"), raw("/bin/a"));
+    unit.add(ident("Service", "Type"), raw("simple"));
+    unit.add(ident("Service", "ExecStartPre"), raw("/bin/b"));
+
+    assert_eq!(unit.remove_all(&ident("Service", "ExecStartPre")), vec![raw("/bin/a"), raw("/bin/b")]);
+    assert_eq!(unit.entries(), &[(ident("Service", "Type"), raw("simple"))]);
+}
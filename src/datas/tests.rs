@@ -1,5 +1,6 @@
 use crate::datas::*;
 use crate::errors::Error;
+use std::time::Duration;
 
 #[test]
 fn value_display() {
@@ -110,6 +111,118 @@ fn value_parse_bool_no() {
     assert_eq!(val, Value::Bool(false));
 }
 
+#[test]
+fn value_parse_bool_true_false() {
+    assert_eq!(Value::parse("true").unwrap(), Value::Bool(true));
+    assert_eq!(Value::parse("false").unwrap(), Value::Bool(false));
+}
+
+#[test]
+fn value_parse_bool_case_insensitive() {
+    assert_eq!(Value::parse("True").unwrap(), Value::Bool(true));
+    assert_eq!(Value::parse("OFF").unwrap(), Value::Bool(false));
+}
+
+#[test]
+fn value_parse_bool_custom_vocabulary() {
+    let vocabulary = BoolVocabulary::new(vec![String::from("1")], vec![String::from("0")], false);
+
+    assert_eq!(Value::parse_with_vocabulary("1", &vocabulary, TextMode::Ascii).unwrap(), Value::Bool(true));
+    assert_eq!(Value::parse_with_vocabulary("0", &vocabulary, TextMode::Ascii).unwrap(), Value::Bool(false));
+    assert_eq!(Value::parse_with_vocabulary("on", &vocabulary, TextMode::Ascii).unwrap(), Value::Raw(String::from("on")));
+}
+
+#[test]
+#[should_panic]
+fn bool_vocabulary_conflict() {
+    let _vocabulary = BoolVocabulary::new(vec![String::from("Yes")], vec![String::from("yes")], false);
+}
+
+#[test]
+fn value_dump_bool_styles() {
+    assert_eq!(Value::Bool(true).dump(), "on");
+    assert_eq!(Value::Bool(false).dump_with_style(BoolStyle::TrueFalse, TextMode::Ascii), "false");
+    assert_eq!(Value::Bool(true).dump_with_style(BoolStyle::EnabledDisabled, TextMode::Ascii), "enabled");
+}
+
+#[test]
+fn value_text_modes() {
+    assert_eq!(Value::parse_with("30 €", TextMode::Utf8).unwrap(), Value::Raw(String::from("30 €")));
+    assert_eq!(Value::parse_with("'caf\\x0000e9'", TextMode::Utf8).unwrap(), Value::Str(String::from("café")));
+    assert!(Value::parse_with("30 €", TextMode::Ascii).is_err());
+
+    let lexeme = Lexeme::new(String::from("\"a\""), Value::Str(String::from("a")));
+    assert_eq!(lexeme.dump(&Value::Str(String::from("é;")), BoolStyle::default(), TextMode::Utf8), "\"é\\;\"");
+    assert_eq!(lexeme.dump(&Value::Str(String::from("é;")), BoolStyle::default(), TextMode::Ascii), "\"\\x0000e9\\;\"");
+}
+
+#[test]
+fn value_parse_duration() {
+    let val = Value::parse("30s").unwrap();
+
+    assert_eq!(val, Value::Duration(Duration::from_secs(30)));
+}
+
+#[test]
+fn value_parse_size() {
+    let val = Value::parse("64KiB").unwrap();
+
+    assert_eq!(val, Value::Size(64 * 1024));
+}
+
+#[test]
+fn value_parse_size_overflow() {
+    match Value::parse("99999999999999999999KiB") {
+        Ok(_)                   => panic!("This value overflows and should not be accepted"),
+        Err(Error::Overflow(_)) => {},
+        Err(err)                => panic!("Invalid error value {:?}", err),
+    }
+}
+
+#[test]
+fn value_dump_duration_and_size() {
+    assert_eq!(Value::Duration(Duration::from_secs(300)).dump(), "5m");
+    assert_eq!(Value::Size(2_000_000_000).dump(), "2GB");
+    assert_eq!(Value::parse(&Value::Duration(Duration::from_millis(1500)).dump()).unwrap(), Value::Duration(Duration::from_millis(1500)));
+}
+
+#[test]
+fn value_parse_datetime() {
+    let val = Value::parse("2020-04-28T12:30:00+02:00").unwrap();
+
+    assert_eq!(val, Value::DateTime(datetime::DateTime::parse("2020-04-28T12:30:00+02:00").unwrap()));
+}
+
+#[test]
+fn value_parse_date() {
+    let val = Value::parse("2020-04-28").unwrap();
+
+    assert_eq!(val, Value::Date(datetime::Date::new(2020, 4, 28)));
+}
+
+#[test]
+fn value_parse_time_escaped_or_not() {
+    let expected = Value::Time(datetime::Time::new(12, 30, 0, 0));
+
+    assert_eq!(Value::parse("12:30:00").unwrap(), expected);
+    assert_eq!(Value::parse(r"12\:30\:00").unwrap(), expected);
+}
+
+#[test]
+fn value_parse_invalid_time() {
+    // Not a time so the colons must be escaped
+    assert!(Value::parse("25:30:00").is_err());
+    assert_eq!(Value::parse(r"25\:30\:00").unwrap(), Value::Raw(String::from("25:30:00")));
+}
+
+#[test]
+fn value_dump_datetime_round_trip() {
+    let val = Value::DateTime(datetime::DateTime::parse("2020-04-28T12:30:00.25Z").unwrap());
+
+    assert_eq!(val.dump(), "2020-04-28T12:30:00.25Z");
+    assert_eq!(Value::parse(&val.dump()).unwrap(), val);
+}
+
 #[test]
 fn value_parse_err() {
     let val = Value::parse(r"Hello \p");
@@ -210,3 +323,79 @@ fn identifier_format_without_section() {
 
     assert_eq!(format!("{}", ident), variable);
 }
+
+#[test]
+fn lexeme_quote() {
+    assert_eq!(Lexeme::new(String::from("'a'"), Value::Str(String::from("a"))).quote(), Some('\''));
+    assert_eq!(Lexeme::new(String::from("\"a\""), Value::Str(String::from("a"))).quote(), Some('"'));
+    assert_eq!(Lexeme::new(String::from("a"), Value::Raw(String::from("a"))).quote(), None);
+    assert_eq!(Lexeme::new(String::new(), Value::Raw(String::new())).quote(), None);
+}
+
+#[test]
+fn lexeme_dump() {
+    let lexeme = Lexeme::new(String::from("010"), Value::Int(10));
+
+    assert_eq!(lexeme.dump(&Value::Int(10), BoolStyle::default(), TextMode::Ascii), "010");
+    assert_eq!(lexeme.dump(&Value::Int(11), BoolStyle::default(), TextMode::Ascii), "11");
+    assert_eq!(lexeme.dump(&Value::Str(String::from("x")), BoolStyle::default(), TextMode::Ascii), "'x'");
+}
+
+#[test]
+fn identifier_policy_ascii() {
+    let policy = IdentifierPolicy::default();
+
+    assert_eq!(policy, IdentifierPolicy::Ascii);
+    assert!(policy.is_valid("contains spaces inside"));
+    assert!(!policy.is_valid("größe"));
+    assert_eq!(policy.normalize("e\u{301}"), "e\u{301}");
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn identifier_policy_unicode_xid() {
+    let policy = IdentifierPolicy::UnicodeXid;
+
+    assert!(policy.is_supported());
+    assert!(policy.is_valid("größe"));
+    assert!(policy.is_valid("名前"));
+    assert!(policy.is_valid("$starts-with-$"));
+    assert!(policy.is_valid("contains spaces inside"));
+    assert!(!policy.is_valid("1st"));
+    assert!(!policy.is_valid("_starts_with_underscore"));
+    assert!(!policy.is_valid("emoji_\u{1f600}"));
+    assert!(!policy.is_valid("semi;colon"));
+    assert!(!policy.is_valid(""));
+}
+
+#[cfg(not(feature = "unicode"))]
+#[test]
+fn identifier_policy_unicode_xid_unsupported() {
+    let policy = IdentifierPolicy::UnicodeXid;
+
+    assert!(!policy.is_supported());
+    assert!(!policy.is_valid("ascii"));
+    assert!(!policy.is_valid("größe"));
+    assert_eq!(policy.normalize("e\u{301}"), "e\u{301}");
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn identifier_with_policy_normalizes() {
+    let composed = Identifier::with_policy(Some(String::from("caf\u{e9}")), String::from("cl\u{e9}"), IdentifierPolicy::UnicodeXid);
+    let decomposed = Identifier::with_policy(Some(String::from("cafe\u{301}")), String::from("cle\u{301}"), IdentifierPolicy::UnicodeXid);
+
+    assert_eq!(composed, decomposed);
+    assert_eq!(decomposed.section(), Some("caf\u{e9}"));
+    assert_eq!(decomposed.name(), "cl\u{e9}");
+
+    let mut set = std::collections::HashSet::new();
+    set.insert(composed);
+    assert!(set.contains(&decomposed));
+}
+
+#[test]
+#[should_panic]
+fn identifier_with_policy_invalid() {
+    Identifier::with_policy(None, String::from("größe"), IdentifierPolicy::Ascii);
+}
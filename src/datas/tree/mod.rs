@@ -12,15 +12,16 @@ use std::collections::{HashMap, hash_map};
 /// 
 /// # Example
 /// ```
-/// use mininip::datas{Identifier, Value, self};
-/// use datas::tree::Tree;
+/// use mininip::datas::tree::Tree;
 /// use mininip::parse::parse_file;
 /// 
-/// let tree = Tree::from_data(parse_file("good.ini").unwrap());
+/// let tree = Tree::from(parse_file("good.ini").unwrap());
 /// for i in tree.sections() {
-///     println!("[{}] ; Section {}", i, i);
+///     if let Some(name) = i.name() {
+///         println!("[{}] ; Section {}", name, name);
+///     }
 ///     for j in i.keys() {
-///         println!("{}={} ; key {}", j.ident().name(), j.value(), j.ident().name());
+///         println!("{}={} ; key {}", j.name(), tree.get_data()[&j], j.name());
 ///     }
 /// }
 /// ```
@@ -54,7 +55,7 @@ impl From<HashMap<Identifier, Value>> for Tree {
     fn from(data: HashMap<Identifier, Value>) -> Tree {
         Tree {
             cache: Cache::from(&data),
-            data: data,
+            data,
         }
     }
 }
@@ -79,7 +80,7 @@ impl<'a> Iterator for SectionIterator<'a> {
         if !self.awaited {
             self.awaited = true;
 
-            if self.target.cache.keys.get(&None).is_some() {
+            if self.target.cache.keys.contains_key(&None) {
                 return Some(Section {
                     ident: None,
                     target: self.target,
@@ -89,7 +90,7 @@ impl<'a> Iterator for SectionIterator<'a> {
 
         let ident = self.iterator.next()?;
         Some(Section {
-            ident: Some(&ident),
+            ident: Some(ident),
             target: self.target,
         })
     }
@@ -105,7 +106,7 @@ pub struct Section<'a> {
 impl<'a> Section<'a> {
     /// Returns the identifier (name) of this section
     pub fn name(&self) -> Option<&'a str> {
-        self.ident.clone()
+        self.ident
     }
 
     /// Returns an iterator over the keys of this section
@@ -128,10 +129,7 @@ impl<'a> Section<'a> {
     /// Returns the identifier of this section like it must be passed to an
     /// `Identifier`: an `Option<String>` instead of an `Option<&str>`
     pub fn name_owned(&self) -> Option<String> {
-        match self.ident {
-            Some(val) => Some(String::from(val)),
-            None      => None,
-        }
+        self.ident.map(String::from)
     }
 }
 
@@ -169,10 +167,7 @@ impl From<&HashMap<Identifier, Value>> for Cache {
         let mut keys = HashMap::<_, Vec<String>>::new();
 
         for i in data.keys() {
-            let section_name = match i.section() {
-                Some(val) => Some(String::from(val)),
-                None      => None,
-            };
+            let section_name = i.section().map(String::from);
 
             match keys.entry(section_name.clone()) {
                 hash_map::Entry::Occupied(mut entry) => entry.get_mut().push(String::from(i.name())),
@@ -207,5 +202,8 @@ impl From<&HashMap<Identifier, Value>> for Cache {
 }
 
 
+#[cfg(any(feature = "serde_json", feature = "toml"))]
+pub(crate) mod nested;
+
 #[cfg(test)]
 mod tests;
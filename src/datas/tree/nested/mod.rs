@@ -0,0 +1,109 @@
+//! The conversion between a [`Tree`](../struct.Tree.html "datas::tree::Tree") and a nested document like JSON or TOML, where the keys of the global section are the scalar items of the root map while each section is a map in the root one
+//! 
+//! Sections can't be nested so a map in a section is rejected. An empty map in the root one is an empty section, which is not kept since a `Tree` only knows the sections of its keys
+
+use crate::datas::{Identifier, Value};
+use crate::datas::tree::Tree;
+use std::collections::HashMap;
+
+/// A map of a nested document, like a JSON object or a TOML table, whose items are either scalars or maps
+pub(crate) trait Map: Default {
+    /// The type of the items of the map
+    type Item;
+    /// The error returned when the document and the `Tree` can't be converted into each other
+    type Error;
+    /// An iterator over the entries of the map
+    type Entries<'a>: Iterator<Item = (&'a String, &'a Self::Item)> where Self: 'a;
+
+    /// Iterates over the entries of `self`
+    fn entries(&self) -> Self::Entries<'_>;
+
+    /// Returns whether `self` has an item named `key`
+    fn contains_key(&self, key: &str) -> bool;
+
+    /// Inserts `item` as `key`
+    fn insert(&mut self, key: String, item: Self::Item);
+
+    /// Returns `item` as a map if it is one
+    fn as_map(item: &Self::Item) -> Option<&Self>;
+
+    /// Consumes `self` and returns it as an item of a map
+    fn into_item(self) -> Self::Item;
+
+    /// Returns the path of the item `key` in the map at `parent`, or in the root map if `parent` is `None`
+    fn path(parent: Option<&str>, key: &str) -> String;
+
+    /// Returns the error for the item at `path` whose name is not a valid [`Identifier`](../../struct.Identifier.html "datas::Identifier")
+    fn invalid_name(path: String) -> Self::Error;
+
+    /// Returns the error for the section at `path` which has the same name as a key of the global section
+    fn name_clash(path: String) -> Self::Error;
+
+    /// Returns the error for the map at `path` which is nested in a section
+    fn nested(path: String) -> Self::Error;
+}
+
+/// Converts `tree` into a map, converting each value with `convert`
+/// 
+/// # Return value
+/// `Ok(map)` in case of success
+/// 
+/// `Err(error)` if a key of the global section has the name of a section or with the first error returned by `convert`
+pub(crate) fn from_tree<M: Map>(tree: &Tree, mut convert: impl FnMut(&Identifier, &Value) -> Result<M::Item, M::Error>) -> Result<M, M::Error> {
+    let data = tree.get_data();
+    let mut root = M::default();
+
+    for section in tree.sections() {
+        let mut map = M::default();
+        for ident in section.keys() {
+            let item = convert(&ident, &data[&ident])?;
+            map.insert(String::from(ident.name()), item);
+        }
+
+        match section.name() {
+            Some(name) if root.contains_key(name) => return Err(M::name_clash(M::path(None, name))),
+            Some(name)                            => root.insert(String::from(name), map.into_item()),
+            None                                  => root = map,
+        }
+    }
+
+    Ok(root)
+}
+
+/// Converts `root` into a [`Tree`](../struct.Tree.html "datas::tree::Tree"), converting each scalar item with `convert` which receives its path
+/// 
+/// # Return value
+/// `Ok(tree)` in case of success
+/// 
+/// `Err(error)` with the first invalid name or nested map found or with the first error returned by `convert`
+pub(crate) fn to_tree<M: Map>(root: &M, mut convert: impl FnMut(&str, &M::Item) -> Result<Value, M::Error>) -> Result<Tree, M::Error> {
+    let mut data = HashMap::new();
+
+    for (name, item) in root.entries() {
+        let path = M::path(None, name);
+        if !Identifier::is_valid(name) {
+            return Err(M::invalid_name(path));
+        }
+
+        let section = match M::as_map(item) {
+            Some(section) => section,
+            None          => {
+                data.insert(Identifier::new(None, name.clone()), convert(&path, item)?);
+                continue;
+            },
+        };
+
+        for (key, item) in section.entries() {
+            let path = M::path(Some(&path), key);
+            if !Identifier::is_valid(key) {
+                return Err(M::invalid_name(path));
+            } else if M::as_map(item).is_some() {
+                return Err(M::nested(path));
+            }
+
+            data.insert(Identifier::new(Some(name.clone()), key.clone()), convert(&path, item)?);
+        }
+    }
+
+    Ok(Tree::from(data))
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::approx_constant)]
+
 use crate::datas::{tree::*, Identifier, Value};
 use crate::parse::Parser;
 
@@ -0,0 +1,523 @@
+//! Deserializes INI data into any type implementing [`serde::Deserialize`](https://docs.rs/serde "serde"). Requires the `serde` feature
+//! 
+//! # Mapping
+//! - the keys of the global section are mapped to the top-level fields
+//! - each named section is mapped to a top-level field which must be a structure (or a map) of its keys
+//! - a `Value` is mapped to the primitive type it holds. A `Duration`, a `Date`, a `Time` or a `DateTime` is given as a string (see [`Value::dump`](../datas/enum.Value.html#method.dump "datas::Value::dump"))
+//! - a string field receives the text written in the file, like `1.10` or `007`, whatever the type of its `Value`. A [`Tree`](../datas/tree/struct.Tree.html "datas::tree::Tree") does not keep this text so [`from_tree`](fn.from_tree.html "de::from_tree") only reads a `Raw` or a `Str` into a string field
+//! - a missing key is mapped to `None` for an `Option`, an empty `Raw` value too
+//! - a `Raw` or `Str` value is mapped to a unit enum variant by its name
+//! - a `Raw` or `Str` value is mapped to a sequence by splitting it on its commas `,`. Each item is then parsed as a `Value`
+//! 
+//! # Example
+//! ```
+//! use serde::Deserialize;
+//! 
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     name: String,
+//!     server: Server,
+//! }
+//! 
+//! #[derive(Deserialize)]
+//! struct Server {
+//!     port: u16,
+//!     verbose: Option<bool>,
+//! }
+//! 
+//! let config: Config = mininip::from_str("name = 'main'\n[server]\nport = 8080\n").unwrap();
+//! assert_eq!(config.name, "main");
+//! assert_eq!(config.server.port, 8080);
+//! assert_eq!(config.server.verbose, None);
+//! ```
+
+use std::collections::HashMap;
+use std::error;
+use std::fmt::{self, Display};
+use std::io::Read;
+use serde::de::{self, Deserialize, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use crate::datas::{self, Identifier, Lexeme, TextMode, Value};
+use crate::datas::encoding::{self, Encoding};
+use crate::datas::tree::Tree;
+use crate::errors::ParseFileError;
+use crate::parse::Parser;
+
+/// An error happening while deserializing INI data
+/// 
+/// It names the [`Identifier`](../datas/struct.Identifier.html "datas::Identifier") of the value which could not be deserialized and its line number when they are known
+#[derive(Debug)]
+pub struct Error {
+    message: String,
+    identifier: Option<Identifier>,
+    line: Option<usize>,
+}
+
+impl error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.identifier, self.line) {
+            (Some(ident), Some(line)) => write!(f, "{} (at {}, line {})", self.message, ident, line),
+            (Some(ident), None)       => write!(f, "{} (at {})", self.message, ident),
+            (None, Some(line))        => write!(f, "{} (line {})", self.message, line),
+            (None, None)              => f.write_str(&self.message),
+        }
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Error {
+        Error {
+            message: format!("{}", msg),
+            identifier: None,
+            line: None,
+        }
+    }
+}
+
+impl From<ParseFileError> for Error {
+    fn from(err: ParseFileError) -> Error {
+        Error {
+            message: format!("{}", err),
+            identifier: None,
+            line: None,
+        }
+    }
+}
+
+impl Error {
+    /// Returns the message describing the error
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the identifier of the value which could not be deserialized, if known
+    pub fn identifier(&self) -> Option<&Identifier> {
+        self.identifier.as_ref()
+    }
+
+    /// Returns the number of the line (starting from 1) where the error occured, if known
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// Attaches `identifier` and its line to `self` unless it already names an identifier
+    fn locate(mut self, identifier: &Identifier, source: Option<&Source>) -> Error {
+        if self.identifier.is_none() {
+            self.line = source.and_then(|source| source.locations.get(identifier).copied());
+            self.identifier = Some(identifier.clone());
+        }
+
+        self
+    }
+}
+
+
+/// Deserializes an instance of `T` from a string containing INI data
+/// 
+/// # Return value
+/// `Ok(value)` in case of success
+/// 
+/// `Err(error)` if `content` is not valid INI or does not match `T`
+pub fn from_str<T: DeserializeOwned>(content: &str) -> Result<T, Error> {
+    let mut parser = Parser::new();
+    parser.set_keep_lexemes(true);
+    if let Err(err) = parser.parse_content(content) {
+        let mut err = Error::from(ParseFileError::from(err));
+        err.line = Some(parser.line_number());
+        return Err(err);
+    }
+
+    let source = Source {
+        locations: parser.locations().clone(),
+        lexemes: parser.lexemes().clone(),
+    };
+    let tree = Tree::from(parser.data());
+
+    T::deserialize(TreeDeserializer::new(&tree, Some(&source)))
+}
+
+/// Deserializes an instance of `T` from a reader of INI data, such as a file
+/// 
+/// The data is read in UTF-8 or in the encoding of its byte order mark, like [`parse_file`](../parse/fn.parse_file.html "parse::parse_file") does
+/// 
+/// # Return value
+/// `Ok(value)` in case of success
+/// 
+/// `Err(error)` if `reader` fails, can't be decoded, does not contain valid INI or does not match `T`
+pub fn from_reader<R: Read, T: DeserializeOwned>(mut reader: R) -> Result<T, Error> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)
+          .map_err(|err| Error::from(ParseFileError::from(err)))?;
+    let (content, _, _) = encoding::decode(&bytes, Encoding::Utf8)
+                                   .map_err(|err| Error::from(ParseFileError::from(err)))?;
+
+    from_str(&content)
+}
+
+/// Deserializes an instance of `T` from a [`Tree`](../datas/tree/struct.Tree.html "datas::tree::Tree"). The strings may be borrowed from `tree`
+/// 
+/// # Return value
+/// `Ok(value)` in case of success
+/// 
+/// `Err(error)` if `tree` does not match `T`
+pub fn from_tree<'de, T: Deserialize<'de>>(tree: &'de Tree) -> Result<T, Error> {
+    T::deserialize(TreeDeserializer::new(tree, None))
+}
+
+
+/// What is known about the text parsed, which a `Tree` does not keep
+struct Source {
+    /// The line of each key
+    locations: HashMap<Identifier, usize>,
+    /// The text of each value
+    lexemes: HashMap<Identifier, Lexeme>,
+}
+
+/// A key of the top-level map: either a key of the global section or a named section
+enum Entry<'de> {
+    Key(&'de Identifier, &'de Value),
+    Section(&'de str),
+}
+
+/// Deserializes a whole `Tree` as a map of its global keys and its named sections
+struct TreeDeserializer<'de, 'a> {
+    tree: &'de Tree,
+    source: Option<&'a Source>,
+}
+
+impl<'de, 'a> TreeDeserializer<'de, 'a> {
+    fn new(tree: &'de Tree, source: Option<&'a Source>) -> TreeDeserializer<'de, 'a> {
+        TreeDeserializer {
+            tree,
+            source,
+        }
+    }
+
+    /// Lists the keys of the section `name`, bound to their values
+    fn keys(&self, name: Option<&str>) -> Vec<(&'de Identifier, &'de Value)> {
+        let data = self.tree.get_data();
+
+        let mut keys = data.iter()
+                           .filter(|(ident, _)| ident.section() == name)
+                           .collect::<Vec<_>>();
+        keys.sort_unstable_by(|(a, _), (b, _)| a.name().cmp(b.name()));
+
+        keys
+    }
+}
+
+impl<'de, 'a> de::Deserializer<'de> for TreeDeserializer<'de, 'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let mut entries = self.keys(None)
+                              .into_iter()
+                              .map(|(ident, value)| Entry::Key(ident, value))
+                              .collect::<Vec<_>>();
+
+        for i in self.tree.sections() {
+            if let Some(name) = i.name() {
+                entries.push(Entry::Section(name));
+            }
+        }
+
+        visitor.visit_map(TreeMapAccess {
+            deserializer: &self,
+            entries: entries.into_iter(),
+            next: None,
+        })
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Iterates over the top-level map of a `Tree`
+struct TreeMapAccess<'de, 'a, 'b> {
+    deserializer: &'b TreeDeserializer<'de, 'a>,
+    entries: std::vec::IntoIter<Entry<'de>>,
+    next: Option<Entry<'de>>,
+}
+
+impl<'de, 'a, 'b> MapAccess<'de> for TreeMapAccess<'de, 'a, 'b> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Error> {
+        let entry = match self.entries.next() {
+            Some(val) => val,
+            None      => return Ok(None),
+        };
+
+        let key = match &entry {
+            Entry::Key(ident, _)  => ident.name(),
+            Entry::Section(name)  => name,
+        };
+        self.next = Some(entry);
+
+        seed.deserialize(de::value::BorrowedStrDeserializer::new(key)).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        match self.next.take().expect("`next_value_seed` must be called after `next_key_seed`") {
+            Entry::Key(ident, value) => seed.deserialize(ValueDeserializer {
+                ident,
+                value,
+                source: self.deserializer.source,
+            }),
+            Entry::Section(name)     => seed.deserialize(SectionDeserializer {
+                keys: self.deserializer.keys(Some(name)),
+                source: self.deserializer.source,
+            }),
+        }
+    }
+}
+
+
+/// Deserializes a named section as a map of its keys
+struct SectionDeserializer<'de, 'a> {
+    keys: Vec<(&'de Identifier, &'de Value)>,
+    source: Option<&'a Source>,
+}
+
+impl<'de, 'a> de::Deserializer<'de> for SectionDeserializer<'de, 'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(SectionMapAccess {
+            keys: self.keys.into_iter(),
+            next: None,
+            source: self.source,
+        })
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Iterates over the keys of a named section
+struct SectionMapAccess<'de, 'a> {
+    keys: std::vec::IntoIter<(&'de Identifier, &'de Value)>,
+    next: Option<(&'de Identifier, &'de Value)>,
+    source: Option<&'a Source>,
+}
+
+impl<'de, 'a> MapAccess<'de> for SectionMapAccess<'de, 'a> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Error> {
+        let (ident, value) = match self.keys.next() {
+            Some(val) => val,
+            None      => return Ok(None),
+        };
+        self.next = Some((ident, value));
+
+        seed.deserialize(de::value::BorrowedStrDeserializer::new(ident.name())).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let (ident, value) = self.next.take().expect("`next_value_seed` must be called after `next_key_seed`");
+
+        seed.deserialize(ValueDeserializer {
+            ident,
+            value,
+            source: self.source,
+        })
+    }
+}
+
+
+/// Deserializes a single `Value`, naming its identifier in case of error
+struct ValueDeserializer<'de, 'a> {
+    ident: &'de Identifier,
+    value: &'de Value,
+    source: Option<&'a Source>,
+}
+
+impl<'de, 'a> ValueDeserializer<'de, 'a> {
+    /// Attaches the identifier deserialized to the error returned, if any
+    fn locate<T>(&self, result: Result<T, Error>) -> Result<T, Error> {
+        result.map_err(|err| err.locate(self.ident, self.source))
+    }
+
+    /// Returns the text of the value as written in the file without its escape sequences, if known
+    fn text(&self) -> Option<String> {
+        let lexeme = self.source?.lexemes.get(self.ident)?;
+
+        match Value::parse_uninferred(lexeme.text(), TextMode::Ascii) {
+            Ok(Value::Raw(text)) | Ok(Value::Str(text)) => Some(text),
+            _                                           => None,
+        }
+    }
+}
+
+impl<'de, 'a> de::Deserializer<'de> for ValueDeserializer<'de, 'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let result = match self.value {
+            Value::Raw(string)   => visitor.visit_borrowed_str(string),
+            Value::Str(string)   => visitor.visit_borrowed_str(string),
+            Value::Int(number)   => visitor.visit_i64(*number),
+            Value::Float(number) => visitor.visit_f64(*number),
+            Value::Bool(value)   => visitor.visit_bool(*value),
+            Value::Size(size)    => visitor.visit_u64(*size),
+            value                => visitor.visit_string(value.dump()),
+        };
+
+        self.locate(result)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let result = match (self.value, self.text()) {
+            (Value::Raw(string), _) | (Value::Str(string), _) => visitor.visit_borrowed_str(string),
+            (_, Some(text))                                   => visitor.visit_string(text),
+            (value, None)                                     => Err(de::Error::invalid_type(de::Unexpected::Other(value.type_name()), &"a string")),
+        };
+
+        self.locate(result)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Raw(string) if string.is_empty() => visitor.visit_none(),
+            _                                       => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let items = datas::split_list(self.value);
+
+        let items = items.into_iter()
+                         .map(|(text, item)| (text.map(String::from).or_else(|| self.text()), item))
+                         .collect::<Vec<_>>();
+        let result = visitor.visit_seq(ItemsSeqAccess {
+            items: items.into_iter(),
+        });
+
+        self.locate(result)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value, Error> {
+        let result = match self.value {
+            Value::Raw(string) | Value::Str(string) => visitor.visit_enum(de::value::BorrowedStrDeserializer::new(string)),
+            value                                   => Err(de::Error::invalid_type(de::Unexpected::Other(value.type_name()), &"a variant name")),
+        };
+
+        self.locate(result)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char
+        bytes byte_buf unit unit_struct tuple_struct map struct identifier
+    }
+}
+
+/// Iterates over the items of a comma-separated list
+struct ItemsSeqAccess {
+    items: std::vec::IntoIter<(Option<String>, Value)>,
+}
+
+impl<'de> SeqAccess<'de> for ItemsSeqAccess {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Error> {
+        match self.items.next() {
+            Some((text, item)) => seed.deserialize(ItemDeserializer { text, item }).map(Some),
+            None               => Ok(None),
+        }
+    }
+}
+
+/// Deserializes an item of a comma-separated list. Unlike `ValueDeserializer`, it owns its `Value`
+struct ItemDeserializer {
+    /// The text of the item in the list, which is `None` if the value is not a list written as a string
+    text: Option<String>,
+    item: Value,
+}
+
+impl<'de> de::Deserializer<'de> for ItemDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.item {
+            Value::Raw(string)   => visitor.visit_string(string),
+            Value::Str(string)   => visitor.visit_string(string),
+            Value::Int(number)   => visitor.visit_i64(number),
+            Value::Float(number) => visitor.visit_f64(number),
+            Value::Bool(value)   => visitor.visit_bool(value),
+            Value::Size(size)    => visitor.visit_u64(size),
+            value                => visitor.visit_string(value.dump()),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match (self.item, self.text) {
+            (Value::Raw(string), _) | (Value::Str(string), _) => visitor.visit_string(string),
+            (_, Some(text))                                   => visitor.visit_string(text),
+            (value, None)                                     => Err(de::Error::invalid_type(de::Unexpected::Other(value.type_name()), &"a string")),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value, Error> {
+        match self.item {
+            Value::Raw(string) | Value::Str(string) => visitor.visit_enum(string.into_deserializer()),
+            value                                   => Err(de::Error::invalid_type(de::Unexpected::Other(value.type_name()), &"a variant name")),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+
+#[cfg(test)]
+mod tests;
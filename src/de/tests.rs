@@ -0,0 +1,179 @@
+use crate::de::*;
+use crate::datas::Identifier;
+use crate::datas::tree::Tree;
+use crate::parse::{parse_file, Parser};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Good {
+    author: String,
+    version_major: u32,
+    numbers: Numbers,
+    symbols: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Numbers {
+    one: i64,
+    two: f64,
+    three: u8,
+    four: Option<i64>,
+}
+
+#[test]
+fn from_tree_good_file() {
+    let tree = Tree::from(parse_file("good.ini").unwrap());
+    let good: Good = from_tree(&tree).unwrap();
+
+    assert_eq!(good.author, "Boris DRYKONINGEN");
+    assert_eq!(good.version_major, 0);
+    assert_eq!(good.numbers, Numbers { one: 1, two: 2.0, three: 3, four: None });
+    assert_eq!(good.symbols["smiley"], "\u{263a}");
+    assert_eq!(good.symbols["semicolon"], ";");
+}
+
+#[test]
+fn from_tree_borrows() {
+    #[derive(Deserialize)]
+    struct Borrowed<'a> {
+        name: &'a str,
+    }
+
+    let tree = Tree::from(crate::parse::Parser::new().data());
+    assert!(from_tree::<Borrowed>(&tree).is_err());
+
+    let mut parser = crate::parse::Parser::new();
+    parser.parse_line("name = Ferris").unwrap();
+    let tree = Tree::from(parser.data());
+    let borrowed: Borrowed = from_tree(&tree).unwrap();
+
+    assert_eq!(borrowed.name, "Ferris");
+}
+
+#[test]
+fn from_str_enums_and_sequences() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "lowercase")]
+    enum Level {
+        Debug,
+        Info,
+    }
+
+    #[derive(Deserialize)]
+    struct Log {
+        level: Level,
+        levels: Vec<Level>,
+        ports: Vec<u16>,
+        empty: Vec<u16>,
+        pair: (String, bool),
+    }
+
+    let log: Log = from_str("level = info\nlevels = debug, info\nports = 80, 443\nempty =\npair = abc, yes").unwrap();
+
+    assert_eq!(log.level, Level::Info);
+    assert_eq!(log.levels, vec![Level::Debug, Level::Info]);
+    assert_eq!(log.ports, vec![80, 443]);
+    assert_eq!(log.empty, Vec::<u16>::new());
+    assert_eq!(log.pair, (String::from("abc"), true));
+}
+
+#[test]
+fn from_str_option_and_strings() {
+    #[derive(Deserialize)]
+    struct Config {
+        empty: Option<i64>,
+        version: String,
+        timeout: String,
+    }
+
+    let config: Config = from_str("empty =\nversion = 1.10\ntimeout = 30s").unwrap();
+
+    assert_eq!(config.empty, None);
+    assert_eq!(config.version, "1.10");
+    assert_eq!(config.timeout, "30s");
+}
+
+#[test]
+fn from_str_strings_keep_their_text() {
+    #[derive(Deserialize)]
+    struct Config {
+        a: String,
+        b: String,
+        c: String,
+        d: String,
+        e: String,
+        list: Vec<String>,
+        single: Vec<String>,
+    }
+
+    let config: Config = from_str("a = yes\nb = 1.10\nc = 007\nd = 1e3\ne = 12\\:30\\:00\nlist = yes, 1.10, 007\nsingle = 007").unwrap();
+
+    assert_eq!(config.a, "yes");
+    assert_eq!(config.b, "1.10");
+    assert_eq!(config.c, "007");
+    assert_eq!(config.d, "1e3");
+    assert_eq!(config.e, "12:30:00");
+    assert_eq!(config.list, vec!["yes", "1.10", "007"]);
+    assert_eq!(config.single, vec!["007"]);
+}
+
+#[test]
+fn from_tree_rejects_typed_strings() {
+    #[derive(Debug, Deserialize)]
+    struct Config {
+        #[allow(dead_code)]
+        version: String,
+    }
+
+    let mut parser = Parser::new();
+    parser.parse_content("version = 1.10").unwrap();
+    let tree = Tree::from(parser.data());
+
+    let err = from_tree::<Config>(&tree).unwrap_err();
+    assert_eq!(err.identifier(), Some(&Identifier::new(None, String::from("version"))));
+}
+
+#[test]
+fn from_str_error_names_identifier_and_line() {
+    #[derive(Debug, Deserialize)]
+    struct Server {
+        #[allow(dead_code)]
+        port: u8,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Config {
+        #[allow(dead_code)]
+        server: Server,
+    }
+
+    let err = from_str::<Config>("; comment\n[server]\nport = 8080\n").unwrap_err();
+
+    assert_eq!(err.identifier(), Some(&Identifier::new(Some(String::from("server")), String::from("port"))));
+    assert_eq!(err.line(), Some(3));
+    assert!(format!("{}", err).ends_with("(at server.port, line 3)"));
+}
+
+#[test]
+fn from_str_parse_error() {
+    #[derive(Debug, Deserialize)]
+    struct Empty {}
+
+    let err = from_str::<Empty>("a = 1\nb ! 2\n").unwrap_err();
+
+    assert_eq!(err.identifier(), None);
+    assert_eq!(err.line(), Some(2));
+}
+
+#[test]
+fn from_reader_works() {
+    #[derive(Deserialize)]
+    struct Config {
+        answer: i64,
+    }
+
+    let config: Config = from_reader("answer = 42".as_bytes()).unwrap();
+
+    assert_eq!(config.answer, 42);
+}
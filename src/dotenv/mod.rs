@@ -0,0 +1,333 @@
+//! Reads and writes the `.env` files of the dotenv tools into the same `HashMap<Identifier, Value>` as an INI file
+//! 
+//! A `.env` file does not have any section, so each variable is a key of the global section and each value is a `Value::Raw`. [`DotenvDumper`](struct.DotenvDumper.html "dotenv::DotenvDumper") writes the key `port` of the section `server` as `SERVER_PORT` and [`restore_sections`](fn.restore_sections.html "dotenv::restore_sections") moves it back to its section
+//! 
+//! # Example
+//! ```
+//! use mininip::datas::{Identifier, Value};
+//! use mininip::dotenv::{self, DotenvDumper};
+//! use mininip::parse::Parser;
+//! 
+//! let mut parser = Parser::new();
+//! parser.parse_content("[server]\nport = 8080\n").unwrap();
+//! let ini = parser.data();
+//! 
+//! let mut dumper = DotenvDumper::new();
+//! for (ident, value) in ini.clone() {
+//!     dumper.dump(ident, value);
+//! }
+//! let env = dumper.generate();
+//! assert_eq!(env, "SERVER_PORT=8080\n");
+//! 
+//! let env = dotenv::parse_content(&env.replace("8080", "9090")).unwrap();
+//! let data = dotenv::restore_sections(env, ini.keys());
+//! let port = Identifier::new(Some(String::from("server")), String::from("port"));
+//! assert_eq!(data[&port], Value::Raw(String::from("9090")));
+//! ```
+
+use crate::datas::{Identifier, IdentifierPolicy, Value};
+use crate::errors::{Error, error_kinds::*, ParseFileError};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+/// Reads the content of a `.env` file
+/// 
+/// - a line starting with `#` is a comment, and a `#` following a whitespace starts a comment after an unquoted value
+/// - a variable is declared by `KEY=value`, which may be preceded by `export` and whitespaces
+/// - a key matches `[A-Za-z_][A-Za-z0-9_.]*` (see [`IdentifierPolicy::Dotenv`](../datas/enum.IdentifierPolicy.html "datas::IdentifierPolicy"))
+/// - an unquoted value is trimmed and read verbatim
+/// - a value between single quotes is read verbatim and may span several lines
+/// - a value between double quotes may span several lines and has the escape sequences `\n`, `\r`, `\t`, `\"` and `\\`, any other backslash being kept
+/// 
+/// # Return value
+/// `Ok(data)` in case of success with `data` as a `HashMap<Identifier, Value>` linking each key of the global section to its value
+/// 
+/// `Err(error)` in case of error with `error` as the error code of the first invalid line, which may be a missing `=`, an unterminated quote or an invalid key
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::{Identifier, Value};
+/// use mininip::dotenv;
+/// 
+/// let data = dotenv::parse_content("export A=1 # comment\nB='single \\n'\nC=\"double \\n\"\n").unwrap();
+/// 
+/// assert_eq!(data[&Identifier::new(None, String::from("A"))], Value::Raw(String::from("1")));
+/// assert_eq!(data[&Identifier::new(None, String::from("B"))], Value::Raw(String::from("single \\n")));
+/// assert_eq!(data[&Identifier::new(None, String::from("C"))], Value::Raw(String::from("double \n")));
+/// ```
+pub fn parse_content(content: &str) -> Result<HashMap<Identifier, Value>, Error> {
+    let mut data = HashMap::new();
+
+    let mut begin = 0;
+    while begin < content.len() {
+        begin = parse_statement(content, begin, &mut data)?;
+    }
+
+    Ok(data)
+}
+
+/// Reads in a `.env` file encoded in UTF-8
+/// 
+/// # Return value
+/// `Ok(data)` in case of success with `data` as a `HashMap<Identifier, Value>` linking each key to its value
+/// 
+/// `Err(error)` in case of failure with `error` as an error code for either an I/O error or a parsing error (see [ParseFileError](../errors/enum.ParseFileError.html "errors::ParseFileError"))
+pub fn parse_file<T: AsRef<Path>>(path: T) -> Result<HashMap<Identifier, Value>, ParseFileError> {
+    let mut file = File::open(path)?;
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+
+    Ok(parse_content(&content)?)
+}
+
+/// Returns the index after the end of the line containing the index `index` of `content`
+fn next_line(content: &str, index: usize) -> usize {
+    content[index..].find('\n').map_or(content.len(), |end| index + end + 1)
+}
+
+/// Reads the statement starting at the index `begin` of `content` and stores its variable into `data`
+/// 
+/// # Return value
+/// `Ok(next)` in case of success with `next` as the index of the next statement, since a quoted value may span several lines
+/// 
+/// `Err(error)` in case of error with `error` as the error code
+fn parse_statement(content: &str, begin: usize, data: &mut HashMap<Identifier, Value>) -> Result<usize, Error> {
+    let next = next_line(content, begin);
+    let line = content[begin..next].trim_end_matches(['\n', '\r']);
+
+    let effective_line = line.trim_start();
+    if effective_line.is_empty() || effective_line.starts_with('#') {
+        return Ok(next);
+    }
+
+    let key_begin = match effective_line.strip_prefix("export") {
+        Some(rest) if rest.starts_with([' ', '\t']) && !rest.trim_start().starts_with('=') => line.len() - rest.trim_start().len(),
+        _                                                                                 => line.len() - effective_line.len(),
+    };
+    let delimiter = match line[key_begin..].find('=') {
+        Some(index) => key_begin + index,
+        None        => return Err(Error::from(ExpectedToken::new(String::from(line), line.trim_end().len(), String::from("=")))),
+    };
+
+    let key = line[key_begin..delimiter].trim();
+    if key.is_empty() {
+        return Err(Error::from(ExpectedIdentifier::new(String::from(line), key_begin)));
+    } else if !IdentifierPolicy::Dotenv.is_valid(key) {
+        return Err(Error::from(InvalidIdentifier::with_policy(String::from(line), String::from(key), IdentifierPolicy::Dotenv)));
+    }
+    let identifier = Identifier::with_policy(None, String::from(key), IdentifierPolicy::Dotenv);
+
+    let value_begin = begin + delimiter + 1;
+    let value_begin = value_begin + content[value_begin..next].len() - content[value_begin..next].trim_start_matches([' ', '\t']).len();
+    let quote = match content[value_begin..].chars().next() {
+        Some(quote) if quote == '\'' || quote == '"' => quote,
+        _                                            => {
+            let value = &content[value_begin..begin + line.len()];
+            let end = value.char_indices()
+                           .find(|&(n, c)| c == '#' && value[..n].ends_with([' ', '\t']))
+                           .map_or(value.len(), |(n, _)| n);
+
+            data.insert(identifier, Value::Raw(String::from(value[..end].trim_end())));
+            return Ok(next);
+        },
+    };
+
+    // A quoted value may span several lines
+    let mut value = String::new();
+    let mut iter = content[value_begin + 1..].char_indices().map(|(n, c)| (n + value_begin + 1, c));
+    let close = loop {
+        match iter.next() {
+            Some((n, c)) if c == quote                            => break n,
+            Some((n, '\r')) if content[n + 1..].starts_with('\n') => (),
+            Some((_, '\\')) if quote == '"'                       => match iter.next() {
+                Some((_, 'n'))  => value.push('\n'),
+                Some((_, 'r'))  => value.push('\r'),
+                Some((_, 't'))  => value.push('\t'),
+                Some((_, '"'))  => value.push('"'),
+                Some((_, '\\')) => value.push('\\'),
+                Some((_, c))    => {
+                    value.push('\\');
+                    value.push(c);
+                },
+                None            => value.push('\\'),
+            },
+            Some((_, c))                                          => value.push(c),
+            None                                                  => return Err(Error::from(ExpectedToken::new(String::from(line), line.len(), quote.to_string()))),
+        }
+    };
+
+    // Only a comment may follow the closing quote
+    let next = next_line(content, close);
+    let last_line_begin = content[..close].rfind('\n').map_or(0, |index| index + 1);
+    let last_line = content[last_line_begin..next].trim_end_matches(['\n', '\r']);
+    let rest = last_line[close + 1 - last_line_begin..].trim_start();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err(Error::from(UnexpectedToken::new(String::from(last_line), last_line.len() - rest.len())));
+    }
+
+    data.insert(identifier, Value::Raw(value));
+    Ok(next)
+}
+
+
+/// Returns the name of the environment variable of `identifier`, which is `SECTION_KEY` for the key `key` of the section `section`
+/// 
+/// The names are in uppercase and every character which is not a latin alphanumeric character is replaced by `_`
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::Identifier;
+/// use mininip::dotenv::env_name;
+/// 
+/// assert_eq!(env_name(&Identifier::new(Some(String::from("server")), String::from("max-connections"))), "SERVER_MAX_CONNECTIONS");
+/// assert_eq!(env_name(&Identifier::new(None, String::from("debug"))), "DEBUG");
+/// ```
+pub fn env_name(identifier: &Identifier) -> String {
+    let convert = |name: &str| -> String {
+        name.chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+            .collect()
+    };
+
+    match identifier.section() {
+        Some(section) => format!("{}_{}", convert(section), convert(identifier.name())),
+        None          => convert(identifier.name()),
+    }
+}
+
+/// Moves the variables read from a `.env` file back to the identifiers they were written from
+/// 
+/// Each variable of the global section of `env` named like the [`env_name`](fn.env_name.html "dotenv::env_name") of one of `identifiers` is renamed to this identifier, while the other ones are kept as is. If several identifiers have the same name, the last one is used
+pub fn restore_sections<'a, I: IntoIterator<Item = &'a Identifier>>(env: HashMap<Identifier, Value>, identifiers: I) -> HashMap<Identifier, Value> {
+    let names: HashMap<String, &Identifier> = identifiers.into_iter()
+                                                         .map(|ident| (env_name(ident), ident))
+                                                         .collect();
+
+    env.into_iter()
+       .map(|(ident, value)| match (ident.section(), names.get(ident.name())) {
+           (None, Some(original)) => ((*original).clone(), value),
+           _                      => (ident, value),
+       })
+       .collect()
+}
+
+
+/// A stated object, which from couples of [`Identifier`](../datas/struct.Identifier.html "datas::Identifier") and [`Value`](../datas/enum.Value.html "datas::Value"), creates the content of a `.env` file
+/// 
+/// Each key is written with its [`env_name`](fn.env_name.html "dotenv::env_name") and the lines are written in the order they were dumped. A value is unquoted if it only contains safe characters, between single quotes if it does not contain any single quote nor line feed and between double quotes otherwise
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::{Identifier, Value};
+/// use mininip::dotenv::DotenvDumper;
+/// 
+/// let mut dumper = DotenvDumper::new();
+/// dumper.set_export(true);
+/// dumper.dump(Identifier::new(Some(String::from("db")), String::from("url")), Value::Raw(String::from("postgres://localhost/app")));
+/// dumper.dump(Identifier::new(None, String::from("motd")), Value::Str(String::from("Hello $USER")));
+/// dumper.dump(Identifier::new(None, String::from("quote")), Value::Str(String::from("It's\n\"fine\"")));
+/// 
+/// assert_eq!(dumper.generate(), "export DB_URL=postgres://localhost/app\nexport MOTD='Hello $USER'\nexport QUOTE=\"It's\\n\\\"fine\\\"\"\n");
+/// ```
+#[derive(Debug, Default)]
+pub struct DotenvDumper {
+    /// The lines in the order they were dumped
+    lines: Vec<String>,
+    /// Whether each line starts with `export`
+    export: bool,
+}
+
+impl DotenvDumper {
+    /// Creates a new `DotenvDumper` object
+    pub fn new() -> DotenvDumper {
+        DotenvDumper {
+            lines: Vec::new(),
+            export: false,
+        }
+    }
+
+    /// Enables or disables writing `export` before each variable, so that the file can be sourced by a shell. It is disabled by default
+    pub fn set_export(&mut self, enabled: bool) {
+        self.export = enabled;
+    }
+
+    /// Dumps a couple [`Identifier`](../datas/struct.Identifier.html "datas::Identifier") / [`Value`](../datas/enum.Value.html "datas::Value") into `self`
+    /// 
+    /// A boolean is written `true` or `false` and any other value is written as its text
+    pub fn dump(&mut self, identifier: Identifier, value: Value) {
+        let value = match value {
+            Value::Bool(value) => value.to_string(),
+            value              => format!("{}", value),
+        };
+
+        self.lines.push(format!("{}={}", env_name(&identifier), quote(&value)));
+    }
+
+    /// Generates a `String` containing the code of the `.env` file stored in the `DotenvDumper`
+    pub fn generate(self) -> String {
+        let mut result = String::new();
+        for line in self.lines {
+            if self.export {
+                result.push_str("export ");
+            }
+            result.push_str(&line);
+            result.push('\n');
+        }
+
+        result
+    }
+}
+
+/// Quotes `value` if needed so that it is read back unchanged by [`parse_content`](fn.parse_content.html "dotenv::parse_content")
+fn quote(value: &str) -> String {
+    if value.chars().all(|c| c.is_ascii_alphanumeric() || "_-./:@%+,".contains(c)) {
+        String::from(value)
+    } else if !value.contains(['\'', '\n', '\r']) {
+        format!("'{}'", value)
+    } else {
+        let mut result = String::from("\"");
+        for c in value.chars() {
+            match c {
+                '\n' => result.push_str("\\n"),
+                '\r' => result.push_str("\\r"),
+                '\t' => result.push_str("\\t"),
+                '"'  => result.push_str("\\\""),
+                '\\' => result.push_str("\\\\"),
+                c    => result.push(c),
+            }
+        }
+        result.push('"');
+        result
+    }
+}
+
+/// Dumps a `HashMap<Identifier, Value>` into a `.env` file, sorted by key
+/// 
+/// # Parameters
+/// `path` the path of the file (must be closed)
+/// 
+/// `data` the data to dump
+/// 
+/// # Return value
+/// Since any [`DotenvDumper`](struct.DotenvDumper.html "dotenv::DotenvDumper") operation is infallible, it only returns an `io::Result<()>` which indicates a file manipulation error
+pub fn dump_into_file<T: AsRef<Path>>(path: T, data: HashMap<Identifier, Value>) -> io::Result<()> {
+    let mut file = File::create(path)?;
+
+    let mut data: Vec<(Identifier, Value)> = data.into_iter().collect();
+    data.sort_by(|(a, _), (b, _)| (a.section(), a.name()).cmp(&(b.section(), b.name())));
+
+    let mut dumper = DotenvDumper::new();
+    for (k, v) in data {
+        dumper.dump(k, v);
+    }
+
+    file.write_all(dumper.generate().as_bytes())?;
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,147 @@
+use crate::datas::{Identifier, IdentifierPolicy, Value};
+use crate::dotenv::*;
+use crate::errors::Error;
+use crate::parse::Parser;
+use std::collections::HashMap;
+
+fn ident(name: &str) -> Identifier {
+    Identifier::new(None, String::from(name))
+}
+
+fn raw(text: &str) -> Value {
+    Value::Raw(String::from(text))
+}
+
+#[test]
+fn dotenv_fixture_launcher() {
+    let data = parse_content(include_str!("../../fixtures/dotenv/launcher.env")).unwrap();
+
+    let mut expected = HashMap::new();
+    expected.insert(ident("APP_NAME"), raw("mininip"));
+    expected.insert(ident("SERVER_PORT"), raw("8080"));
+    expected.insert(ident("SERVER_HOST"), raw("localhost"));
+    expected.insert(ident("URL"), raw("http://example.com/#anchor"));
+    expected.insert(ident("EMPTY"), raw(""));
+    expected.insert(ident("SINGLE"), raw("literal $HOME \\n # not a comment"));
+    expected.insert(ident("DOUBLE"), raw("tab\there \"quoted\" \\ \\$HOME"));
+    expected.insert(ident("MULTI_SINGLE"), raw("first\nsecond"));
+    expected.insert(ident("MULTI_DOUBLE"), raw("line one\nline two\nline three"));
+    expected.insert(ident("INDENTED"), raw("value"));
+    assert_eq!(data, expected);
+}
+
+#[test]
+fn dotenv_crlf() {
+    let data = parse_content("A=1\r\nB=\"x\r\ny\"\r\nC='z' # comment\r\n").unwrap();
+
+    assert_eq!(data[&ident("A")], raw("1"));
+    assert_eq!(data[&ident("B")], raw("x\ny"));
+    assert_eq!(data[&ident("C")], raw("z"));
+}
+
+#[test]
+fn dotenv_export_is_a_prefix() {
+    let data = parse_content("export\tA=1\nexported=2\nexport = 3\n").unwrap();
+
+    assert_eq!(data[&ident("A")], raw("1"));
+    assert_eq!(data[&ident("exported")], raw("2"));
+    assert_eq!(data[&ident("export")], raw("3"));
+}
+
+#[test]
+fn dotenv_errors() {
+    match parse_content("A\n") {
+        Err(Error::ExpectedToken(_)) => (),
+        other                        => panic!("Expected an ExpectedToken error, got {:?}", other),
+    }
+    match parse_content("A=\"open\nB=1\n") {
+        Err(Error::ExpectedToken(_)) => (),
+        other                        => panic!("Expected an ExpectedToken error, got {:?}", other),
+    }
+    match parse_content("A='closed' extra\n") {
+        Err(Error::UnexpectedToken(_)) => (),
+        other                          => panic!("Expected an UnexpectedToken error, got {:?}", other),
+    }
+    match parse_content("1A=value\n") {
+        Err(Error::InvalidIdentifier(_)) => (),
+        other                            => panic!("Expected an InvalidIdentifier error, got {:?}", other),
+    }
+    match parse_content("=value\n") {
+        Err(Error::ExpectedIdentifier(_)) => (),
+        other                             => panic!("Expected an ExpectedIdentifier error, got {:?}", other),
+    }
+    for content in &["my key=value\n", "a:b=value\n", "a-b=value\n"] {
+        match parse_content(content) {
+            Err(Error::InvalidIdentifier(_)) => (),
+            other                            => panic!("Expected an InvalidIdentifier error, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn dotenv_keys() {
+    let data = parse_content("_JAVA_OPTIONS=-Xmx1g
+log.level=debug
+").unwrap();
+
+    let java_options = Identifier::with_policy(None, String::from("_JAVA_OPTIONS"), IdentifierPolicy::Dotenv);
+    assert_eq!(data[&java_options], raw("-Xmx1g"));
+    assert_eq!(data[&ident("log.level")], raw("debug"));
+}
+
+#[test]
+fn dotenv_env_name() {
+    assert_eq!(env_name(&Identifier::new(Some(String::from("valid since 1.2.0")), String::from("$starts-with-$"))), "VALID_SINCE_1_2_0__STARTS_WITH__");
+    assert_eq!(env_name(&ident("path")), "PATH");
+}
+
+#[test]
+fn dotenv_dump_quotes() {
+    let mut dumper = DotenvDumper::new();
+    dumper.dump(ident("a"), raw(""));
+    dumper.dump(ident("b"), raw("with space"));
+    dumper.dump(ident("c"), raw("it's \\ \"here\"\t"));
+    dumper.dump(ident("d"), Value::Bool(true));
+    dumper.dump(ident("e"), Value::Float(1.5));
+
+    assert_eq!(dumper.generate(), "A=\nB='with space'\nC=\"it's \\\\ \\\"here\\\"\\t\"\nD=true\nE=1.5\n");
+}
+
+#[test]
+fn dotenv_dump_reverses_parse() {
+    let data = parse_content(include_str!("../../fixtures/dotenv/launcher.env")).unwrap();
+
+    let mut dumper = DotenvDumper::new();
+    dumper.set_export(true);
+    for (ident, value) in data.clone() {
+        dumper.dump(ident, value);
+    }
+
+    assert_eq!(parse_content(&dumper.generate()).unwrap(), data);
+}
+
+#[test]
+fn dotenv_ini_round_trip() {
+    let mut parser = Parser::new();
+    parser.parse_content("name = app\n[server]\nport = 8080\nmax-connections = 10\n").unwrap();
+    let ini = parser.data();
+
+    let mut data: Vec<(Identifier, Value)> = ini.clone().into_iter().collect();
+    data.sort_by(|(a, _), (b, _)| (a.section(), a.name()).cmp(&(b.section(), b.name())));
+    let mut dumper = DotenvDumper::new();
+    for (ident, value) in data {
+        dumper.dump(ident, value);
+    }
+    let env = dumper.generate();
+    assert_eq!(env, "NAME=app\nSERVER_MAX_CONNECTIONS=10\nSERVER_PORT=8080\n");
+
+    let env = parse_content(&format!("{}EXTRA=1\n", env)).unwrap();
+    let data = restore_sections(env, ini.keys());
+
+    let server = Some(String::from("server"));
+    assert_eq!(data.len(), 4);
+    assert_eq!(data[&ident("name")], raw("app"));
+    assert_eq!(data[&Identifier::new(server.clone(), String::from("port"))], raw("8080"));
+    assert_eq!(data[&Identifier::new(server, String::from("max-connections"))], raw("10"));
+    assert_eq!(data[&ident("EXTRA")], raw("1"));
+}
@@ -1,6 +1,10 @@
 //! Provides a `Dumper` structure which creates a new INI file content
 
-use crate::datas::{Identifier, Value};
+use crate::datas::{BoolStyle, Identifier, Lexeme, LineEnding, TextMode, Value};
+use crate::datas::encoding::Encoding;
+use crate::datas::profile::Profile;
+use crate::errors::{EncodingError, ParseFileError};
+use crate::parse::Dialect;
 use std::collections::{hash_map, HashMap};
 use std::path::Path;
 use std::fs::File;
@@ -41,6 +45,24 @@ use std::io::{self, Write};
 pub struct Dumper {
     /// The keys of this member are the section names and the values are a list of affectation lines generated
     tree: HashMap<Option<String>, Vec<String>>,
+    /// The spelling of the booleans dumped
+    bool_style: BoolStyle,
+    /// The original spelling of the values, reused when they did not change
+    lexemes: HashMap<Identifier, Lexeme>,
+    /// Whether the non-ASCII characters of the values are escaped
+    text_mode: TextMode,
+    /// The line ending written after each line
+    line_ending: LineEnding,
+    /// Whether the values are written with the systemd syntax, in the order they were dumped
+    systemd: bool,
+    /// The sections in the order they were dumped, used only with the systemd syntax
+    section_order: Vec<Option<String>>,
+}
+
+impl Default for Dumper {
+    fn default() -> Self {
+        Dumper::new()
+    }
 }
 
 impl Dumper {
@@ -48,17 +70,111 @@ impl Dumper {
     pub fn new() -> Dumper {
         Dumper {
             tree: HashMap::new(),
+            bool_style: BoolStyle::default(),
+            lexemes: HashMap::new(),
+            text_mode: TextMode::default(),
+            line_ending: LineEnding::default(),
+            systemd: false,
+            section_order: Vec::new(),
         }
     }
 
+    /// Creates a new `Dumper` object following `dialect`
+    pub fn with_dialect(dialect: Dialect) -> Dumper {
+        let mut dumper = Dumper::new();
+        dumper.set_dialect(dialect);
+
+        dumper
+    }
+
+    /// Follows `dialect` in the next couples dumped
+    /// 
+    /// With [`Dialect::Systemd`](../parse/enum.Dialect.html "parse::Dialect"), the sections and the assignments keep the order they were dumped in, since a key may be dumped several times to build a list, and the values are written verbatim. A line feed in a value is written as a line continuation, which is read back as a space, or as a space if the continuation would be misread. Any other dialect is dumped with the rules of MinIniP (see [`GitDumper`](struct.GitDumper.html "dump::GitDumper") for git)
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::dump::Dumper;
+    /// use mininip::datas::{Identifier, Value};
+    /// use mininip::parse::Dialect;
+    /// 
+    /// let mut dumper = Dumper::new();
+    /// dumper.set_dialect(Dialect::Systemd);
+    /// 
+    /// let description = Identifier::new(Some(String::from("Unit")), String::from("Description"));
+    /// dumper.dump(description, Value::Raw(String::from("A service; with a semicolon")));
+    /// let exec_start = Identifier::new(Some(String::from("Service")), String::from("ExecStart"));
+    /// dumper.dump(exec_start.clone(), Value::Raw(String::new()));
+    /// dumper.dump(exec_start, Value::Raw(String::from("/usr/bin/app")));
+    /// 
+    /// assert_eq!(dumper.generate(), "[Unit]\nDescription=A service; with a semicolon\n\n[Service]\nExecStart=\nExecStart=/usr/bin/app\n");
+    /// ```
+    pub fn set_dialect(&mut self, dialect: Dialect) {
+        self.systemd = dialect == Dialect::Systemd;
+    }
+
+    /// Changes the spelling of the booleans in the next couples dumped
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::dump::Dumper;
+    /// use mininip::datas::{BoolStyle, Identifier, Value};
+    /// 
+    /// let mut dumper = Dumper::new();
+    /// dumper.set_bool_style(BoolStyle::TrueFalse);
+    /// dumper.dump(Identifier::new(None, String::from("verbose")), Value::Bool(true));
+    /// 
+    /// assert_eq!(dumper.generate(), "verbose=true\n");
+    /// ```
+    pub fn set_bool_style(&mut self, style: BoolStyle) {
+        self.bool_style = style;
+    }
+
+    /// Changes the original spelling of the values in the next couples dumped (see [`Lexeme::dump`](../datas/struct.Lexeme.html#method.dump "datas::Lexeme::dump")), so that an unchanged value is written back as it was read
+    /// 
+    /// # See
+    /// [`Parser::set_keep_lexemes`](../parse/struct.Parser.html#method.set_keep_lexemes "parse::Parser::set_keep_lexemes") to get the lexemes of a file
+    pub fn set_lexemes(&mut self, lexemes: HashMap<Identifier, Lexeme>) {
+        self.lexemes = lexemes;
+    }
+
+    /// Changes whether the non-ASCII characters of the values are escaped in the next couples dumped. It is [`TextMode::Ascii`](../datas/enum.TextMode.html "datas::TextMode") by default, where they are written as `\x??????`
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::dump::Dumper;
+    /// use mininip::datas::{Identifier, TextMode, Value};
+    /// 
+    /// let mut dumper = Dumper::new();
+    /// dumper.set_text_mode(TextMode::Utf8);
+    /// dumper.dump(Identifier::new(None, String::from("greeting")), Value::Str(String::from("café = \u{263a}")));
+    /// 
+    /// assert_eq!(dumper.generate(), "greeting='café \\= \u{263a}'\n");
+    /// ```
+    pub fn set_text_mode(&mut self, mode: TextMode) {
+        self.text_mode = mode;
+    }
+
+    /// Changes the line ending written after each line. It is [`LineEnding::Lf`](../datas/enum.LineEnding.html "datas::LineEnding") by default
+    /// 
+    /// # See
+    /// [`Parser::line_ending`](../parse/struct.Parser.html#method.line_ending "parse::Parser::line_ending") to write a file back with its own line endings
+    pub fn set_line_ending(&mut self, ending: LineEnding) {
+        self.line_ending = ending;
+    }
+
     /// Dumps a couple [`Identifier`](../datas/struct.Identifier.html "datas::Identifier") / [`Value`](../datas/enum.Value.html "datas::Value") into `self`
     pub fn dump(&mut self, identifier: Identifier, value: Value) {
-        let line = format!("{}={}", identifier.name(), value.dump());
-
-        let key = match identifier.section() {
-            Some(val) => Some(String::from(val)),
-            None      => None,
+        let value = match self.lexemes.get(&identifier) {
+            _ if self.systemd => dump_systemd_value(&value, self.bool_style, self.line_ending),
+            Some(lexeme)      => lexeme.dump(&value, self.bool_style, self.text_mode),
+            None              => value.dump_with_style(self.bool_style, self.text_mode),
         };
+        let line = format!("{}={}", identifier.name(), value);
+
+        let key = identifier.section().map(String::from);
+        if self.systemd && !self.section_order.contains(&key) {
+            self.section_order.push(key.clone());
+        }
         match self.tree.entry(key) {
             hash_map::Entry::Occupied(mut entry) => entry.get_mut().push(line),
             hash_map::Entry::Vacant(entry)       => { entry.insert(vec![line]); },
@@ -67,6 +183,10 @@ impl Dumper {
 
     /// Generates a `String` containing the code of the INI data stored in the `Dumper`
     pub fn generate(mut self) -> String {
+        if self.systemd {
+            return self.generate_in_order();
+        }
+
         // We want the sections to be sorted by name
         let mut sections: Vec<String> = Vec::with_capacity(self.tree.len());
         for (key, _value) in self.tree.iter() {
@@ -77,38 +197,128 @@ impl Dumper {
         sections.sort();
 
         // And None to be the first one
+        let ending = self.line_ending.as_str();
         let mut result = String::new();
         if let Some(val) = self.tree.get_mut(&None) {
             val.sort();
             for i in val {
                 result.push_str(i);
-                result.push('\n');
+                result.push_str(ending);
             }
 
-            result.push('\n');
+            result.push_str(ending);
         }
 
         for i in sections {
             result.push('[');
             result.push_str(&i);
-            result.push_str("]\n");
+            result.push(']');
+            result.push_str(ending);
 
             let section = self.tree.get_mut(&Some(i))
                                    .expect("i is in sections so it is valid");
             section.sort();
             for j in section {
                 result.push_str(j);
-                result.push('\n');
+                result.push_str(ending);
             }
 
-            result.push('\n');
+            result.push_str(ending);
         }
 
-        result.pop();
+        let len = result.len().saturating_sub(ending.len());
+        result.truncate(len);
+        result
+    }
+
+    /// Generates the code of the INI data stored in the `Dumper` like [`generate`](#method.generate "dump::Dumper::generate") does, encoded in `encoding` and starting with its byte order mark if `bom` is `true`
+    /// 
+    /// # Return value
+    /// `Ok(bytes)` in case of success
+    /// 
+    /// `Err(error)` if a character can't be written in `encoding`, with its offset in the text generated
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::dump::Dumper;
+    /// use mininip::datas::{Identifier, TextMode, Value};
+    /// use mininip::datas::encoding::Encoding;
+    /// 
+    /// let mut dumper = Dumper::new();
+    /// dumper.set_text_mode(TextMode::Utf8);
+    /// dumper.dump(Identifier::new(None, String::from("city")), Value::Str(String::from("Genève")));
+    /// 
+    /// assert_eq!(dumper.generate_bytes(Encoding::Windows1252, false).unwrap(), b"city='Gen\xE8ve'\n");
+    /// ```
+    pub fn generate_bytes(self, encoding: Encoding, bom: bool) -> Result<Vec<u8>, EncodingError> {
+        encoding.encode(&self.generate(), bom)
+    }
+
+    /// Generates the code of the INI data with the sections and the assignments in the order they were dumped, except the global section which is always the first one
+    fn generate_in_order(mut self) -> String {
+        let mut sections = self.section_order;
+        if let Some(index) = sections.iter().position(Option::is_none) {
+            sections.remove(index);
+            sections.insert(0, None);
+        }
+
+        let ending = self.line_ending.as_str();
+        let mut result = String::new();
+        for i in sections {
+            if let Some(name) = &i {
+                result.push('[');
+                result.push_str(name);
+                result.push(']');
+                result.push_str(ending);
+            }
+
+            let section = self.tree.remove(&i)
+                                   .expect("i is in section_order so it is valid");
+            for j in section {
+                result.push_str(&j);
+                result.push_str(ending);
+            }
+
+            result.push_str(ending);
+        }
+
+        let len = result.len().saturating_sub(ending.len());
+        result.truncate(len);
         result
     }
 }
 
+/// Formats `value` verbatim with the systemd syntax, where a line feed is written as a line continuation
+/// 
+/// Since a line continuation is read back as a space, a line feed is written as a space when the continuation would be misread: when the next line is empty or starts with `#` or `;`, which would be a comment, or when the line ends with an odd number of backslashes. In the same way, a space is added after an odd number of backslashes at the end of the value so that the next assignment is not read as a continuation. This space is trimmed when the value is read
+fn dump_systemd_value(value: &Value, style: BoolStyle, ending: LineEnding) -> String {
+    let text = match value {
+        Value::Bool(value) => String::from(style.spell(*value)),
+        value              => format!("{}", value),
+    };
+    let continues = |line: &str| (line.len() - line.trim_end_matches('\\').len()) % 2 == 1;
+
+    let mut result = String::with_capacity(text.len());
+    let mut lines = text.split('\n').peekable();
+    while let Some(line) = lines.next() {
+        result.push_str(line);
+
+        match lines.peek().map(|next| next.trim_start()) {
+            Some(next) if continues(line)
+                       || next.is_empty()
+                       || next.starts_with(['#', ';']) => result.push(' '),
+            Some(_)                                    => {
+                result.push('\\');
+                result.push_str(ending.as_str());
+            },
+            None if continues(line)                    => result.push(' '),
+            None                                       => {},
+        }
+    }
+
+    result
+}
+
 /// Dumps a `HashMap<Identifier, Value>` into a file
 /// 
 /// # Parameters
@@ -119,14 +329,42 @@ impl Dumper {
 /// # Return value
 /// Since any [`Dumper`](struct.Dumper.html "dump::Dumper") operation is infallible, it only returns an `io::Result<()>` which indicates a file manipulation error
 pub fn dump_into_file<T: AsRef<Path>>(path: T, data: HashMap<Identifier, Value>) -> io::Result<()> {
+    dump_into_file_with(path, data, TextMode::Ascii)
+}
+
+/// Dumps a `HashMap<Identifier, Value>` into a file like [`dump_into_file`](fn.dump_into_file.html "dump::dump_into_file") does, but writes the non-ASCII characters of the values as is in UTF-8 if `mode` is `TextMode::Utf8`
+/// 
+/// # Return value
+/// The same as [`dump_into_file`](fn.dump_into_file.html "dump::dump_into_file")
+pub fn dump_into_file_with<T: AsRef<Path>>(path: T, data: HashMap<Identifier, Value>, mode: TextMode) -> io::Result<()> {
     let mut file = File::create(path)?;
     let mut dumper = Dumper::new();
+    dumper.set_text_mode(mode);
 
     for (k, v) in data {
         dumper.dump(k, v);
     }
 
-    file.write(dumper.generate().as_bytes())?;
+    file.write_all(dumper.generate().as_bytes())?;
+    Ok(())
+}
+
+/// Writes `profile` into a file with its encoding and its line endings, like [`Profile::to_bytes`](../datas/profile/struct.Profile.html#method.to_bytes "datas::profile::Profile::to_bytes")
+/// 
+/// # Parameters
+/// `path` the path of the file (must be closed)
+/// 
+/// `profile` the content of the file, which may have been read by [`parse_profile_file`](../parse/fn.parse_profile_file.html "parse::parse_profile_file")
+/// 
+/// # Return value
+/// `Ok(())` in case of success
+/// 
+/// `Err(error)` in case of failure with `error` as either an I/O error or an encoding error if a character of `profile` can't be written in its encoding, in which case the file is not created (see [ParseFileError](../errors/enum.ParseFileError.html "errors::ParseFileError"))
+pub fn dump_profile_into_file<T: AsRef<Path>>(path: T, profile: &Profile) -> Result<(), ParseFileError> {
+    let bytes = profile.to_bytes()?;
+    let mut file = File::create(path)?;
+
+    file.write_all(&bytes)?;
     Ok(())
 }
 
@@ -1,5 +1,8 @@
+#![allow(clippy::approx_constant)]
+
 use crate::dump::dumper::*;
-use crate::datas::{Identifier, Value};
+use crate::datas::{BoolStyle, Identifier, Lexeme, Value};
+use std::collections::HashMap;
 
 #[test]
 fn dumper_without_globals() {
@@ -69,6 +72,17 @@ fn dumper_with_globals() {
     assert_eq!(expected, dumper.generate());
 }
 
+#[test]
+fn dumper_with_bool_style() {
+    let mut dumper = Dumper::new();
+    dumper.set_bool_style(BoolStyle::YesNo);
+
+    dumper.dump(Identifier::new(None, String::from("a")), Value::Bool(true));
+    dumper.dump(Identifier::new(None, String::from("b")), Value::Bool(false));
+
+    assert_eq!("a=yes\nb=no\n", dumper.generate());
+}
+
 #[test]
 fn dumper_with_escape() {
     let mut dumper = Dumper::new();
@@ -80,3 +94,141 @@ fn dumper_with_escape() {
 
     assert_eq!("ident=\\:D \\= \\x00263a\n", dumper.generate());
 }
+
+#[test]
+fn dumper_reuses_lexemes() {
+    let ratio = Identifier::new(None, String::from("ratio"));
+    let name = Identifier::new(None, String::from("name"));
+    let flag = Identifier::new(None, String::from("flag"));
+
+    let mut lexemes = HashMap::new();
+    lexemes.insert(ratio.clone(), Lexeme::new(String::from("1e3"), Value::Float(1000.0)));
+    lexemes.insert(name.clone(), Lexeme::new(String::from("\"old\""), Value::Str(String::from("old"))));
+    lexemes.insert(flag.clone(), Lexeme::new(String::from("Yes"), Value::Bool(true)));
+
+    let mut dumper = Dumper::new();
+    dumper.set_lexemes(lexemes);
+    dumper.dump(ratio, Value::Float(1000.0));
+    dumper.dump(name, Value::Str(String::from("new")));
+    dumper.dump(flag, Value::Bool(false));
+
+    assert_eq!(dumper.generate(), "flag=off\nname=\"new\"\nratio=1e3\n");
+}
+
+#[test]
+fn dumper_systemd_order() {
+    use crate::parse::{Dialect, Parser};
+
+    let content = "\
+    [Unit]\n\
+    Description=Demo; with # special = characters\n\
+    \n\
+    [Service]\n\
+    Type=simple\n\
+    ExecStart=\n\
+    ExecStart=/usr/bin/app --flag \"quoted arg\"\n\
+    Environment=A=1\n\
+    Environment=B=2\n\
+    \n\
+    [Install]\n\
+    WantedBy=multi-user.target\n";
+
+    let mut parser = Parser::with_dialect(Dialect::Systemd);
+    parser.parse_content(content).unwrap();
+
+    let mut dumper = Dumper::with_dialect(Dialect::Systemd);
+    for (ident, value) in parser.unit_file().into_entries() {
+        dumper.dump(ident, value);
+    }
+
+    assert_eq!(dumper.generate(), content);
+}
+
+#[test]
+fn dumper_systemd_values() {
+    use crate::parse::Dialect;
+
+    let mut dumper = Dumper::new();
+    dumper.set_dialect(Dialect::Systemd);
+    dumper.set_bool_style(BoolStyle::YesNo);
+    dumper.set_lexemes(HashMap::new());
+
+    let service = Some(String::from("Service"));
+    dumper.dump(Identifier::new(service.clone(), String::from("RemainAfterExit")), Value::Bool(true));
+    dumper.dump(Identifier::new(service.clone(), String::from("Nice")), Value::Int(-5));
+    dumper.dump(Identifier::new(service, String::from("ExecStart")), Value::Raw(String::from("/bin/a\n--flag")));
+    dumper.dump(Identifier::new(None, String::from("Global")), Value::Raw(String::from("x")));
+
+    assert_eq!(dumper.generate(), "Global=x\n\n[Service]\nRemainAfterExit=yes\nNice=-5\nExecStart=/bin/a\\\n--flag\n");
+}
+
+#[test]
+fn dumper_systemd_round_trip() {
+    use crate::parse::{Dialect, Parser};
+
+    // A line feed is read back as a space
+    let values = [
+        ("Continued", "/bin/a\n--flag",   "/bin/a --flag"),
+        ("Hash",      "a\n#b",            "a #b"),
+        ("Semicolon", "a\n  ;b",          "a   ;b"),
+        ("Empty",     "a\n\nb",          "a  b"),
+        ("Trailing",  "C:\\dir\\",     "C:\\dir\\"),
+        ("Inner",     "a\\\nb",         "a\\ b"),
+        ("Even",      "a\\\\\nb",     "a\\\\ b"),
+    ];
+
+    let service = Some(String::from("Service"));
+    let mut dumper = Dumper::with_dialect(Dialect::Systemd);
+    for (key, value, _) in values.iter() {
+        dumper.dump(Identifier::new(service.clone(), String::from(*key)), Value::Raw(String::from(*value)));
+        dumper.dump(Identifier::new(service.clone(), String::from("Next")), Value::Raw(String::from(*key)));
+    }
+    let content = dumper.generate();
+    assert_eq!(content.lines().filter(|line| line.starts_with("Next=")).count(), values.len());
+
+    let mut parser = Parser::with_dialect(Dialect::Systemd);
+    parser.parse_content(&content).unwrap();
+    let unit = parser.unit_file();
+    for (key, _, expected) in values.iter() {
+        assert_eq!(unit.get("Service", key), Some(&Value::Raw(String::from(*expected))), "{}", key);
+    }
+}
+
+#[test]
+fn dumper_utf8_values() {
+    use crate::datas::TextMode;
+    use crate::parse::Parser;
+
+    let mut dumper = Dumper::new();
+    dumper.set_text_mode(TextMode::Utf8);
+    dumper.dump(Identifier::new(None, String::from("name")), Value::Str(String::from("日本語 = café")));
+    dumper.dump(Identifier::new(None, String::from("path")), Value::Raw(String::from("C:\\x0000e9")));
+    let content = dumper.generate();
+    assert_eq!(content, "name='日本語 \\= café'\npath=C\\:\\\\x0000e9\n");
+
+    let mut parser = Parser::new();
+    parser.set_text_mode(TextMode::Utf8);
+    parser.parse_content(&content).unwrap();
+    let data = parser.data();
+    assert_eq!(data[&Identifier::new(None, String::from("name"))], Value::Str(String::from("日本語 = café")));
+    assert_eq!(data[&Identifier::new(None, String::from("path"))], Value::Raw(String::from("C:\\x0000e9")));
+}
+
+#[test]
+fn dumper_line_endings() {
+    use crate::datas::LineEnding;
+    use crate::parse::Dialect;
+
+    let mut dumper = Dumper::new();
+    dumper.set_line_ending(LineEnding::CrLf);
+    dumper.dump(Identifier::new(None, String::from("a")), Value::Int(1));
+    dumper.dump(Identifier::new(Some(String::from("s")), String::from("b")), Value::Int(2));
+    assert_eq!(dumper.generate(), "a=1\r\n\r\n[s]\r\nb=2\r\n");
+
+    let mut dumper = Dumper::with_dialect(Dialect::Systemd);
+    dumper.set_line_ending(LineEnding::Cr);
+    dumper.dump(Identifier::new(Some(String::from("Service")), String::from("ExecStart")), Value::Raw(String::from("/bin/a\n--flag")));
+    assert_eq!(dumper.generate(), "[Service]\rExecStart=/bin/a\\\r--flag\r");
+
+    assert_eq!(Dumper::new().generate(), "");
+}
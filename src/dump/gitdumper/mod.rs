@@ -0,0 +1,140 @@
+//! Provides a `GitDumper` structure which creates a new git configuration file content
+
+use crate::datas::{LineEnding, Value};
+use crate::datas::gitconfig::{GitConfig, GitIdentifier};
+
+/// A section name and its optional subsection
+type Section = (String, Option<String>);
+
+/// A stated object, which from couples of [`GitIdentifier`](../datas/gitconfig/struct.GitIdentifier.html "datas::gitconfig::GitIdentifier") and [`Value`](../datas/enum.Value.html "datas::Value"), creates a new git configuration readable with [`Dialect::GitConfig`](../parse/enum.Dialect.html "parse::Dialect")
+/// 
+/// Unlike a [`Dumper`](struct.Dumper.html "dump::Dumper"), it keeps the order of the sections and of the values since a key may have several values
+/// 
+/// # Example
+/// ```
+/// use mininip::dump::GitDumper;
+/// use mininip::datas::Value;
+/// use mininip::datas::gitconfig::GitIdentifier;
+/// 
+/// let mut dumper = GitDumper::new();
+/// 
+/// let bare = GitIdentifier::new(String::from("core"), None, String::from("bare"));
+/// dumper.dump(bare, Value::Bool(true));
+/// 
+/// let fetch = GitIdentifier::new(String::from("remote"), Some(String::from("origin")), String::from("fetch"));
+/// dumper.dump(fetch.clone(), Value::Raw(String::from("+refs/heads/*:refs/remotes/origin/*")));
+/// dumper.dump(fetch, Value::Raw(String::from("+refs/tags/*:refs/tags/*")));
+/// 
+/// let comment = GitIdentifier::new(String::from("user"), None, String::from("name"));
+/// dumper.dump(comment, Value::Raw(String::from(" Jane #1 ")));
+/// 
+/// let expected = "\
+/// [core]\n\
+/// \tbare\n\
+/// [remote \"origin\"]\n\
+/// \tfetch = +refs/heads/*:refs/remotes/origin/*\n\
+/// \tfetch = +refs/tags/*:refs/tags/*\n\
+/// [user]\n\
+/// \tname = \" Jane #1 \"\n";
+/// 
+/// assert_eq!(dumper.generate(), expected);
+/// ```
+#[derive(Debug, Default)]
+pub struct GitDumper {
+    /// The sections and subsections in the order they were dumped, with their assignment lines
+    sections: Vec<(Section, Vec<String>)>,
+    /// The line ending written after each line
+    line_ending: LineEnding,
+}
+
+impl GitDumper {
+    /// Creates a new `GitDumper` object
+    pub fn new() -> GitDumper {
+        GitDumper {
+            sections: Vec::new(),
+            line_ending: LineEnding::default(),
+        }
+    }
+
+    /// Changes the line ending written after each line. It is [`LineEnding::Lf`](../datas/enum.LineEnding.html "datas::LineEnding") by default
+    pub fn set_line_ending(&mut self, ending: LineEnding) {
+        self.line_ending = ending;
+    }
+
+    /// Dumps a couple [`GitIdentifier`](../datas/gitconfig/struct.GitIdentifier.html "datas::gitconfig::GitIdentifier") / [`Value`](../datas/enum.Value.html "datas::Value") into `self`, after the values already dumped for the same key
+    /// 
+    /// `Value::Bool(true)` is dumped as a key without any value, `Value::Bool(false)` as `false` and any other value is dumped as its text, quoted if needed
+    pub fn dump(&mut self, identifier: GitIdentifier, value: Value) {
+        let line = match value {
+            Value::Bool(true)  => format!("\t{}", identifier.key()),
+            Value::Bool(false) => format!("\t{} = false", identifier.key()),
+            value              => format!("\t{} = {}", identifier.key(), dump_git_value(&format!("{}", value))),
+        };
+
+        let section = (String::from(identifier.section()), identifier.subsection().map(String::from));
+        match self.sections.iter_mut().find(|(i, _)| *i == section) {
+            Some((_, lines)) => lines.push(line),
+            None             => self.sections.push((section, vec![line])),
+        }
+    }
+
+    /// Dumps every variable of `config` into `self`, in the order they were declared
+    pub fn dump_config(&mut self, config: GitConfig) {
+        for (identifier, value) in config.into_entries() {
+            self.dump(identifier, value);
+        }
+    }
+
+    /// Generates a `String` containing the code of the git configuration stored in the `GitDumper`
+    pub fn generate(self) -> String {
+        let mut result = String::new();
+        for ((section, subsection), lines) in self.sections {
+            result.push('[');
+            result.push_str(&section);
+            if let Some(subsection) = subsection {
+                result.push_str(" \"");
+                result.push_str(&subsection.replace('\\', "\\\\").replace('"', "\\\""));
+                result.push('"');
+            }
+            result.push(']');
+            result.push_str(self.line_ending.as_str());
+
+            for i in lines {
+                result.push_str(&i);
+                result.push_str(self.line_ending.as_str());
+            }
+        }
+
+        result
+    }
+}
+
+/// Escapes `value` with the git-config syntax and puts it between double quotes if its whitespaces at both ends, its comment characters or its carriage returns would be lost otherwise, or if it would be read as a `Value::Bool`
+fn dump_git_value(value: &str) -> String {
+    let mut new = String::with_capacity(value.len());
+    for i in value.chars() {
+        match i {
+            '\\'   => new.push_str(r"\\"),
+            '"'    => new.push_str("\\\""),
+            '\n'   => new.push_str("\\n"),
+            '\t'   => new.push_str("\\t"),
+            '\x08' => new.push_str("\\b"),
+            _      => new.push(i),
+        }
+    }
+
+    let needs_quotes = value.starts_with(char::is_whitespace)
+                    || value.ends_with(char::is_whitespace)
+                    || value.contains(['#', ';', '\r'])
+                    || value == "true"
+                    || value == "false";
+    if needs_quotes {
+        format!("\"{}\"", new)
+    } else {
+        new
+    }
+}
+
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,125 @@
+use crate::dump::gitdumper::*;
+use crate::datas::Value;
+use crate::datas::gitconfig::GitIdentifier;
+use crate::parse::{Dialect, Parser};
+
+fn parse(content: &str) -> GitConfig {
+    let mut parser = Parser::with_dialect(Dialect::GitConfig);
+    parser.parse_content(content).unwrap();
+
+    parser.git_config()
+}
+
+#[test]
+fn gitdumper_values() {
+    let mut dumper = GitDumper::new();
+    let ident = |key: &str| GitIdentifier::new(String::from("a"), Some(String::from("sub \"quoted\" \\")), String::from(key));
+
+    dumper.dump(ident("yes"), Value::Bool(true));
+    dumper.dump(ident("no"), Value::Bool(false));
+    dumper.dump(ident("int"), Value::Int(-3));
+    dumper.dump(ident("escapes"), Value::Raw(String::from("tab\there\nquote\" backslash\\")));
+    dumper.dump(ident("comment"), Value::Str(String::from("a;b")));
+
+    let expected = "\
+    [a \"sub \\\"quoted\\\" \\\\\"]\n\
+    \tyes\n\
+    \tno = false\n\
+    \tint = -3\n\
+    \tescapes = tab\\there\\nquote\\\" backslash\\\\\n\
+    \tcomment = \"a;b\"\n";
+    assert_eq!(dumper.generate(), expected);
+}
+
+#[test]
+fn gitdumper_round_trip() {
+    let content = "\
+    [core]\n\
+    \tbare\n\
+    \tEditor = \"  vim \" # comment\n\
+    [remote \"origin\"]\n\
+    \tfetch = a\n\
+    \turl = u\n\
+    \tfetch = b\\tc\n\
+    [core]\n\
+    \tfetch = d\n";
+    let config = parse(content);
+
+    let mut dumper = GitDumper::new();
+    dumper.dump_config(config.clone());
+    let dumped = dumper.generate();
+
+    let expected = "\
+    [core]\n\
+    \tbare\n\
+    \teditor = \"  vim \"\n\
+    \tfetch = d\n\
+    [remote \"origin\"]\n\
+    \tfetch = a\n\
+    \turl = u\n\
+    \tfetch = b\\tc\n";
+    assert_eq!(dumped, expected);
+
+    let reparsed = parse(&dumped);
+    assert_eq!(reparsed.get_all("remote", Some("origin"), "fetch"), config.get_all("remote", Some("origin"), "fetch"));
+    assert_eq!(reparsed.get("core", None, "editor"), config.get("core", None, "editor"));
+    assert_eq!(reparsed.get("core", None, "bare"), Some(&Value::Bool(true)));
+}
+
+#[test]
+fn gitdumper_empty() {
+    assert_eq!(GitDumper::new().generate(), "");
+}
+
+#[test]
+fn gitdumper_line_endings() {
+    use crate::datas::LineEnding;
+
+    let mut parser = Parser::with_dialect(Dialect::GitConfig);
+    parser.parse_content("[core]\r\n\tbare\r\n").unwrap();
+    let ending = parser.line_ending().unwrap_or_default();
+
+    let mut dumper = GitDumper::new();
+    dumper.set_line_ending(ending);
+    dumper.dump_config(parser.git_config());
+
+    assert_eq!(ending, LineEnding::CrLf);
+    assert_eq!(dumper.generate(), "[core]\r\n\tbare\r\n");
+}
+
+#[test]
+fn gitdumper_round_trip_booleans() {
+    let ident = |key: &str| GitIdentifier::new(String::from("core"), None, String::from(key));
+    let values = [("yes", Value::Bool(true)), ("no", Value::Bool(false)),
+                  ("raw-true", Value::Raw(String::from("true"))), ("raw-false", Value::Raw(String::from("false")))];
+
+    let mut dumper = GitDumper::new();
+    for (key, value) in values.iter() {
+        dumper.dump(ident(key), value.clone());
+    }
+    let dumped = dumper.generate();
+
+    assert_eq!(dumped, "[core]\n\tyes\n\tno = false\n\traw-true = \"true\"\n\traw-false = \"false\"\n");
+    let config = parse(&dumped);
+    for (key, value) in values.iter() {
+        assert_eq!(config.get("core", None, key), Some(value));
+    }
+}
+
+#[test]
+fn gitdumper_round_trip_carriage_returns() {
+    use crate::datas::LineEnding;
+
+    let values = ["a\rb", "end\r", "\r", "cr\r\nlf"];
+    for ending in &[LineEnding::Lf, LineEnding::CrLf, LineEnding::Cr] {
+        let mut dumper = GitDumper::new();
+        dumper.set_line_ending(*ending);
+        for value in values.iter() {
+            dumper.dump(GitIdentifier::new(String::from("core"), None, String::from("key")), Value::Raw(String::from(*value)));
+        }
+        let dumped = dumper.generate();
+
+        let expected: Vec<Value> = values.iter().map(|value| Value::Raw(String::from(*value))).collect();
+        assert_eq!(parse(&dumped).get_all("core", None, "key"), expected.iter().collect::<Vec<_>>(), "{:?}", dumped);
+    }
+}
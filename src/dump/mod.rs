@@ -1,5 +1,9 @@
 //! Provides tools to generate a INI file from any data
 
+use std::time::Duration;
+use crate::datas::TextMode;
+use crate::parse::{DURATION_UNITS, SIZE_UNITS};
+
 /// Formats a `&str` by escaping special characters
 /// 
 /// # Return value
@@ -13,7 +17,7 @@
 /// More escaped characters may be found at [Wikipedia](https://en.wikipedia.org/wiki/INI_file#Escape_characters "INI file")
 /// 
 /// # The Unicode special case
-/// A non-ASCII character is escaped as a `\x??????` with exactly 6 hexadecimal digits even if a smaller number is suitable
+/// A non-ASCII character is escaped as a `\x??????` with exactly 6 hexadecimal digits even if a smaller number is suitable. See [`dump_str_with`](fn.dump_str_with.html "dump::dump_str_with") to write it as is
 /// 
 /// # Examples
 /// ```
@@ -23,6 +27,20 @@
 /// assert_eq!(dump_str("\u{263a}"),  r"\x00263a");
 /// ```
 pub fn dump_str(content: &str) -> String {
+    dump_str_with(content, TextMode::Ascii)
+}
+
+/// Formats a `&str` like [`dump_str`](fn.dump_str.html "dump::dump_str") does, but writes the non-ASCII characters as is if `mode` is `TextMode::Utf8`
+/// 
+/// # Examples
+/// ```
+/// use mininip::datas::TextMode;
+/// use mininip::dump::dump_str_with;
+/// 
+/// assert_eq!(dump_str_with("très = \u{263a}", TextMode::Utf8), "très \\= \u{263a}");
+/// assert_eq!(dump_str_with("très", TextMode::Ascii), r"tr\x0000e8s");
+/// ```
+pub fn dump_str_with(content: &str, mode: TextMode) -> String {
     let mut new = String::with_capacity(content.len());
 
     for i in content.chars() {
@@ -42,8 +60,8 @@ pub fn dump_str(content: &str) -> String {
             '='    => new.push_str("\\="),
             ':'    => new.push_str("\\:"),
 
-            // The ASCII characters are left unchanged
-            _ if i.is_ascii() => new.push_str(&format!("{}", i)),
+            // The ASCII characters are left unchanged, like the other ones in UTF-8
+            _ if i.is_ascii() || mode == TextMode::Utf8 => new.push(i),
 
             // The non-ASCII characters are escaped with `\x??????`
             _ => new.push_str(&format!("\\x{:06x}", i as u32)),
@@ -53,9 +71,82 @@ pub fn dump_str(content: &str) -> String {
     new
 }
 
+/// Formats a `Duration` in a canonical way, using the biggest unit which represents it exactly
+/// 
+/// # Return value
+/// A `String` containing the duration as an integer followed by a unit
+/// 
+/// When this integer would not fit in a `u64`, which may happen with a fraction of a second, the seconds and the fraction are written separately, like `18446744073709551615s 500ms`
+/// 
+/// # See
+/// See [`parse_duration`](../parse/fn.parse_duration.html "parse::parse_duration") for the list of units
+/// 
+/// # Examples
+/// ```
+/// use mininip::dump::dump_duration;
+/// use std::time::Duration;
+/// 
+/// assert_eq!(dump_duration(&Duration::from_secs(300)), "5m");
+/// assert_eq!(dump_duration(&Duration::from_millis(1500)), "1500ms");
+/// assert_eq!(dump_duration(&Duration::from_secs(0)), "0s");
+/// assert_eq!(dump_duration(&Duration::new(u64::MAX, 1)), "18446744073709551615s 1ns");
+/// ```
+pub fn dump_duration(duration: &Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos == 0 {
+        return String::from("0s");
+    }
+
+    // The aliases `µs` and `min` are never used when dumping
+    let (unit, factor) = DURATION_UNITS.iter()
+                                       .filter(|(unit, _)| *unit != "\u{b5}s" && *unit != "min")
+                                       .rev()
+                                       .find(|(_, factor)| nanos % factor == 0)
+                                       .expect("Any duration is a multiple of a nanosecond");
+
+    // `parse_duration` only reads a number fitting in a `u64`
+    if nanos / factor > u64::MAX as u128 {
+        return format!("{}s {}", duration.as_secs(), dump_duration(&Duration::new(0, duration.subsec_nanos())));
+    }
+
+    format!("{}{}", nanos / factor, unit)
+}
+
+/// Formats a size in bytes in a canonical way, using the biggest SI or IEC unit which represents it exactly
+/// 
+/// # Return value
+/// A `String` containing the size as an integer followed by a unit
+/// 
+/// # See
+/// See [`parse_size`](../parse/fn.parse_size.html "parse::parse_size") for the list of units
+/// 
+/// # Examples
+/// ```
+/// use mininip::dump::dump_size;
+/// 
+/// assert_eq!(dump_size(65_536), "64KiB");
+/// assert_eq!(dump_size(2_000_000_000), "2GB");
+/// assert_eq!(dump_size(1_001), "1001B");
+/// ```
+pub fn dump_size(size: u64) -> String {
+    if size == 0 {
+        return String::from("0B");
+    }
+
+    // The alias `KB` is never used when dumping
+    let (unit, factor) = SIZE_UNITS.iter()
+                                   .filter(|(unit, factor)| *unit != "KB" && size % factor == 0)
+                                   .max_by_key(|(_, factor)| *factor)
+                                   .expect("Any size is a multiple of a byte");
+
+    format!("{}{}", size / factor, unit)
+}
+
 
 mod dumper;
 pub use dumper::*;
+mod gitdumper;
+pub use gitdumper::GitDumper;
 
 #[cfg(test)]
 mod tests;
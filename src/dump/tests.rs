@@ -1,4 +1,5 @@
 use crate::dump::*;
+use std::time::Duration;
 
 /// Tests only the constant substitutions such as `\` -> `\\` and not the runtime-computed ones
 #[test]
@@ -35,3 +36,42 @@ fn dump_str_complementary_test() {
     assert_eq!(dump_str("très_content=☺ ; the symbol of hapiness"), "tr\\x0000e8s_content\\=\\x00263a \\; the symbol of hapiness");
 }
 
+#[test]
+fn dump_str_with_utf8() {
+    use crate::datas::TextMode;
+
+    assert_eq!(dump_str_with("très_content=☺ ; 日本語", TextMode::Utf8), "très_content\\=☺ \\; 日本語");
+    assert_eq!(dump_str_with("très", TextMode::Ascii), dump_str("très"));
+}
+
+
+#[test]
+fn dump_duration_canonical_unit() {
+    assert_eq!(dump_duration(&Duration::from_nanos(1)), "1ns");
+    assert_eq!(dump_duration(&Duration::from_micros(1500)), "1500us");
+    assert_eq!(dump_duration(&Duration::from_millis(250)), "250ms");
+    assert_eq!(dump_duration(&Duration::from_secs(30)), "30s");
+    assert_eq!(dump_duration(&Duration::from_secs(90)), "90s");
+    assert_eq!(dump_duration(&Duration::from_secs(7200)), "2h");
+    assert_eq!(dump_duration(&Duration::from_secs(2 * 86400)), "2d");
+    assert_eq!(dump_duration(&Duration::new(0, 0)), "0s");
+}
+
+#[test]
+fn dump_duration_round_trip() {
+    for duration in &[Duration::new(u64::MAX, 1), Duration::new(u64::MAX, 250_000_000), Duration::new(u64::MAX / 1000, 1_000), Duration::new(u64::MAX, 0)] {
+        assert_eq!(crate::parse::parse_duration(&dump_duration(duration)).unwrap(), Some(*duration), "for {:?}", duration);
+    }
+
+    assert_eq!(dump_duration(&Duration::new(u64::MAX, 250_000_000)), "18446744073709551615s 250ms");
+}
+
+#[test]
+fn dump_size_canonical_unit() {
+    assert_eq!(dump_size(0), "0B");
+    assert_eq!(dump_size(512), "512B");
+    assert_eq!(dump_size(2_000), "2kB");
+    assert_eq!(dump_size(1 << 20), "1MiB");
+    assert_eq!(dump_size(2_000_000_000), "2GB");
+    assert_eq!(dump_size(u64::MAX), format!("{}B", u64::MAX));
+}
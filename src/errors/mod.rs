@@ -13,6 +13,8 @@ pub enum Error {
     UnexpectedToken(error_kinds::UnexpectedToken),
     InvalidEscape(error_kinds::InvalidEscape),
     InvalidIdentifier(error_kinds::InvalidIdentifier),
+    Overflow(error_kinds::Overflow),
+    WrongType(error_kinds::WrongType),
 }
 
 impl error::Error for Error {}
@@ -26,6 +28,8 @@ impl Display for Error {
             Error::UnexpectedToken(err)    => write!(f, "{}", err),
             Error::InvalidEscape(err)      => write!(f, "{}", err),
             Error::InvalidIdentifier(err)  => write!(f, "{}", err),
+            Error::Overflow(err)           => write!(f, "{}", err),
+            Error::WrongType(err)          => write!(f, "{}", err),
         }
     }
 }
@@ -66,6 +70,18 @@ impl From<error_kinds::InvalidIdentifier> for Error {
     }
 }
 
+impl From<error_kinds::Overflow> for Error {
+    fn from(src: error_kinds::Overflow) -> Error {
+        Error::Overflow(src)
+    }
+}
+
+impl From<error_kinds::WrongType> for Error {
+    fn from(err: error_kinds::WrongType) -> Error {
+        Error::WrongType(err)
+    }
+}
+
 /// Contains all the error types used in `Error`'s variants
 pub mod error_kinds {
     use std::error;
@@ -305,16 +321,124 @@ pub mod error_kinds {
                 ident: identifier,
             }
         }
+
+        /// Creates a new `InvalidIdentifier` error for an identifier which is invalid according to `policy`
+        /// 
+        /// # Panics
+        /// Panics
+        /// - if `identifier`, once normalized by `policy`, is valid according to `policy`
+        /// - if `identifier` is not in `line`
+        pub fn with_policy(line: String, identifier: String, policy: crate::datas::IdentifierPolicy) -> InvalidIdentifier {
+            assert!(line.find(&identifier).is_some(), "`line` must contain `identifier`");
+            assert!(!policy.is_valid(&policy.normalize(&identifier)), "`identifier` must be an invalid identifier");
+
+            InvalidIdentifier {
+                line,
+                ident: identifier,
+            }
+        }
+    }
+
+    /// A parsing error happening when a value is well-formed but too big to be stored in its type
+    /// 
+    /// # See
+    /// See [`Value::parse`](../../datas/enum.Value.html#method.parse "datas::Value::parse") for the types which may overflow
+    #[derive(Debug)]
+    pub struct Overflow {
+        line: String,
+        value: String,
+        type_name: &'static str,
+    }
+
+    impl error::Error for Overflow {}
+
+    impl Display for Overflow {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "Value {} is too big to be stored in {} in {}", self.value, self.type_name, self.line)
+        }
+    }
+
+    impl Overflow {
+        /// Creates a new `Overflow` error
+        /// 
+        /// # Parameters
+        /// `line`: the line where the error occured
+        /// 
+        /// `value`: the value which overflows
+        /// 
+        /// `type_name`: the name of the type the value is too big for, like `u64`
+        /// 
+        /// # Panics
+        /// Panics if `value` is not in `line`
+        pub fn new(line: String, value: String, type_name: &'static str) -> Overflow {
+            assert!(line.contains(&value), "`line` must contain `value`");
+
+            Overflow {
+                line,
+                value,
+                type_name,
+            }
+        }
+
+        /// Replaces the line of `self` by `line` if it contains the value, which is useful when the error was raised by a function reading the value alone
+        pub(crate) fn in_line(self, line: &str) -> Overflow {
+            if line.contains(&self.value) {
+                Overflow {
+                    line: String::from(line),
+                    ..self
+                }
+            } else {
+                self
+            }
+        }
+    }
+
+    /// A parsing error happening when a value can't be read as the type forced for its key
+    /// 
+    /// # See
+    /// See [`Value::parse_as`](../../datas/enum.Value.html#method.parse_as "datas::Value::parse_as") and [`Parser::set_type_hints`](../../parse/struct.Parser.html#method.set_type_hints "parse::Parser::set_type_hints")
+    #[derive(Debug)]
+    pub struct WrongType {
+        line: String,
+        expected: &'static str,
+        found: &'static str,
+    }
+
+    impl error::Error for WrongType {}
+
+    impl Display for WrongType {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "Expected a value of type {} but found a value of type {} in {}", self.expected, self.found, self.line)
+        }
+    }
+
+    impl WrongType {
+        /// Creates a new `WrongType` error
+        /// 
+        /// # Parameters
+        /// `line`: the line where the error occured
+        /// 
+        /// `expected`: the name of the type forced
+        /// 
+        /// `found`: the name of the type the value is written as
+        pub fn new(line: String, expected: &'static str, found: &'static str) -> WrongType {
+            WrongType {
+                line,
+                expected,
+                found,
+            }
+        }
     }
 }
 
-/// Represents either an IO error or a parsing error
+/// Represents either an IO error, a parsing error or an encoding error
 /// 
-/// Is used by this library in [`parse_file`](../parse/fn.parse_file.html "parse::parse_file") which may encounter an error with the file to parse or with its content
+/// Is used by this library in [`parse_file`](../parse/fn.parse_file.html "parse::parse_file") which may encounter an error with the file to parse or with its content, and by [`dump_profile_into_file`](../dump/fn.dump_profile_into_file.html "dump::dump_profile_into_file") which may fail to write the file or to encode its content
 #[derive(Debug)]
 pub enum ParseFileError {
     IOError(io::Error),
     ParseError(Error),
+    EncodingError(EncodingError),
 }
 
 impl error::Error for ParseFileError {}
@@ -322,8 +446,9 @@ impl error::Error for ParseFileError {}
 impl Display for ParseFileError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            ParseFileError::IOError(err)    => write!(f, "{}", err),
-            ParseFileError::ParseError(err) => write!(f, "{}", err),
+            ParseFileError::IOError(err)       => write!(f, "{}", err),
+            ParseFileError::ParseError(err)    => write!(f, "{}", err),
+            ParseFileError::EncodingError(err) => write!(f, "{}", err),
         }
     }
 }
@@ -340,6 +465,224 @@ impl From<Error> for ParseFileError {
     }
 }
 
+impl From<EncodingError> for ParseFileError {
+    fn from(err: EncodingError) -> ParseFileError {
+        ParseFileError::EncodingError(err)
+    }
+}
+
+/// Represents a failure while decoding or encoding the text of an INI file in an [`Encoding`](../datas/encoding/enum.Encoding.html "datas::encoding::Encoding")
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::encoding::Encoding;
+/// 
+/// let err = Encoding::Latin1.encode("caf\u{e9} \u{263a}", false).unwrap_err();
+/// assert_eq!(err.encoding(), Encoding::Latin1);
+/// assert_eq!(err.offset(), 6);
+/// assert_eq!(err.character(), Some('\u{263a}'));
+/// assert_eq!(format!("{}", err), "The character '\u{263a}' at byte 6 can't be written in Latin-1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodingError {
+    encoding: crate::datas::encoding::Encoding,
+    offset: usize,
+    character: Option<char>,
+}
+
+impl error::Error for EncodingError {}
+
+impl Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.character {
+            Some(c) => write!(f, "The character {:?} at byte {} can't be written in {}", c, self.offset, self.encoding),
+            None    => write!(f, "Invalid {} data at byte {}", self.encoding, self.offset),
+        }
+    }
+}
+
+impl EncodingError {
+    /// Creates a new `EncodingError`
+    /// 
+    /// # Parameters
+    /// `encoding`: the encoding of the text
+    /// 
+    /// `offset`: the offset of the error in bytes, in the data decoded or in the text encoded
+    /// 
+    /// `character`: the character which can't be encoded, or `None` if the error happened while decoding
+    pub fn new(encoding: crate::datas::encoding::Encoding, offset: usize, character: Option<char>) -> EncodingError {
+        EncodingError {
+            encoding,
+            offset,
+            character,
+        }
+    }
+
+    /// Returns the encoding of the text
+    pub fn encoding(&self) -> crate::datas::encoding::Encoding {
+        self.encoding
+    }
+
+    /// Returns the offset of the error in bytes
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the character which can't be encoded, or `None` if the data can't be decoded
+    pub fn character(&self) -> Option<char> {
+        self.character
+    }
+}
+
+/// Represents a failure while converting a [`Value`](../datas/enum.Value.html "datas::Value") into another type
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::Value;
+/// use std::convert::TryFrom;
+/// 
+/// let err = Value::Str(String::from("42")).as_i64().unwrap_err();
+/// assert_eq!(err.expected(), "Int");
+/// assert_eq!(err.found(), "Str");
+/// assert_eq!(format!("{}", err), "Expected a value of type Int but found a value of type Str");
+/// 
+/// let err = u8::try_from(&Value::Int(300)).unwrap_err();
+/// assert_eq!(err.value(), Some("300"));
+/// assert_eq!(format!("{}", err), "Value 300 of type Int is out of the range of type u8");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionError {
+    expected: &'static str,
+    found: &'static str,
+    value: Option<String>,
+}
+
+impl error::Error for ConversionError {}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "Value {} of type {} is out of the range of type {}", value, self.found, self.expected),
+            None        => write!(f, "Expected a value of type {} but found a value of type {}", self.expected, self.found),
+        }
+    }
+}
+
+impl ConversionError {
+    /// Creates a new `ConversionError`
+    /// 
+    /// # Parameters
+    /// `expected`: the name of the type expected
+    /// 
+    /// `found`: the name of the type found (see [`Value::type_name`](../datas/enum.Value.html#method.type_name "datas::Value::type_name"))
+    pub fn new(expected: &'static str, found: &'static str) -> ConversionError {
+        ConversionError {
+            expected,
+            found,
+            value: None,
+        }
+    }
+
+    /// Creates a new `ConversionError` for a value whose type is right but which does not fit in the type expected
+    /// 
+    /// # Parameters
+    /// `expected`: the name of the type expected
+    /// 
+    /// `found`: the name of the type found
+    /// 
+    /// `value`: the value out of range, as it is displayed
+    pub fn out_of_range(expected: &'static str, found: &'static str, value: String) -> ConversionError {
+        ConversionError {
+            expected,
+            found,
+            value: Some(value),
+        }
+    }
+
+    /// Returns the name of the type expected
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// Returns the name of the type found
+    pub fn found(&self) -> &'static str {
+        self.found
+    }
+
+    /// Returns the value out of range if the conversion failed because of it, or `None` if the type found is wrong
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+/// Represents a failure while mapping a Rust type from or into the data of an INI file (see [`FromIni`](../datas/trait.FromIni.html "datas::FromIni") and [`ToIni`](../datas/trait.ToIni.html "datas::ToIni"))
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::{FromIni, Identifier};
+/// use mininip::datas::tree::Tree;
+/// use mininip::errors::MappingError;
+/// use std::collections::HashMap;
+/// 
+/// let data = HashMap::new();
+/// let port = Identifier::new(None, String::from("port"));
+/// match u16::from_key(&data, &port) {
+///     Err(MappingError::MissingKey(ident)) => assert_eq!(ident, port),
+///     result                               => panic!("Wrong result returned (got {:?})", result),
+/// }
+/// 
+/// assert!(u16::from_tree(&Tree::from(data)).is_err()); // A number is not a section
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MappingError {
+    /// A required key is not defined
+    MissingKey(crate::datas::Identifier),
+    /// The value of a key can't be converted into the type expected
+    InvalidValue(crate::datas::Identifier, ConversionError),
+    /// The type named can't be mapped from or into a whole section
+    NotASection(&'static str),
+    /// A section is expected at this identifier but sections can't be nested
+    NestedSection(crate::datas::Identifier),
+}
+
+impl error::Error for MappingError {}
+
+impl Display for MappingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MappingError::MissingKey(ident)          => write!(f, "Missing key {}", ident),
+            MappingError::InvalidValue(ident, err)   => write!(f, "Invalid value for {}: {}", ident, err),
+            MappingError::NotASection(type_name)     => write!(f, "A value of type {} can't be mapped to a section", type_name),
+            MappingError::NestedSection(ident)       => write!(f, "A section can't be nested at {}", ident),
+        }
+    }
+}
+
+/// Represents a failure while resolving the `%(name)s` interpolations of a value read with [`ConfigParserView`](../datas/configparser/struct.ConfigParserView.html "datas::configparser::ConfigParserView")
+/// 
+/// The identifier stored is the one of the value read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterpolationError {
+    /// The key named is not defined in the section nor in `[DEFAULT]`
+    MissingReference(crate::datas::Identifier, String),
+    /// A `%` is not followed by `%` or by `(name)s`
+    InvalidSyntax(crate::datas::Identifier),
+    /// The interpolations are nested too deeply, which is probably a reference loop
+    TooDeep(crate::datas::Identifier),
+}
+
+impl error::Error for InterpolationError {}
+
+impl Display for InterpolationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InterpolationError::MissingReference(ident, name) => write!(f, "Bad value substitution at {}: no key {} found", ident, name),
+            InterpolationError::InvalidSyntax(ident)          => write!(f, "Invalid interpolation syntax at {}: `%` must be followed by `%` or `(name)s`", ident),
+            InterpolationError::TooDeep(ident)                => write!(f, "Interpolations nested too deeply at {}", ident),
+        }
+    }
+}
+
 /// Returns the character at the `index`th index (`index` is in bytes) in `string`
 /// 
 /// # Panics
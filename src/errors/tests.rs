@@ -45,6 +45,23 @@ fn invalid_escape_format() {
     assert_eq!(fmt, "Invalid escape sequence \\xyzabcd in ident=\\xyzabcd");
 }
 
+#[test]
+fn overflow_format() {
+    let line = String::from("size = 99999999999999999999KiB");
+    let err = error_kinds::Overflow::new(line, String::from("99999999999999999999KiB"), "u64");
+
+    let fmt = format!("{}", err);
+    assert_eq!(fmt, "Value 99999999999999999999KiB is too big to be stored in u64 in size = 99999999999999999999KiB");
+}
+
+#[test]
+fn wrong_type_format() {
+    let err = error_kinds::WrongType::new(String::from("abc"), "Int", "Raw");
+
+    let fmt = format!("{}", err);
+    assert_eq!(fmt, "Expected a value of type Int but found a value of type Raw in abc");
+}
+
 #[test]
 #[should_panic]
 fn expected_identifier_overflow() {
@@ -78,7 +95,7 @@ fn expected_escape_alignment_error() {
 #[should_panic]
 fn unexpected_token_overflow() {
     let line = String::from("hello world");
-    let _err = error_kinds::UnexpectedToken::new(line, 1000_000);
+    let _err = error_kinds::UnexpectedToken::new(line, 1_000_000);
 }
 
 #[test]
@@ -96,6 +113,31 @@ fn invalid_escape_not_included() {
     let _err = error_kinds::InvalidEscape::new(line, String::from("\\{"));
 }
 
+#[test]
+#[should_panic]
+fn overflow_not_included() {
+    let line = String::from("size = 1KiB");
+    let _err = error_kinds::Overflow::new(line, String::from("2KiB"), "u64");
+}
+
+#[test]
+fn conversion_error_format() {
+    let err = ConversionError::new("u8", "Int");
+
+    let fmt = format!("{}", err);
+    assert_eq!(fmt, "Expected a value of type u8 but found a value of type Int");
+    assert_eq!(err.value(), None);
+}
+
+#[test]
+fn conversion_error_out_of_range_format() {
+    let err = ConversionError::out_of_range("u8", "Int", String::from("300"));
+
+    let fmt = format!("{}", err);
+    assert_eq!(fmt, "Value 300 of type Int is out of the range of type u8");
+    assert_eq!(err.value(), Some("300"));
+}
+
 #[test]
 fn nth_char_works_well() {
     assert_eq!(nth_char("abcdefg", 1), 'b');
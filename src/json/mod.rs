@@ -0,0 +1,220 @@
+//! Conversion between a [`Tree`](../datas/tree/struct.Tree.html "datas::tree::Tree") and JSON (requires the `serde_json` feature)
+//! 
+//! The keys of the global section are the scalar members of the root object while each section is an object member of the root. The values are converted as follows
+//! - `Int` and `Float` are JSON numbers
+//! - `Bool` is a JSON boolean
+//! - `Raw` and `Str` are JSON strings, told apart according to a [`StringConvention`](enum.StringConvention.html "json::StringConvention")
+//! - `Duration`, `Size`, `Date`, `Time` and `DateTime` are JSON strings of their INI text, read back like a `Raw`
+//! 
+//! # Example
+//! ```
+//! use mininip::datas::tree::Tree;
+//! use mininip::json::{self, StringConvention};
+//! use mininip::parse::Parser;
+//! 
+//! let mut parser = Parser::new();
+//! parser.parse_content("name = 'app'\n[server]\nport = 8080\nsecure = on\n").unwrap();
+//! let tree = Tree::from(parser.data());
+//! 
+//! let json = json::to_value(&tree, StringConvention::Quoted).unwrap();
+//! assert_eq!(json.to_string(), r#"{"name":"'app'","server":{"port":8080,"secure":true}}"#);
+//! 
+//! let back = json::from_value(&json, StringConvention::Quoted).unwrap();
+//! assert_eq!(back.get_data(), tree.get_data());
+//! ```
+
+use crate::datas::{Identifier, Value};
+use crate::datas::tree::{nested, Tree};
+use serde_json::{map, Map, Number};
+use std::error;
+use std::fmt::{self, Display};
+
+/// How `Value::Raw` and `Value::Str` are told apart, since both are JSON strings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StringConvention {
+    /// Both are written as is and a JSON string is read as a `Str`
+    #[default]
+    Str,
+    /// Both are written as is and a JSON string is read as a `Raw`
+    Raw,
+    /// A `Str` is written between single quotes like in an INI file and a `Raw` as is. A JSON string starting and ending with the same quote (`'` or `"`) is read as a `Str` without its quotes while any other one is read as a `Raw`
+    /// 
+    /// This is the only lossless convention, except for a `Raw` which itself starts and ends with a quote
+    Quoted,
+}
+
+/// Represents a failure while converting between a [`Tree`](../datas/tree/struct.Tree.html "datas::tree::Tree") and JSON
+/// 
+/// The path stored by most variants is a JSON pointer like `/section/key` to the member involved
+#[derive(Debug)]
+pub enum Error {
+    /// The text is not valid JSON
+    Syntax(serde_json::Error),
+    /// The root of the document is not an object
+    NotAnObject,
+    /// A section contains an object or an array while sections can't be nested
+    TooDeep(String),
+    /// An array or `null` has no INI equivalent
+    Unsupported(String, &'static str),
+    /// A member name is not a valid [`Identifier`](../datas/struct.Identifier.html "datas::Identifier")
+    InvalidName(String),
+    /// A key of the global section has the same name as a section so they can't be both members of the root object
+    NameClash(String),
+    /// A NaN or infinite `Float` can't be written in JSON
+    NonFiniteFloat(Identifier),
+}
+
+impl error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Syntax(err)             => write!(f, "{}", err),
+            Error::NotAnObject             => write!(f, "The root of the document must be an object"),
+            Error::TooDeep(path)           => write!(f, "Sections can't be nested at {}", path),
+            Error::Unsupported(path, kind) => write!(f, "A value of type {} has no INI equivalent at {}", kind, path),
+            Error::InvalidName(path)       => write!(f, "Invalid identifier at {}", path),
+            Error::NameClash(path)         => write!(f, "A key and a section have the same name at {}", path),
+            Error::NonFiniteFloat(ident)   => write!(f, "The value of {} is not a finite number", ident),
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::Syntax(err)
+    }
+}
+
+impl nested::Map for Map<String, serde_json::Value> {
+    type Item = serde_json::Value;
+    type Error = Error;
+    type Entries<'a> = map::Iter<'a>;
+
+    fn entries(&self) -> map::Iter<'_> {
+        self.iter()
+    }
+
+    fn contains_key(&self, key: &str) -> bool {
+        self.contains_key(key)
+    }
+
+    fn insert(&mut self, key: String, item: serde_json::Value) {
+        self.insert(key, item);
+    }
+
+    fn as_map(item: &serde_json::Value) -> Option<&Self> {
+        item.as_object()
+    }
+
+    fn into_item(self) -> serde_json::Value {
+        serde_json::Value::Object(self)
+    }
+
+    /// Returns the JSON pointer to the member `key` of the object at `parent`
+    fn path(parent: Option<&str>, key: &str) -> String {
+        format!("{}/{}", parent.unwrap_or(""), key.replace('~', "~0").replace('/', "~1"))
+    }
+
+    fn invalid_name(path: String) -> Error {
+        Error::InvalidName(path)
+    }
+
+    fn name_clash(path: String) -> Error {
+        Error::NameClash(path)
+    }
+
+    fn nested(path: String) -> Error {
+        Error::TooDeep(path)
+    }
+}
+
+
+/// Converts `tree` into a JSON object
+/// 
+/// # Return value
+/// `Ok(json)` in case of success
+/// 
+/// `Err(error)` if a key of the global section has the name of a section or if a `Float` is not finite
+pub fn to_value(tree: &Tree, convention: StringConvention) -> Result<serde_json::Value, Error> {
+    let root: Map<String, serde_json::Value> = nested::from_tree(tree, |ident, value| to_json(ident, value, convention))?;
+
+    Ok(serde_json::Value::Object(root))
+}
+
+/// Converts `tree` into an indented JSON text
+/// 
+/// # Return value
+/// The same as [`to_value`](fn.to_value.html "json::to_value")
+pub fn to_string(tree: &Tree, convention: StringConvention) -> Result<String, Error> {
+    Ok(serde_json::to_string_pretty(&to_value(tree, convention)?)?)
+}
+
+/// Converts the value of `ident` into JSON
+fn to_json(ident: &Identifier, value: &Value, convention: StringConvention) -> Result<serde_json::Value, Error> {
+    Ok(match value {
+        Value::Int(number)                                         => serde_json::Value::from(*number),
+        Value::Float(number)                                       => match Number::from_f64(*number) {
+            Some(number) => serde_json::Value::Number(number),
+            None         => return Err(Error::NonFiniteFloat(ident.clone())),
+        },
+        Value::Bool(value)                                         => serde_json::Value::Bool(*value),
+        Value::Str(text) if convention == StringConvention::Quoted => serde_json::Value::String(format!("'{}'", text)),
+        value                                                      => serde_json::Value::String(format!("{}", value)),
+    })
+}
+
+
+/// Converts a JSON object into a [`Tree`](../datas/tree/struct.Tree.html "datas::tree::Tree")
+/// 
+/// # Return value
+/// `Ok(tree)` in case of success
+/// 
+/// `Err(error)` in case of error with `error` as the first invalid member found (see [`Error`](enum.Error.html "json::Error"))
+pub fn from_value(json: &serde_json::Value, convention: StringConvention) -> Result<Tree, Error> {
+    let root = match json {
+        serde_json::Value::Object(root) => root,
+        _                               => return Err(Error::NotAnObject),
+    };
+
+    nested::to_tree(root, |path, member| from_json(path, member, convention))
+}
+
+/// Reads a JSON text into a [`Tree`](../datas/tree/struct.Tree.html "datas::tree::Tree")
+/// 
+/// # Return value
+/// The same as [`from_value`](fn.from_value.html "json::from_value") or `Err(Error::Syntax(error))` if `content` is not valid JSON
+pub fn from_str(content: &str, convention: StringConvention) -> Result<Tree, Error> {
+    let json: serde_json::Value = serde_json::from_str(content)?;
+    from_value(&json, convention)
+}
+
+/// Converts the scalar `json` found at `path` into a `Value`
+fn from_json(path: &str, json: &serde_json::Value, convention: StringConvention) -> Result<Value, Error> {
+    Ok(match json {
+        serde_json::Value::Null           => return Err(Error::Unsupported(String::from(path), "null")),
+        serde_json::Value::Array(_)       => return Err(Error::Unsupported(String::from(path), "array")),
+        serde_json::Value::Object(_)      => return Err(Error::TooDeep(String::from(path))),
+        serde_json::Value::Bool(value)    => Value::Bool(*value),
+        serde_json::Value::Number(number) => match number.as_i64() {
+            Some(number) => Value::Int(number),
+            None         => Value::Float(number.as_f64().expect("Any JSON number which is not an i64 is read as an f64")),
+        },
+        serde_json::Value::String(text)   => match convention {
+            StringConvention::Str    => Value::Str(text.clone()),
+            StringConvention::Raw    => Value::Raw(text.clone()),
+            StringConvention::Quoted => {
+                let quoted = text.len() >= 2 && (text.starts_with('\'') && text.ends_with('\'') || text.starts_with('"') && text.ends_with('"'));
+                if quoted {
+                    Value::Str(String::from(&text[1..text.len() - 1]))
+                } else {
+                    Value::Raw(text.clone())
+                }
+            },
+        },
+    })
+}
+
+
+#[cfg(test)]
+mod tests;
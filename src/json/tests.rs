@@ -0,0 +1,121 @@
+use crate::datas::{Identifier, Value};
+use crate::datas::tree::Tree;
+use crate::json::*;
+use crate::parse::Parser;
+use std::collections::HashMap;
+
+fn tree(content: &str) -> Tree {
+    let mut parser = Parser::new();
+    parser.parse_content(content).unwrap();
+
+    Tree::from(parser.data())
+}
+
+fn ident(section: Option<&str>, name: &str) -> Identifier {
+    Identifier::new(section.map(String::from), String::from(name))
+}
+
+#[test]
+fn json_conventions() {
+    let tree = tree("raw = text\nstr = 'text'\n");
+
+    let json = to_value(&tree, StringConvention::Str).unwrap();
+    assert_eq!(json.to_string(), r#"{"raw":"text","str":"text"}"#);
+    assert_eq!(from_value(&json, StringConvention::Str).unwrap().get_data()[&ident(None, "raw")], Value::Str(String::from("text")));
+    assert_eq!(from_value(&json, StringConvention::Raw).unwrap().get_data()[&ident(None, "str")], Value::Raw(String::from("text")));
+
+    let json = to_value(&tree, StringConvention::Quoted).unwrap();
+    assert_eq!(json.to_string(), r#"{"raw":"text","str":"'text'"}"#);
+    assert_eq!(from_value(&json, StringConvention::Quoted).unwrap().get_data(), tree.get_data());
+}
+
+#[test]
+fn json_quoted_strings() {
+    let tree = from_str(r#"{"a": "\"double\"", "b": "'", "c": "''", "d": "'mixed\""}"#, StringConvention::Quoted).unwrap();
+    let data = tree.get_data();
+
+    assert_eq!(data[&ident(None, "a")], Value::Str(String::from("double")));
+    assert_eq!(data[&ident(None, "b")], Value::Raw(String::from("'")));
+    assert_eq!(data[&ident(None, "c")], Value::Str(String::new()));
+    assert_eq!(data[&ident(None, "d")], Value::Raw(String::from("'mixed\"")));
+}
+
+#[test]
+fn json_typed_values() {
+    let tree = tree("[values]\nint = -3\nfloat = 1.5\nbool = off\nsize = 4KiB\n");
+
+    let json = to_value(&tree, StringConvention::Str).unwrap();
+    assert_eq!(json["values"]["int"], serde_json::json!(-3));
+    assert_eq!(json["values"]["float"], serde_json::json!(1.5));
+    assert_eq!(json["values"]["bool"], serde_json::json!(false));
+    assert_eq!(json["values"]["size"], serde_json::json!("4KiB"));
+
+    let back = from_value(&json, StringConvention::Raw).unwrap();
+    let data = back.get_data();
+    assert_eq!(data[&ident(Some("values"), "int")], Value::Int(-3));
+    assert_eq!(data[&ident(Some("values"), "float")], Value::Float(1.5));
+    assert_eq!(data[&ident(Some("values"), "bool")], Value::Bool(false));
+    assert_eq!(data[&ident(Some("values"), "size")], Value::Raw(String::from("4KiB")));
+}
+
+#[test]
+fn json_big_integers_are_floats() {
+    let tree = from_str(r#"{"big": 18446744073709551615}"#, StringConvention::Str).unwrap();
+
+    assert_eq!(tree.get_data()[&ident(None, "big")], Value::Float(18446744073709551615.0));
+}
+
+#[test]
+fn json_to_string_is_indented() {
+    let tree = tree("[a]\nb = 1\n");
+
+    assert_eq!(to_string(&tree, StringConvention::Str).unwrap(), "{\n  \"a\": {\n    \"b\": 1\n  }\n}");
+}
+
+#[test]
+fn json_export_errors() {
+    let mut data = HashMap::new();
+    data.insert(ident(None, "server"), Value::Int(1));
+    data.insert(ident(Some("server"), "port"), Value::Int(2));
+    match to_value(&Tree::from(data), StringConvention::Str) {
+        Err(Error::NameClash(path)) => assert_eq!(path, "/server"),
+        other                       => panic!("Expected a NameClash error, got {:?}", other),
+    }
+
+    let mut data = HashMap::new();
+    data.insert(ident(Some("maths"), "nan"), Value::Float(f64::NAN));
+    match to_value(&Tree::from(data), StringConvention::Str) {
+        Err(Error::NonFiniteFloat(ident)) => assert_eq!(ident.name(), "nan"),
+        other                             => panic!("Expected a NonFiniteFloat error, got {:?}", other),
+    }
+}
+
+#[test]
+fn json_import_errors() {
+    let expect = |content: &str, expected: &str| {
+        match from_str(content, StringConvention::Str) {
+            Err(err) => assert_eq!(format!("{}", err), expected, "for {}", content),
+            Ok(_)    => panic!("{} should be rejected", content),
+        }
+    };
+
+    expect("[1, 2]", "The root of the document must be an object");
+    expect(r#"{"section": {"nested": {"key": 1}}}"#, "Sections can't be nested at /section/nested");
+    expect(r#"{"section": {"list": [1]}}"#, "A value of type array has no INI equivalent at /section/list");
+    expect(r#"{"key": null}"#, "A value of type null has no INI equivalent at /key");
+    expect(r#"{"a/b~c": 1}"#, "Invalid identifier at /a~1b~0c");
+    expect(r#"{"section": {"1st": 1}}"#, "Invalid identifier at /section/1st");
+
+    match from_str("{", StringConvention::Str) {
+        Err(Error::Syntax(_)) => (),
+        other                 => panic!("Expected a Syntax error, got {:?}", other.map(|tree| tree.into_data())),
+    }
+}
+
+#[test]
+fn json_empty_section_is_dropped() {
+    let tree = from_str(r#"{"empty": {}, "key": "value"}"#, StringConvention::Str).unwrap();
+
+    assert_eq!(tree.sections().count(), 1);
+    assert_eq!(tree.get_data().len(), 1);
+}
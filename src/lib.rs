@@ -4,6 +4,22 @@ pub mod datas;
 pub mod dump;
 pub mod parse;
 pub mod errors;
+pub mod schema;
+pub mod properties;
+pub mod dotenv;
+
+#[cfg(feature = "serde")]
+pub mod de;
+#[cfg(feature = "serde")]
+pub use de::{from_str, from_reader, from_tree};
+#[cfg(feature = "serde")]
+pub mod ser;
+#[cfg(feature = "serde")]
+pub use ser::{to_string, to_writer};
+#[cfg(feature = "serde_json")]
+pub mod json;
+#[cfg(feature = "toml")]
+pub mod toml;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,156 @@
+//! Contains the definition of [`Dialect`](enum.Dialect.html "parse::Dialect")
+
+/// A set of rules followed by a [`Parser`](struct.Parser.html "parse::Parser"), since each implementation of the INI format has its own
+/// 
+/// # See
+/// [`Parser::with_dialect`](struct.Parser.html#method.with_dialect "parse::Parser::with_dialect") and [`Parser::set_dialect`](struct.Parser.html#method.set_dialect "parse::Parser::set_dialect") to choose a dialect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Dialect {
+    /// The rules of MinIniP described in the README, which is the default dialect
+    #[default]
+    Mininip,
+    /// The rules of the `configparser` module of Python with its default options
+    /// - `=` and `:` are both delimiters between a key and its value
+    /// - a line starting with `#` or `;` is a comment, but a comment can't follow a value on the same line
+    /// - the values are read verbatim, without any escape sequence nor type inference, so every value is a `Raw`
+    /// - the keys are converted to lowercase
+    /// - a line indented more than the key continues its value, and the empty lines between them are kept
+    /// 
+    /// The `[DEFAULT]` section and the `%(name)s` interpolations are resolved on lookup by [`ConfigParserView`](../datas/configparser/struct.ConfigParserView.html "datas::configparser::ConfigParserView")
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::{Dialect, Parser};
+    /// use mininip::datas::{Identifier, Value};
+    /// 
+    /// let mut parser = Parser::with_dialect(Dialect::ConfigParser);
+    /// parser.parse_content("[server]\n# A comment\nURL: http://localhost:80/ ; not a comment\nhosts =\n    a.org\n    b.org\n").unwrap();
+    /// 
+    /// let data = parser.data();
+    /// let url = Identifier::new(Some(String::from("server")), String::from("url"));
+    /// assert_eq!(data[&url], Value::Raw(String::from("http://localhost:80/ ; not a comment")));
+    /// let hosts = Identifier::new(Some(String::from("server")), String::from("hosts"));
+    /// assert_eq!(data[&hosts], Value::Raw(String::from("\na.org\nb.org")));
+    /// ```
+    ConfigParser,
+    /// The syntax of the configuration files of git, like `.gitconfig`
+    /// - a section may have a subsection like `[remote "origin"]`, whose name is case-sensitive and may contain any character
+    /// - a line starting with `#` or `;` is a comment, and a comment may follow a value outside of double quotes
+    /// - a key may be assigned several times and keeps all its values, and a key without `=` is `Value::Bool(true)`
+    /// - the unquoted values `true` and `false` are a `Value::Bool`
+    /// - the other values are `Raw`, with the whitespaces trimmed outside of double quotes, the escapes `\\`, `\"`, `\n`, `\t` and `\b` and a trailing `\` to continue on the next line. A carriage return between double quotes is kept
+    /// - the section names and the keys are converted to lowercase
+    /// 
+    /// The variables are returned by [`Parser::git_config`](struct.Parser.html#method.git_config "parse::Parser::git_config") and can be written back with [`GitDumper`](../dump/struct.GitDumper.html "dump::GitDumper")
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::{Dialect, Parser};
+    /// use mininip::datas::Value;
+    /// 
+    /// let mut parser = Parser::with_dialect(Dialect::GitConfig);
+    /// parser.parse_content("[Core]\n\tbare\n\teditor = \"vim -u NONE\" # quoted\n[branch \"Main\"]\n\tremote = origin\n").unwrap();
+    /// 
+    /// let config = parser.git_config();
+    /// assert_eq!(config.get("core", None, "bare"), Some(&Value::Bool(true)));
+    /// assert_eq!(config.get("core", None, "editor"), Some(&Value::Raw(String::from("vim -u NONE"))));
+    /// assert_eq!(config.get("branch", Some("Main"), "remote"), Some(&Value::Raw(String::from("origin"))));
+    /// assert_eq!(config.get("branch", Some("main"), "remote"), None);
+    /// ```
+    GitConfig,
+    /// The syntax of the unit files of systemd
+    /// - a line starting with `#` or `;` is a comment, but a comment can't follow a value on the same line
+    /// - the values are read verbatim, without any escape sequence nor type inference, so every value is a `Raw`
+    /// - a line ending with a backslash continues on the next line, the backslash being replaced by a space, and the comment lines between them are ignored
+    /// - a key may be assigned several times to build a list, and an empty assignment resets this list
+    /// 
+    /// Every assignment is returned in order by [`Parser::unit_file`](struct.Parser.html#method.unit_file "parse::Parser::unit_file") and can be written back with [`Dumper::with_dialect`](../dump/struct.Dumper.html#method.with_dialect "dump::Dumper::with_dialect"). [`Parser::data`](struct.Parser.html#method.data "parse::Parser::data") only keeps the last assignment of each key
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::{Dialect, Parser};
+    /// use mininip::datas::Value;
+    /// 
+    /// let mut parser = Parser::with_dialect(Dialect::Systemd);
+    /// parser.parse_content("[Service]\nExecStart=/bin/sh -c \"echo 1; \\\n# a comment\n  echo 2\"\nExecStartPre=/bin/true\nExecStartPre=/bin/false\n").unwrap();
+    /// 
+    /// let unit = parser.unit_file();
+    /// assert_eq!(unit.get("Service", "ExecStart"), Some(&Value::Raw(String::from("/bin/sh -c \"echo 1;    echo 2\""))));
+    /// assert_eq!(unit.get_list("Service", "ExecStartPre").len(), 2);
+    /// ```
+    Systemd,
+    /// The syntax of the Desktop Entry files of freedesktop.org, like `.desktop` files
+    /// - a line starting with `#` is a comment
+    /// - a group name may contain any printable ASCII character except `[` and `]`, like `[Desktop Entry]`
+    /// - a key may be localized like `Name[fr_FR]`, and the values may contain any UTF-8 character
+    /// - the escapes `\s`, `\n`, `\t`, `\r` and `\\` are unescaped on lookup, as well as `\;` in a list separated by `;`
+    /// 
+    /// The variables are returned by [`Parser::desktop_entry`](struct.Parser.html#method.desktop_entry "parse::Parser::desktop_entry"), which looks up a localized value with the fallback rules of the specification
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::{Dialect, Parser};
+    /// use mininip::datas::desktop::Locale;
+    /// 
+    /// let mut parser = Parser::with_dialect(Dialect::DesktopEntry);
+    /// parser.parse_content("[Desktop Entry]\nComment=A text editor\nComment[de_DE@euro]=Ein Texteditor für \\sSie\n").unwrap();
+    /// 
+    /// let entry = parser.desktop_entry();
+    /// let locale = Locale::parse("de_DE.ISO-8859-15@euro").unwrap();
+    /// assert_eq!(entry.get_localized("Desktop Entry", "Comment", &locale), Some(String::from("Ein Texteditor für  Sie")));
+    /// ```
+    DesktopEntry,
+    /// The syntax of `parse_ini_file` as documented in the PHP manual, with the sections processed and the default scanner mode `INI_SCANNER_NORMAL`. It has not been checked against PHP itself
+    /// - a line starting with `;` is a comment, and a comment may follow a value outside of quotes
+    /// - `key[] = value` appends a value to the array `key` and `key[name] = value` inserts one with the key `name`
+    /// - a value may be made of unquoted words, strings between double quotes, raw strings between single quotes and `${VAR}` variables, which are concatenated
+    /// - the unquoted words `true`, `on` and `yes` are `"1"` while `false`, `off`, `no`, `none` and `null` are `""`, ignoring the case, and an unquoted word naming a constant is replaced by its value
+    /// - between double quotes, only `\"`, `\\` and `\$` are escape sequences and a value may span several lines
+    /// - every value is a `Str` and may contain any UTF-8 character
+    /// 
+    /// The bitwise operators of PHP, like `E_ALL & ~E_NOTICE`, are not supported. The array is returned by [`Parser::php_array`](struct.Parser.html#method.php_array "parse::Parser::php_array"), and the constants and variables are set by [`Parser::set_php_constants`](struct.Parser.html#method.set_php_constants "parse::Parser::set_php_constants") and [`Parser::set_php_variables`](struct.Parser.html#method.set_php_variables "parse::Parser::set_php_variables")
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::{Dialect, Parser};
+    /// use mininip::datas::Value;
+    /// use mininip::datas::php::PhpValue;
+    /// 
+    /// let mut parser = Parser::with_dialect(Dialect::Php);
+    /// parser.parse_content("[third_section]\nphpversion[] = \"5.0\"\nphpversion[] = \"5.1\"\nurls[svn] = \"http://svn.php.net\"\ndebug = off ; comment\n").unwrap();
+    /// 
+    /// let array = parser.php_array();
+    /// let section = array.get("third_section").and_then(PhpValue::as_array).unwrap();
+    /// let versions = section.get("phpversion").and_then(PhpValue::as_array).unwrap();
+    /// assert_eq!(versions.get(1), Some(&PhpValue::Value(Value::Str(String::from("5.1")))));
+    /// let urls = section.get("urls").and_then(PhpValue::as_array).unwrap();
+    /// assert_eq!(urls.get("svn"), Some(&PhpValue::Value(Value::Str(String::from("http://svn.php.net")))));
+    /// assert_eq!(section.get("debug"), Some(&PhpValue::Value(Value::Str(String::new()))));
+    /// ```
+    Php,
+    /// The syntax of the profile files read by `GetPrivateProfileString` on Windows
+    /// - a line starting with `;` is a comment, and a `;` anywhere else is part of the line
+    /// - the whitespaces around the section names, the keys and the values are ignored, and any character is allowed in them
+    /// - the sections and the keys are looked up ignoring their case, and the first match wins when one is declared twice
+    /// - the double or single quotes surrounding a value are removed on lookup
+    /// - no line is an error, and a line without `=` is a key with an empty value
+    /// 
+    /// The lines are returned by [`Parser::profile`](struct.Parser.html#method.profile "parse::Parser::profile"). [`parse_profile_file`](fn.parse_profile_file.html "parse::parse_profile_file") also reads the files encoded in UTF-16 with a byte order mark and [`dump_profile_into_file`](../dump/fn.dump_profile_into_file.html "dump::dump_profile_into_file") writes them back in the same encoding
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::{Dialect, Parser};
+    /// 
+    /// let mut parser = Parser::with_dialect(Dialect::WindowsProfile);
+    /// parser.parse_content("[Boot Loader]\ntimeout = 30 ; seconds\n[boot loader]\ntimeout=5\n").unwrap();
+    /// 
+    /// let profile = parser.profile();
+    /// assert_eq!(profile.get(Some("BOOT LOADER"), "Timeout"), Some("30 ; seconds"));
+    /// assert_eq!(profile.section_names(), vec!["Boot Loader", "boot loader"]);
+    /// ```
+    WindowsProfile,
+}
+
+
+#[cfg(test)]
+mod tests;
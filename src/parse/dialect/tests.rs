@@ -0,0 +1,296 @@
+use crate::parse::{Dialect, Parser};
+use crate::datas::{Identifier, Value};
+
+fn ident(section: &str, key: &str) -> Identifier {
+    Identifier::new(Some(String::from(section)), String::from(key))
+}
+
+#[test]
+fn dialect_default() {
+    assert_eq!(Dialect::default(), Dialect::Mininip);
+}
+
+#[test]
+fn dialect_configparser_comments() {
+    let mut parser = Parser::with_dialect(Dialect::ConfigParser);
+    parser.parse_content("# hash\n; semicolon\n[section]\nkey = a # b ; c\n").unwrap();
+    let data = parser.data();
+
+    assert_eq!(data.len(), 1);
+    assert_eq!(data[&ident("section", "key")], Value::Raw(String::from("a # b ; c")));
+}
+
+#[test]
+fn dialect_configparser_no_inference() {
+    let mut parser = Parser::with_dialect(Dialect::ConfigParser);
+    parser.parse_content("[section]\nint = 42\nbool = yes\npath = C:\\Users\n").unwrap();
+    let data = parser.data();
+
+    assert_eq!(data[&ident("section", "int")], Value::Raw(String::from("42")));
+    assert_eq!(data[&ident("section", "bool")], Value::Raw(String::from("yes")));
+    assert_eq!(data[&ident("section", "path")], Value::Raw(String::from("C:\\Users")));
+}
+
+#[test]
+fn dialect_configparser_identifiers() {
+    use crate::datas::IdentifierPolicy;
+
+    let mut parser = Parser::with_dialect(Dialect::ConfigParser);
+    parser.parse_content("[1st section]\n_Private = a\n").unwrap();
+    let private = Identifier::with_policy(Some(String::from("1st section")), String::from("_private"), IdentifierPolicy::ConfigParser);
+    assert_eq!(parser.data()[&private], Value::Raw(String::from("a")));
+
+    let mut parser = Parser::with_dialect(Dialect::ConfigParser);
+    parser.set_dialect(Dialect::Mininip);
+    assert!(parser.parse_line("_private = a").is_err());
+}
+
+#[test]
+fn dialect_configparser_section_whitespaces() {
+    use crate::datas::IdentifierPolicy;
+
+    let mut parser = Parser::with_dialect(Dialect::ConfigParser);
+    parser.parse_content("[a ]
+key = 1
+[ ]
+key = 2
+").unwrap();
+    let ident = |section: &str| Identifier::with_policy(Some(String::from(section)), String::from("key"), IdentifierPolicy::ConfigParser);
+    let data = parser.data();
+
+    assert_eq!(data[&ident("a ")], Value::Raw(String::from("1")));
+    assert_eq!(data[&ident(" ")], Value::Raw(String::from("2")));
+}
+
+#[test]
+fn dialect_configparser_invalid_identifiers() {
+    use crate::datas::IdentifierPolicy;
+    use crate::errors::Error;
+
+    let mut parser = Parser::with_dialect(Dialect::ConfigParser);
+    parser.parse_line("[section]").unwrap();
+    match parser.parse_line("= value") {
+        Err(Error::InvalidIdentifier(_)) => (),
+        other                            => panic!("Expected an InvalidIdentifier error, got {:?}", other),
+    }
+
+    // The key is lowercased before being checked but the error still reports it as written
+    parser.set_identifier_policy(IdentifierPolicy::Ascii);
+    match parser.parse_line("1ST = value") {
+        Err(Error::InvalidIdentifier(_)) => (),
+        other                            => panic!("Expected an InvalidIdentifier error, got {:?}", other),
+    }
+}
+
+#[test]
+fn dialect_configparser_lowercase_keys() {
+    let mut parser = Parser::with_dialect(Dialect::ConfigParser);
+    parser.parse_content("[Section]\nKey: value\n").unwrap();
+    let data = parser.data();
+
+    assert_eq!(data[&ident("Section", "key")], Value::Raw(String::from("value")));
+}
+
+#[test]
+fn dialect_configparser_continuation() {
+    let mut parser = Parser::with_dialect(Dialect::ConfigParser);
+    parser.parse_content("[section]\nkey = first\n  second\n\n  third\n\nother = value\n").unwrap();
+    let data = parser.data();
+
+    assert_eq!(data[&ident("section", "key")], Value::Raw(String::from("first\nsecond\n\nthird")));
+    assert_eq!(data[&ident("section", "other")], Value::Raw(String::from("value")));
+}
+
+#[test]
+fn dialect_mininip_no_continuation() {
+    let mut parser = Parser::new();
+
+    assert!(parser.parse_content("[section]\nkey = first\n  second\n").is_err());
+}
+
+#[test]
+fn dialect_set_back() {
+    let mut parser = Parser::with_dialect(Dialect::ConfigParser);
+    parser.set_dialect(Dialect::Mininip);
+    parser.parse_content("[section]\nint = 42\n").unwrap();
+
+    assert_eq!(parser.data()[&ident("section", "int")], Value::Int(42));
+}
+
+fn git(content: &str) -> crate::datas::gitconfig::GitConfig {
+    let mut parser = Parser::with_dialect(Dialect::GitConfig);
+    parser.parse_content(content).unwrap();
+
+    parser.git_config()
+}
+
+fn raw(value: &str) -> Value {
+    Value::Raw(String::from(value))
+}
+
+#[test]
+fn dialect_gitconfig_sections() {
+    let config = git("[Core]\n\tbare = false\n[remote \"Or\\\"ig\\\\in\"]\n\turl = u\n[branch.Main]\n\tremote = origin\n[core] editor = vim\n");
+
+    assert_eq!(config.get("core", None, "bare"), Some(&Value::Bool(false)));
+    assert_eq!(config.get("core", None, "editor"), Some(&raw("vim")));
+    assert_eq!(config.get("remote", Some("Or\"ig\\in"), "url"), Some(&raw("u")));
+    assert_eq!(config.get("branch", Some("main"), "remote"), Some(&raw("origin")));
+    assert!(parser_data_is_empty("[core]\nbare\n"));
+}
+
+fn parser_data_is_empty(content: &str) -> bool {
+    let mut parser = Parser::with_dialect(Dialect::GitConfig);
+    parser.parse_content(content).unwrap();
+
+    parser.data().is_empty()
+}
+
+#[test]
+fn dialect_gitconfig_values() {
+    let config = git("[a]\n  Flag ; comment\n  spaces =   x  y\t ; comment\n  quoted = \"  # not ; a comment  \"\n  escapes = a\\tb\\\\c\\\"d\\ne\n  empty =\n  multi = first \\\n    second\n");
+
+    assert_eq!(config.get("a", None, "flag"), Some(&Value::Bool(true)));
+    assert_eq!(config.get("a", None, "spaces"), Some(&raw("x  y")));
+    assert_eq!(config.get("a", None, "quoted"), Some(&raw("  # not ; a comment  ")));
+    assert_eq!(config.get("a", None, "escapes"), Some(&raw("a\tb\\c\"d\ne")));
+    assert_eq!(config.get("a", None, "empty"), Some(&raw("")));
+    assert_eq!(config.get("a", None, "multi"), Some(&raw("first     second")));
+}
+
+#[test]
+fn dialect_gitconfig_multivalued() {
+    let config = git("[remote \"origin\"]\nfetch = a\nurl = u\nFETCH = b\n");
+
+    assert_eq!(config.get_all("remote", Some("origin"), "fetch"), vec![&raw("a"), &raw("b")]);
+    assert_eq!(config.entries().len(), 3);
+}
+
+#[test]
+fn dialect_gitconfig_errors() {
+    for content in &["key = before any section\n", "[core\n", "[]\n", "[with_underscore]\n", "[remote origin]\n",
+                     "[remote \"origin]\n", "[remote \"origin\" ]\n", "[core]\nsnake_case = 1\n", "[core]\n2fa = 1\n",
+                     "[core]\nkey value\n", "[core]\n.key = 1\n", "[.sub]\n", "[core]\nkey = \"unclosed\n", "[core]\nkey = bad\\escape\n"] {
+        let mut parser = Parser::with_dialect(Dialect::GitConfig);
+
+        assert!(parser.parse_content(content).is_err(), "{:?} should not be accepted", content);
+    }
+}
+
+#[test]
+fn dialect_gitconfig_other_dialect() {
+    let mut parser = Parser::new();
+    parser.parse_content("[core]\nbare = 1\n").unwrap();
+
+    assert!(parser.git_config().entries().is_empty());
+}
+
+#[test]
+fn dialect_systemd_verbatim() {
+    let mut parser = Parser::with_dialect(Dialect::Systemd);
+    parser.parse_content("# comment\n; comment\n[Unit]\nDescription = My service; really # yes\nAfter=network.target\n[Service]\nExecStart=/bin/echo \"a=b\" \\x41 42\n").unwrap();
+    let data = parser.data();
+
+    assert_eq!(data.len(), 3);
+    assert_eq!(data[&ident("Unit", "Description")], Value::Raw(String::from("My service; really # yes")));
+    assert_eq!(data[&ident("Service", "ExecStart")], Value::Raw(String::from("/bin/echo \"a=b\" \\x41 42")));
+}
+
+#[test]
+fn dialect_systemd_continuation() {
+    let mut parser = Parser::with_dialect(Dialect::Systemd);
+    parser.parse_content("[Service]\nExecStart=/bin/a \\\n# skipped\n  --flag \\\n\nUser=root\nPath=C:\\\\\nEnd=last \\").unwrap();
+    let data = parser.data();
+
+    assert_eq!(data[&ident("Service", "ExecStart")], Value::Raw(String::from("/bin/a    --flag")));
+    assert_eq!(data[&ident("Service", "User")], Value::Raw(String::from("root")));
+    assert_eq!(data[&ident("Service", "Path")], Value::Raw(String::from("C:\\\\")));
+    assert_eq!(data[&ident("Service", "End")], Value::Raw(String::from("last")));
+}
+
+#[test]
+fn dialect_systemd_unit_file() {
+    let mut parser = Parser::with_dialect(Dialect::Systemd);
+    parser.parse_content("[Service]\nExecStartPre=/bin/a\nExecStartPre=\nExecStartPre=/bin/b\nExecStartPre=/bin/c\n").unwrap();
+    let unit = parser.unit_file();
+
+    assert_eq!(unit.entries().len(), 4);
+    assert_eq!(unit.get_list("Service", "ExecStartPre"), vec![&Value::Raw(String::from("/bin/b")), &Value::Raw(String::from("/bin/c"))]);
+}
+
+#[test]
+fn dialect_systemd_other_dialect() {
+    let mut parser = Parser::new();
+    parser.parse_content("[Service]\nUser=root\n").unwrap();
+
+    assert!(parser.unit_file().entries().is_empty());
+}
+
+#[test]
+fn dialect_desktop_entry() {
+    let mut parser = Parser::with_dialect(Dialect::DesktopEntry);
+    parser.parse_content("# comment\n[Desktop Entry]\nType = Application\nName=Éditeur ; not a comment\nName[fr_FR]=Éditeur de texte\n[Desktop Action new-window]\nExec=app --new-window %U\n").unwrap();
+    let entry = parser.desktop_entry();
+
+    assert_eq!(entry.groups(), vec!["Desktop Entry", "Desktop Action new-window"]);
+    assert_eq!(entry.get("Desktop Entry", "Type"), Some(String::from("Application")));
+    assert_eq!(entry.get("Desktop Entry", "Name"), Some(String::from("Éditeur ; not a comment")));
+    assert_eq!(entry.get_raw("Desktop Entry", "Name", crate::datas::desktop::Locale::parse("fr_FR").as_ref()), Some("Éditeur de texte"));
+    assert_eq!(entry.get("Desktop Action new-window", "Exec"), Some(String::from("app --new-window %U")));
+}
+
+#[test]
+fn dialect_desktop_entry_errors() {
+    for content in &["Name=before any group\n", "[Desktop Entry\n", "[]\n", "[Entrée]\n", "[Desktop Entry]\nName\n",
+                     "[Desktop Entry]\nsnake_case=1\n", "[Desktop Entry]\n=1\n", "[Desktop Entry]\nName[fr=1\n",
+                     "[Desktop Entry]\nName[]=1\n", "[Desktop Entry]\nName[f r]=1\n", "[Desktop Entry]\nExec=a\\xb\n",
+                     "[Desktop Entry]\nExec=a\\\n"] {
+        let mut parser = Parser::with_dialect(Dialect::DesktopEntry);
+
+        assert!(parser.parse_content(content).is_err(), "{:?} should not be accepted", content);
+    }
+}
+
+#[test]
+fn dialect_php() {
+    use crate::datas::php::{PhpKey, PhpValue};
+
+    let mut parser = Parser::with_dialect(Dialect::Php);
+    parser.parse_content("debug = yes\n[paths]\ninclude[] = lib\ninclude[] = \"vendor ; libs\"\n").unwrap();
+    let array = parser.php_array();
+
+    assert_eq!(array.get("debug"), Some(&PhpValue::Value(Value::Str(String::from("1")))));
+    let include = array.get("paths").and_then(PhpValue::as_array)
+                       .and_then(|paths| paths.get("include"))
+                       .and_then(PhpValue::as_array)
+                       .unwrap();
+    assert_eq!(include.entries(), &[(PhpKey::Index(0), PhpValue::Value(Value::Str(String::from("lib")))),
+                                    (PhpKey::Index(1), PhpValue::Value(Value::Str(String::from("vendor ; libs"))))]);
+}
+
+#[test]
+fn dialect_php_leaves_data_empty() {
+    let mut parser = Parser::with_dialect(Dialect::Php);
+    parser.parse_content("a = 1\n").unwrap();
+
+    assert!(parser.data().is_empty());
+}
+
+#[test]
+fn dialect_windows_profile() {
+    let mut parser = Parser::with_dialect(Dialect::WindowsProfile);
+    parser.parse_content("[Drivers]\r\n wave = mmdrv.dll \r\n;timer=timer.drv\r\nWAVE=second\r\n").unwrap();
+    let profile = parser.profile();
+
+    assert_eq!(profile.get(Some("drivers"), "Wave"), Some("mmdrv.dll"));
+    assert_eq!(profile.get(Some("drivers"), "timer"), None);
+    assert_eq!(profile.generate(), "[Drivers]\r\nwave=mmdrv.dll\r\n;timer=timer.drv\r\nWAVE=second\r\n");
+}
+
+#[test]
+fn dialect_windows_profile_leaves_data_empty() {
+    let mut parser = Parser::with_dialect(Dialect::WindowsProfile);
+    parser.parse_content("a = 1\n").unwrap();
+
+    assert!(parser.data().is_empty());
+}
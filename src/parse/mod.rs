@@ -1,6 +1,8 @@
 //! Provides tools to parse an INI file
 
 use std::iter::Fuse;
+use std::time::Duration;
+use crate::datas::TextMode;
 use crate::errors::{Error, error_kinds::*};
 
 /// Reads a string formatted by [`dump_str`](../dump/fn.dump_str.html "dump::dump_str") and unescapes the escaped characters
@@ -11,7 +13,7 @@ use crate::errors::{Error, error_kinds::*};
 /// `Err(err)` In case of error with `err` as the error code
 /// 
 /// # Encoding issues
-/// Only allows ASCII because Unicode or other encodings musn't appear in an INI file (except in comments but this function is not intended to parse whole files)
+/// Only allows ASCII because Unicode or other encodings musn't appear in an INI file (except in comments but this function is not intended to parse whole files). See [`parse_str_with`](fn.parse_str_with.html "parse::parse_str_with") to allow them
 /// 
 /// # Examples
 /// ```
@@ -21,6 +23,20 @@ use crate::errors::{Error, error_kinds::*};
 /// assert_eq!(parse_str(r"abc\=123\; \x00263a").unwrap(), "abc=123; \u{263a}");
 /// ```
 pub fn parse_str(content: &str) -> Result<String, Error> {
+    parse_str_with(content, TextMode::Ascii)
+}
+
+/// Reads a string formatted by [`dump_str_with`](../dump/fn.dump_str_with.html "dump::dump_str_with") like [`parse_str`](fn.parse_str.html "parse::parse_str") does, but allows the non-ASCII characters not to be escaped if `mode` is `TextMode::Utf8`
+/// 
+/// # Examples
+/// ```
+/// use mininip::datas::TextMode;
+/// use mininip::parse::parse_str_with;
+/// 
+/// assert_eq!(parse_str_with("très \\x00263a", TextMode::Utf8).unwrap(), "très \u{263a}");
+/// assert!(parse_str_with("très", TextMode::Ascii).is_err());
+/// ```
+pub fn parse_str_with(content: &str, mode: TextMode) -> Result<String, Error> {
     // new will never be wider than content
     let mut new = String::with_capacity(content.len());
 
@@ -32,9 +48,9 @@ pub fn parse_str(content: &str) -> Result<String, Error> {
         let escape = match i {
             Token::Char(c) => {
                 let n = next;
-                next += 1;
+                next += c.len_utf8();
 
-                if FORBIDDEN.contains(&c) || !c.is_ascii() {
+                if FORBIDDEN.contains(&c) || !c.is_ascii() && mode == TextMode::Ascii {
                     let escape = crate::dump::dump_str(&format!("{}", c));
                     let err = Error::from(ExpectedEscape::new(String::from(content), n, escape));
                     return Err(err);
@@ -86,6 +102,152 @@ pub fn parse_str(content: &str) -> Result<String, Error> {
 }
 
 
+/// The units allowed in a duration associated with their value in nanoseconds
+pub(crate) static DURATION_UNITS: [(&str, u128); 9] = [
+    ("ns",  1),
+    ("us",  1_000),
+    ("\u{b5}s", 1_000),
+    ("ms",  1_000_000),
+    ("s",   1_000_000_000),
+    ("m",   60_000_000_000),
+    ("min", 60_000_000_000),
+    ("h",   3_600_000_000_000),
+    ("d",   86_400_000_000_000),
+];
+
+/// The units allowed in a size associated with their value in bytes. Both the SI (powers of 1000) and the IEC (powers of 1024) units are available
+pub(crate) static SIZE_UNITS: [(&str, u64); 14] = [
+    ("B",   1),
+    ("kB",  1_000),
+    ("KB",  1_000),
+    ("MB",  1_000_000),
+    ("GB",  1_000_000_000),
+    ("TB",  1_000_000_000_000),
+    ("PB",  1_000_000_000_000_000),
+    ("EB",  1_000_000_000_000_000_000),
+    ("KiB", 1 << 10),
+    ("MiB", 1 << 20),
+    ("GiB", 1 << 30),
+    ("TiB", 1 << 40),
+    ("PiB", 1 << 50),
+    ("EiB", 1 << 60),
+];
+
+/// Splits `content` into an unsigned integer and a unit, optionally separated by spaces, like `30s` or `64 KiB`
+/// 
+/// # Return value
+/// `Some((number, unit))` if `content` has this shape
+/// 
+/// `None` otherwise
+fn split_number_and_unit(content: &str) -> Option<(&str, &str)> {
+    let end = content.find(|c: char| !c.is_ascii_digit())?;
+    if end == 0 {
+        return None;
+    }
+
+    let unit = content[end..].trim_start();
+    if unit.is_empty() {
+        return None;
+    }
+
+    Some((&content[..end], unit))
+}
+
+/// Reads a duration written as an unsigned integer followed by a unit
+/// 
+/// The available units are `ns`, `us` (or `µs`), `ms`, `s`, `m` (or `min`), `h` and `d`
+/// 
+/// A number of seconds may be followed by a fraction of a second in a smaller unit, like `30s 500ms`. This is how [`dump_duration`](../dump/fn.dump_duration.html "dump::dump_duration") writes a duration which can't be written with a single unit
+/// 
+/// # Return value
+/// `Ok(Some(duration))` with `duration` as the result once parsed
+/// 
+/// `Ok(None)` if `content` is not written as a duration
+/// 
+/// `Err(err)` if `content` is a duration too big to be stored in a `Duration`
+/// 
+/// # Examples
+/// ```
+/// use mininip::parse::parse_duration;
+/// use std::time::Duration;
+/// 
+/// assert_eq!(parse_duration("250ms").unwrap(), Some(Duration::from_millis(250)));
+/// assert_eq!(parse_duration("5 m").unwrap(), Some(Duration::from_secs(300)));
+/// assert_eq!(parse_duration("5 meters").unwrap(), None);
+/// assert_eq!(parse_duration("18446744073709551615s 500ms").unwrap(), Some(Duration::new(u64::MAX, 500_000_000)));
+/// assert!(parse_duration("99999999999999999999d").is_err());
+/// ```
+pub fn parse_duration(content: &str) -> Result<Option<Duration>, Error> {
+    let (number, unit) = match split_number_and_unit(content) {
+        Some(val) => val,
+        None      => return Ok(None),
+    };
+
+    let overflow = || Error::from(Overflow::new(String::from(content), String::from(content), "Duration"));
+    if let Some(fraction) = unit.strip_prefix('s').filter(|fraction| fraction.starts_with(char::is_whitespace)) {
+        let secs = number.parse::<u64>().map_err(|_| overflow())?;
+
+        return match parse_duration(fraction.trim_start())? {
+            Some(fraction) if fraction.as_secs() == 0 => Ok(Some(Duration::new(secs, fraction.subsec_nanos()))),
+            _                                         => Ok(None),
+        };
+    }
+
+    let factor = match DURATION_UNITS.iter().find(|(name, _)| *name == unit) {
+        Some((_, factor)) => *factor,
+        None              => return Ok(None),
+    };
+    let number = number.parse::<u64>().map_err(|_| overflow())?;
+
+    // A `u64` multiplied by a factor lower than 2^64 always fits in a `u128`
+    let nanos = number as u128 * factor;
+    let secs = nanos / 1_000_000_000;
+    if secs > u64::MAX as u128 {
+        return Err(overflow());
+    }
+
+    Ok(Some(Duration::new(secs as u64, (nanos % 1_000_000_000) as u32)))
+}
+
+/// Reads a size in bytes written as an unsigned integer followed by a unit
+/// 
+/// The available units are `B`, the SI ones `kB` (or `KB`), `MB`, `GB`, `TB`, `PB` and `EB` and the IEC ones `KiB`, `MiB`, `GiB`, `TiB`, `PiB` and `EiB`
+/// 
+/// # Return value
+/// `Ok(Some(size))` with `size` as the number of bytes
+/// 
+/// `Ok(None)` if `content` is not written as a size
+/// 
+/// `Err(err)` if `content` is a size too big to be stored in a `u64`
+/// 
+/// # Examples
+/// ```
+/// use mininip::parse::parse_size;
+/// 
+/// assert_eq!(parse_size("64KiB").unwrap(), Some(65_536));
+/// assert_eq!(parse_size("2 GB").unwrap(), Some(2_000_000_000));
+/// assert_eq!(parse_size("2 GiBs").unwrap(), None);
+/// assert!(parse_size("16EiB").is_err());
+/// ```
+pub fn parse_size(content: &str) -> Result<Option<u64>, Error> {
+    let (number, unit) = match split_number_and_unit(content) {
+        Some(val) => val,
+        None      => return Ok(None),
+    };
+    let factor = match SIZE_UNITS.iter().find(|(name, _)| *name == unit) {
+        Some((_, factor)) => *factor,
+        None              => return Ok(None),
+    };
+
+    let overflow = || Error::from(Overflow::new(String::from(content), String::from(content), "u64"));
+    number.parse::<u64>()
+          .ok()
+          .and_then(|number| number.checked_mul(factor))
+          .map(Some)
+          .ok_or_else(overflow)
+}
+
+
 /// A token which is either a single character or an escape sequence starting with `\`
 #[derive(PartialEq, Debug)]
 enum Token {
@@ -187,6 +349,8 @@ pub fn find_unescaped(string: &str, pattern: char) -> Option<usize> {
 
 mod parser;
 pub use parser::*;
+mod dialect;
+pub use dialect::Dialect;
 
 
 #[cfg(test)]
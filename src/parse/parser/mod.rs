@@ -1,8 +1,16 @@
 //! Contains the definition of [`Parser`](struct.Parser.html "parse::Parser")
 
 use std::collections::HashMap;
-use crate::datas::{Identifier, Value};
-use crate::errors::{Error, error_kinds::*, ParseFileError};
+use crate::datas::{BoolVocabulary, Identifier, IdentifierPolicy, Lexeme, LineEnding, TextMode, Value, ValueType};
+use crate::datas::desktop::{self, DesktopEntry, DesktopKey, Locale};
+use crate::datas::gitconfig::{GitConfig, GitIdentifier};
+use crate::datas::php::{PhpArray, PhpKey, PhpValue};
+use crate::datas::encoding::{self, Encoding};
+use crate::datas::profile::Profile;
+use crate::datas::systemd::UnitFile;
+use crate::dump;
+use super::Dialect;
+use crate::errors::{EncodingError, Error, error_kinds::*, ParseFileError};
 use std::path::Path;
 use std::fs::File;
 use std::io::Read;
@@ -44,6 +52,69 @@ use std::io::Read;
 pub struct Parser {
     variables: HashMap<Identifier, Value>,
     cur_section: Option<String>,
+    bool_vocabulary: BoolVocabulary,
+    /// The type forced for some variables instead of guessing it
+    type_hints: HashMap<Identifier, ValueType>,
+    /// Whether the type of the variables without hint is guessed or is always `Raw` or `Str`
+    infer_types: bool,
+    /// The number of lines parsed so far
+    line_number: usize,
+    /// The line number where each variable was assigned for the last time
+    locations: HashMap<Identifier, usize>,
+    /// The line ending of the first line parsed which had one
+    line_ending: Option<LineEnding>,
+    /// Whether `:` is accepted as a delimiter between a key and its value, like `=`
+    colon_delimiter: bool,
+    /// Whether a line starting with `#` is a comment, like a line starting with `;`
+    hash_comments: bool,
+    /// Whether a comment may follow a value on the same line
+    inline_comments: bool,
+    /// Whether the values are unescaped or read verbatim
+    escapes: bool,
+    /// Whether the keys are converted to lowercase
+    lowercase_keys: bool,
+    /// The rule followed by the keys and the section names
+    identifier_policy: IdentifierPolicy,
+    /// Whether the non-ASCII characters of the values must be escaped
+    text_mode: TextMode,
+    /// Whether an indented line continues the value of the previous key
+    continuation_lines: bool,
+    /// The last value assigned, which may be continued by the next lines if `continuation_lines` is `true`
+    continued: Option<Continued>,
+    /// Whether a line ending with a backslash continues on the next line
+    backslash_continuation: bool,
+    /// The text of the lines ending with a backslash, with this backslash replaced by a space
+    pending_line: Option<String>,
+    /// Whether the source text of each value is kept
+    keep_lexemes: bool,
+    /// The source text of each variable if `keep_lexemes` is `true`
+    lexemes: HashMap<Identifier, Lexeme>,
+    /// The variables read with the git-config syntax, which is `Some` only in this dialect
+    git_config: Option<GitConfig>,
+    /// The subsection of `cur_section` with the git-config syntax
+    cur_subsection: Option<String>,
+    /// The last value read with the git-config syntax if it continues on the next line
+    git_continued: Option<(GitIdentifier, GitValue)>,
+    /// Whether the line being parsed with the git-config syntax ends with a lone carriage return, which is part of the value if it is between double quotes
+    git_cr_ending: bool,
+    /// Every assignment in the order they were declared, which is `Some` only with the systemd dialect
+    unit_file: Option<UnitFile>,
+    /// The variables read with the Desktop Entry syntax, which is `Some` only in this dialect
+    desktop_entry: Option<DesktopEntry>,
+    /// The array read with the PHP syntax, which is `Some` only in this dialect
+    php_array: Option<PhpArray>,
+    /// The values of the constants expanded with the PHP syntax
+    php_constants: HashMap<String, String>,
+    /// The values of the `${VAR}` variables with the PHP syntax, which are looked up in the environment otherwise
+    php_variables: HashMap<String, String>,
+    /// The lines read with the syntax of the profile files of Windows, which is `Some` only in this dialect
+    profile: Option<Profile>,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Parser::new()
+    }
 }
 
 impl Parser {
@@ -52,24 +123,434 @@ impl Parser {
         Parser {
             variables: HashMap::new(),
             cur_section: None,
+            bool_vocabulary: BoolVocabulary::default(),
+            type_hints: HashMap::new(),
+            infer_types: true,
+            line_number: 0,
+            locations: HashMap::new(),
+            line_ending: None,
+            colon_delimiter: false,
+            hash_comments: false,
+            inline_comments: true,
+            escapes: true,
+            lowercase_keys: false,
+            identifier_policy: IdentifierPolicy::default(),
+            text_mode: TextMode::default(),
+            continuation_lines: false,
+            continued: None,
+            backslash_continuation: false,
+            pending_line: None,
+            keep_lexemes: false,
+            lexemes: HashMap::new(),
+            git_config: None,
+            cur_subsection: None,
+            git_continued: None,
+            git_cr_ending: false,
+            unit_file: None,
+            desktop_entry: None,
+            php_array: None,
+            php_constants: HashMap::new(),
+            php_variables: HashMap::new(),
+            profile: None,
         }
     }
 
+    /// Creates a new `Parser` following `dialect`
+    pub fn with_dialect(dialect: Dialect) -> Parser {
+        let mut parser = Parser::new();
+        parser.set_dialect(dialect);
+
+        parser
+    }
+
+    /// Follows `dialect` in the next lines parsed. It overrides the options set by [`set_colon_delimiter`](struct.Parser.html#method.set_colon_delimiter "parse::Parser::set_colon_delimiter") and [`set_type_inference`](struct.Parser.html#method.set_type_inference "parse::Parser::set_type_inference"), and the one set by [`set_identifier_policy`](struct.Parser.html#method.set_identifier_policy "parse::Parser::set_identifier_policy") when switching from or to [`Dialect::ConfigParser`](enum.Dialect.html "parse::Dialect"), which follows [`IdentifierPolicy::ConfigParser`](../datas/enum.IdentifierPolicy.html "datas::IdentifierPolicy")
+    /// 
+    /// # See
+    /// [`Dialect`](enum.Dialect.html "parse::Dialect") for the rules of each dialect
+    pub fn set_dialect(&mut self, dialect: Dialect) {
+        let configparser = dialect == Dialect::ConfigParser;
+        let systemd = dialect == Dialect::Systemd;
+        let verbatim = configparser || systemd;
+
+        self.colon_delimiter = configparser;
+        self.hash_comments = verbatim;
+        self.inline_comments = !verbatim;
+        self.escapes = !verbatim;
+        self.infer_types = !verbatim;
+        self.lowercase_keys = configparser;
+        self.continuation_lines = configparser;
+        self.continued = None;
+        self.backslash_continuation = systemd;
+        self.pending_line = None;
+        self.identifier_policy = match self.identifier_policy {
+            _ if configparser              => IdentifierPolicy::ConfigParser,
+            IdentifierPolicy::ConfigParser => IdentifierPolicy::default(),
+            policy                         => policy,
+        };
+
+        self.unit_file = match dialect {
+            Dialect::Systemd => Some(self.unit_file.take().unwrap_or_default()),
+            _                => None,
+        };
+        self.desktop_entry = match dialect {
+            Dialect::DesktopEntry => Some(self.desktop_entry.take().unwrap_or_default()),
+            _                     => None,
+        };
+        self.php_array = match dialect {
+            Dialect::Php => Some(self.php_array.take().unwrap_or_default()),
+            _            => None,
+        };
+        self.profile = match dialect {
+            Dialect::WindowsProfile => Some(self.profile.take().unwrap_or_default()),
+            _                       => None,
+        };
+
+        self.git_config = match dialect {
+            Dialect::GitConfig => Some(self.git_config.take().unwrap_or_default()),
+            _                  => None,
+        };
+        self.git_continued = None;
+    }
+
+    /// Returns the number of lines parsed so far, which is also the number of the last line parsed (starting from 1)
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    /// Returns the number of the line (starting from 1) where each variable was assigned for the last time
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::Parser;
+    /// use mininip::datas::Identifier;
+    /// 
+    /// let mut parser = Parser::new();
+    /// parser.parse_line("; A comment").unwrap();
+    /// parser.parse_line("answer = 42").unwrap();
+    /// 
+    /// let answer = Identifier::new(None, String::from("answer"));
+    /// assert_eq!(parser.locations()[&answer], 2);
+    /// ```
+    pub fn locations(&self) -> &HashMap<Identifier, usize> {
+        &self.locations
+    }
+
+    /// Returns the line ending of the first line parsed which had one, so that the file can be written back with the same line endings
+    /// 
+    /// # Return value
+    /// `Some(ending)` with `ending` as the line ending found
+    /// 
+    /// `None` if no line parsed so far ended with a line ending
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::Parser;
+    /// use mininip::dump::Dumper;
+    /// use mininip::datas::LineEnding;
+    /// 
+    /// let mut parser = Parser::new();
+    /// parser.parse_content("a = 1\r\n[section]\r\nb = 2\r\n").unwrap();
+    /// assert_eq!(parser.line_ending(), Some(LineEnding::CrLf));
+    /// 
+    /// let mut dumper = Dumper::new();
+    /// dumper.set_line_ending(parser.line_ending().unwrap_or_default());
+    /// for (ident, value) in parser.data() {
+    ///     dumper.dump(ident, value);
+    /// }
+    /// assert_eq!(dumper.generate(), "a=1\r\n\r\n[section]\r\nb=2\r\n");
+    /// ```
+    pub fn line_ending(&self) -> Option<LineEnding> {
+        self.line_ending
+    }
+
+    /// Returns the source text of each variable parsed while [`set_keep_lexemes`](struct.Parser.html#method.set_keep_lexemes "parse::Parser::set_keep_lexemes") was enabled
+    pub fn lexemes(&self) -> &HashMap<Identifier, Lexeme> {
+        &self.lexemes
+    }
+
+    /// Enables or disables keeping the source text of the values in the next lines parsed. It is disabled by default
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::Parser;
+    /// use mininip::dump::Dumper;
+    /// use mininip::datas::{Identifier, Value};
+    /// 
+    /// let mut parser = Parser::new();
+    /// parser.set_keep_lexemes(true);
+    /// parser.parse_content("mask = 1e3\nenabled = yes ; comment\n").unwrap();
+    /// 
+    /// let lexemes = parser.lexemes().clone();
+    /// let mut data = parser.data();
+    /// data.insert(Identifier::new(None, String::from("mask")), Value::Int(5));
+    /// 
+    /// let mut dumper = Dumper::new();
+    /// dumper.set_lexemes(lexemes);
+    /// for (ident, value) in data {
+    ///     dumper.dump(ident, value);
+    /// }
+    /// 
+    /// assert_eq!(dumper.generate(), "enabled=yes\nmask=5\n");
+    /// ```
+    pub fn set_keep_lexemes(&mut self, enabled: bool) {
+        self.keep_lexemes = enabled;
+    }
+
+    /// Changes the rule followed by the keys and the section names in the next lines parsed. It is [`IdentifierPolicy::Ascii`](../datas/enum.IdentifierPolicy.html "datas::IdentifierPolicy") by default
+    /// 
+    /// The identifiers read are created with [`Identifier::with_policy`](../datas/struct.Identifier.html#method.with_policy "datas::Identifier::with_policy"), so they must be looked up with identifiers created in the same way
+    /// 
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "unicode")] {
+    /// use mininip::parse::Parser;
+    /// use mininip::datas::{Identifier, IdentifierPolicy, Value};
+    /// 
+    /// let mut parser = Parser::new();
+    /// parser.set_identifier_policy(IdentifierPolicy::UnicodeXid);
+    /// parser.parse_line("[名前]").unwrap();
+    /// parser.parse_line("gr\u{f6}\u{df}e = 42").unwrap();
+    /// 
+    /// let size = Identifier::with_policy(Some(String::from("名前")), String::from("gro\u{308}\u{df}e"), IdentifierPolicy::UnicodeXid);
+    /// assert_eq!(parser.data()[&size], Value::Int(42));
+    /// # }
+    /// ```
+    pub fn set_identifier_policy(&mut self, policy: IdentifierPolicy) {
+        self.identifier_policy = policy;
+    }
+
+    /// Changes whether the non-ASCII characters of the values must be escaped in the next lines parsed. It is [`TextMode::Ascii`](../datas/enum.TextMode.html "datas::TextMode") by default, where such a character is an error
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::Parser;
+    /// use mininip::datas::{Identifier, TextMode, Value};
+    /// 
+    /// let mut parser = Parser::new();
+    /// assert!(parser.parse_line("greeting = 'café'").is_err());
+    /// 
+    /// parser.set_text_mode(TextMode::Utf8);
+    /// parser.parse_line("greeting = 'café \\x00263a'").unwrap();
+    /// 
+    /// let greeting = Identifier::new(None, String::from("greeting"));
+    /// assert_eq!(parser.data()[&greeting], Value::Str(String::from("café \u{263a}")));
+    /// ```
+    pub fn set_text_mode(&mut self, mode: TextMode) {
+        self.text_mode = mode;
+    }
+
+    /// Changes the words recognized as booleans in the next lines parsed
+    /// 
+    /// # Examples
+    /// ```
+    /// use mininip::parse::Parser;
+    /// use mininip::datas::{BoolVocabulary, Identifier, Value};
+    /// 
+    /// let mut parser = Parser::new();
+    /// let mut vocabulary = BoolVocabulary::default();
+    /// vocabulary.add_true(String::from("1"));
+    /// vocabulary.add_false(String::from("0"));
+    /// parser.set_bool_vocabulary(vocabulary);
+    /// 
+    /// parser.parse_line("verbose = 1").unwrap();
+    /// parser.parse_line("debug = FALSE").unwrap();
+    /// 
+    /// let data = parser.data();
+    /// assert_eq!(data[&Identifier::new(None, String::from("verbose"))], Value::Bool(true));
+    /// assert_eq!(data[&Identifier::new(None, String::from("debug"))], Value::Bool(false));
+    /// ```
+    pub fn set_bool_vocabulary(&mut self, vocabulary: BoolVocabulary) {
+        self.bool_vocabulary = vocabulary;
+    }
+
+    /// Forces the type of some variables in the next lines parsed instead of guessing it from their spelling
+    /// 
+    /// A value which can't be read as the type forced is an [`Error::WrongType`](../errors/enum.Error.html "errors::Error")
+    /// 
+    /// # See
+    /// [`Value::parse_as`](../datas/enum.Value.html#method.parse_as "datas::Value::parse_as") for the way each type is read
+    /// 
+    /// [`Schema::type_hints`](../schema/struct.Schema.html#method.type_hints "schema::Schema::type_hints") to force the types declared in a schema
+    /// 
+    /// # Examples
+    /// ```
+    /// use mininip::parse::Parser;
+    /// use mininip::datas::{Identifier, Value, ValueType};
+    /// use std::collections::HashMap;
+    /// 
+    /// let version = Identifier::new(None, String::from("version"));
+    /// let zip = Identifier::new(None, String::from("zip"));
+    /// 
+    /// let mut hints = HashMap::new();
+    /// hints.insert(version.clone(), ValueType::Str);
+    /// hints.insert(zip.clone(), ValueType::Raw);
+    /// 
+    /// let mut parser = Parser::new();
+    /// parser.set_type_hints(hints);
+    /// parser.parse_content("version = 1.10\nzip = 01234\n").unwrap();
+    /// 
+    /// let data = parser.data();
+    /// assert_eq!(data[&version], Value::Str(String::from("1.10")));
+    /// assert_eq!(data[&zip], Value::Raw(String::from("01234")));
+    /// ```
+    pub fn set_type_hints(&mut self, hints: HashMap<Identifier, ValueType>) {
+        self.type_hints = hints;
+    }
+
+    /// Enables or disables the type inference in the next lines parsed. When it is disabled, every variable without type hint is a `Str` if it is quoted or a `Raw` otherwise
+    /// 
+    /// The type inference is enabled by default
+    /// 
+    /// # Examples
+    /// ```
+    /// use mininip::parse::Parser;
+    /// use mininip::datas::{Identifier, Value};
+    /// 
+    /// let mut parser = Parser::new();
+    /// parser.set_type_inference(false);
+    /// parser.parse_line("answer = 42").unwrap();
+    /// 
+    /// let data = parser.data();
+    /// assert_eq!(data[&Identifier::new(None, String::from("answer"))], Value::Raw(String::from("42")));
+    /// ```
+    pub fn set_type_inference(&mut self, enabled: bool) {
+        self.infer_types = enabled;
+    }
+
+    /// Accepts or not `:` as an alternative to `=` between a key and its value in the next lines parsed, like in the configparser dialect. It is not accepted by default
+    /// 
+    /// The first unescaped delimiter splits the line so a key can't contain a `:` when it is accepted, but a section name still can
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::Parser;
+    /// use mininip::datas::{Identifier, Value};
+    /// 
+    /// let mut parser = Parser::new();
+    /// parser.set_colon_delimiter(true);
+    /// parser.parse_content("[a:b]\nkey: value\nequation: x \\= 1\n").unwrap();
+    /// 
+    /// let data = parser.data();
+    /// let key = Identifier::new(Some(String::from("a:b")), String::from("key"));
+    /// assert_eq!(data[&key], Value::Raw(String::from("value")));
+    /// let equation = Identifier::new(Some(String::from("a:b")), String::from("equation"));
+    /// assert_eq!(data[&equation], Value::Raw(String::from("x = 1")));
+    /// ```
+    pub fn set_colon_delimiter(&mut self, enabled: bool) {
+        self.colon_delimiter = enabled;
+    }
+
     /// Consumes the parser and returns its data which is an `HashMap<Identifier, Value>` linking an identifier to its value
+    /// 
+    /// # Notes
+    /// The variables read with [`Dialect::GitConfig`](enum.Dialect.html "parse::Dialect") are not in this `HashMap` since their identifiers may have a subsection. Use [`git_config`](struct.Parser.html#method.git_config "parse::Parser::git_config") instead
+    /// 
+    /// The variables read with [`Dialect::Php`](enum.Dialect.html "parse::Dialect") are not in this `HashMap` either since they may be arrays. Use [`php_array`](struct.Parser.html#method.php_array "parse::Parser::php_array") instead
+    /// 
+    /// The same goes for the keys read with [`Dialect::WindowsProfile`](enum.Dialect.html "parse::Dialect"), which may contain any character. Use [`profile`](struct.Parser.html#method.profile "parse::Parser::profile") instead
     pub fn data(self) -> HashMap<Identifier, Value> {
         self.variables
     }
 
+    /// Consumes the parser and returns every assignment read with [`Dialect::Systemd`](enum.Dialect.html "parse::Dialect") in the order they were declared, which is empty with any other dialect
+    /// 
+    /// # Notes
+    /// [`data`](struct.Parser.html#method.data "parse::Parser::data") only keeps the last assignment of each key
+    pub fn unit_file(self) -> UnitFile {
+        self.unit_file.unwrap_or_default()
+    }
+
+    /// Consumes the parser and returns the variables read with [`Dialect::DesktopEntry`](enum.Dialect.html "parse::Dialect"), which is empty with any other dialect
+    pub fn desktop_entry(self) -> DesktopEntry {
+        self.desktop_entry.unwrap_or_default()
+    }
+
+    /// Consumes the parser and returns the variables read with [`Dialect::GitConfig`](enum.Dialect.html "parse::Dialect"), which is empty with any other dialect
+    pub fn git_config(self) -> GitConfig {
+        self.git_config.unwrap_or_default()
+    }
+
+    /// Consumes the parser and returns the array read with [`Dialect::Php`](enum.Dialect.html "parse::Dialect"), which is empty with any other dialect
+    /// 
+    /// This array has the shape of the one documented for `parse_ini_file($path, true)` in PHP: the variables declared before any section, then each section as an array
+    pub fn php_array(self) -> PhpArray {
+        self.php_array.unwrap_or_default()
+    }
+
+    /// Consumes the parser and returns the lines read with [`Dialect::WindowsProfile`](enum.Dialect.html "parse::Dialect"), which is empty with any other dialect
+    pub fn profile(self) -> Profile {
+        let mut profile = self.profile.unwrap_or_default();
+        if let Some(ending) = self.line_ending {
+            profile.set_line_ending(ending);
+        }
+
+        profile
+    }
+
+    /// Changes the constants expanded in the values read with [`Dialect::Php`](enum.Dialect.html "parse::Dialect"), like the ones declared by `define` in PHP
+    /// 
+    /// An unquoted word which is not a constant is kept as written
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::{Dialect, Parser};
+    /// use mininip::datas::Value;
+    /// use mininip::datas::php::PhpValue;
+    /// use std::collections::HashMap;
+    /// 
+    /// let mut constants = HashMap::new();
+    /// constants.insert(String::from("BIRD"), String::from("Dodo bird"));
+    /// 
+    /// let mut parser = Parser::with_dialect(Dialect::Php);
+    /// parser.set_php_constants(constants);
+    /// parser.parse_content("animal = BIRD\nplant = TREE\n").unwrap();
+    /// 
+    /// let array = parser.php_array();
+    /// assert_eq!(array.get("animal"), Some(&PhpValue::Value(Value::Str(String::from("Dodo bird")))));
+    /// assert_eq!(array.get("plant"), Some(&PhpValue::Value(Value::Str(String::from("TREE")))));
+    /// ```
+    pub fn set_php_constants(&mut self, constants: HashMap<String, String>) {
+        self.php_constants = constants;
+    }
+
+    /// Changes the values of the `${VAR}` variables read with [`Dialect::Php`](enum.Dialect.html "parse::Dialect"), like the settings of `php.ini` in PHP
+    /// 
+    /// A variable which is not in `variables` is looked up in the environment, and is empty if it is not there either
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::{Dialect, Parser};
+    /// use mininip::datas::Value;
+    /// use mininip::datas::php::PhpValue;
+    /// use std::collections::HashMap;
+    /// 
+    /// let mut variables = HashMap::new();
+    /// variables.insert(String::from("PREFIX"), String::from("/usr"));
+    /// 
+    /// let mut parser = Parser::with_dialect(Dialect::Php);
+    /// parser.set_php_variables(variables);
+    /// parser.parse_content("bin = ${PREFIX}/bin\n").unwrap();
+    /// 
+    /// let array = parser.php_array();
+    /// assert_eq!(array.get("bin"), Some(&PhpValue::Value(Value::Str(String::from("/usr/bin")))));
+    /// ```
+    pub fn set_php_variables(&mut self, variables: HashMap<String, String>) {
+        self.php_variables = variables;
+    }
+
     /// Parses a line
     /// 
     /// # Parameters
-    /// `line` the line to parse
+    /// `line` the line to parse, which may end with its line ending (`\n`, `\r\n` or `\r`)
     /// 
     /// # Return value
     /// `Ok(())` in case of success
     /// 
     /// `Err(error)` in case of error with `error` as the error code (see [`Error`](../errors/enum.Error.html "errors::Error"))
     /// 
+    /// # Notes
+    /// With [`Dialect::Systemd`](enum.Dialect.html "parse::Dialect"), a line ending with a backslash is parsed with the next one, so it is not parsed at all if it is the last line. The same goes for a line ending inside quotes with [`Dialect::Php`](enum.Dialect.html "parse::Dialect"). [`parse_content`](struct.Parser.html#method.parse_content "parse::Parser::parse_content") does not have this issue
+    /// 
     /// # Examples
     /// ```rust
     /// use mininip::parse::Parser;
@@ -90,13 +571,149 @@ impl Parser {
     /// }
     /// ```
     pub fn parse_line(&mut self, line: &str) -> Result<(), Error> {
+        self.line_number += 1;
+
+        // The line ending is remembered to write the file back in the same way
+        let (line, ending) = match LineEnding::find(line) {
+            Some((index, ending)) if index + ending.as_str().len() == line.len() => {
+                self.line_ending.get_or_insert(ending);
+                (&line[..index], Some(ending))
+            },
+            _                                                                    => (line, None),
+        };
+
+        if self.git_config.is_some() {
+            self.git_cr_ending = ending == Some(LineEnding::Cr);
+            return self.parse_git_line(line);
+        } else if self.desktop_entry.is_some() {
+            return self.parse_desktop_line(line);
+        } else if self.php_array.is_some() {
+            return self.parse_php_line(line);
+        } else if self.profile.is_some() {
+            self.parse_profile_line(line);
+            return Ok(());
+        } else if !self.backslash_continuation {
+            return self.parse_joined_line(line);
+        }
+
+        // A comment line is ignored even in the middle of a line continued
+        let effective_line = line.trim_start();
+        if effective_line.starts_with(';') || effective_line.starts_with('#') && self.hash_comments {
+            return Ok(());
+        }
+
+        let mut joined = self.pending_line.take().unwrap_or_default();
+        joined.push_str(line);
+
+        let backslashes = joined.len() - joined.trim_end_matches('\\').len();
+        if backslashes % 2 == 1 {
+            joined.pop();
+            joined.push(' ');
+            self.pending_line = Some(joined);
+            return Ok(());
+        }
+
+        self.parse_joined_line(&joined)
+    }
+
+    /// Parses a line, which may be made of several lines ending with a backslash
+    fn parse_joined_line(&mut self, line: &str) -> Result<(), Error> {
         let effective_line = line.trim_start();
 
         match effective_line.chars().next() {
-            None | Some(';')    => Ok(()),
-            Some(c) if c == '[' => self.parse_section(line),
-            Some(_)             => self.parse_assignment(line),
+            None                                   => {
+                if let Some(continued) = &mut self.continued {
+                    continued.empty_lines += 1;
+                }
+                Ok(())
+            },
+            Some(';')                              => Ok(()),
+            Some('#') if self.hash_comments        => Ok(()),
+            Some(_) if self.is_continuation(line)  => self.continue_value(effective_line),
+            Some('[')                              => {
+                self.continued = None;
+                self.parse_section(line)
+            },
+            Some(_)                                => self.parse_assignment(line),
+        }
+    }
+
+    /// Parses every line of `content`, which may be a whole INI file
+    /// 
+    /// The lines may end with `\n`, `\r\n` or `\r`. The first line ending found is remembered (see [`line_ending`](#method.line_ending "parse::Parser::line_ending"))
+    /// 
+    /// # Return value
+    /// `Ok(())` in case of success
+    /// 
+    /// `Err(error)` in case of error with `error` as the error code of the first invalid line. The lines before it are parsed anyway
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::Parser;
+    /// use mininip::datas::{Identifier, Value};
+    /// 
+    /// let mut parser = Parser::new();
+    /// parser.parse_content("a = 1\n[section]\nb = 2\n").unwrap();
+    /// 
+    /// let data = parser.data();
+    /// assert_eq!(data[&Identifier::new(Some(String::from("section")), String::from("b"))], Value::Int(2));
+    /// ```
+    pub fn parse_content(&mut self, content: &str) -> Result<(), Error> {
+        let mut rest = content;
+        while !rest.is_empty() {
+            let end = match LineEnding::find(rest) {
+                Some((index, ending)) => index + ending.as_str().len(),
+                None                  => rest.len(),
+            };
+            self.parse_line(&rest[..end])?;
+
+            rest = &rest[end..];
+        }
+
+        // The last line may end with a backslash, or inside quotes with the PHP syntax
+        if let Some(line) = self.pending_line.take() {
+            if self.php_array.is_some() {
+                self.parse_php_statement(&line)?;
+            } else {
+                self.parse_joined_line(&line)?;
+            }
         }
+
+        Ok(())
+    }
+
+    /// Decodes `bytes`, which may be the whole content of an INI file, and parses every line of it like [`parse_content`](#method.parse_content "parse::Parser::parse_content")
+    /// 
+    /// The encoding is detected from the byte order mark of `bytes`, which is not part of the first line. Without byte order mark, `bytes` is decoded with `fallback`
+    /// 
+    /// # Return value
+    /// `Ok((encoding, bom))` in case of success with `encoding` as the encoding used and `bom` as whether `bytes` starts with a byte order mark, so that the file can be written back in the same way (see [`Dumper::generate_bytes`](../dump/struct.Dumper.html#method.generate_bytes "dump::Dumper::generate_bytes"))
+    /// 
+    /// `Err(error)` in case of error with `error` as either an encoding error, with the offset of the first invalid byte, or the error code of the first invalid line
+    /// 
+    /// # Example
+    /// ```
+    /// use mininip::parse::Parser;
+    /// use mininip::datas::{Identifier, TextMode, Value};
+    /// use mininip::datas::encoding::Encoding;
+    /// 
+    /// let mut parser = Parser::new();
+    /// parser.set_text_mode(TextMode::Utf8);
+    /// assert_eq!(parser.parse_bytes(b"\xEF\xBB\xBFname = 'caf\xC3\xA9'\n", Encoding::Latin1).unwrap(), (Encoding::Utf8, true));
+    /// assert_eq!(parser.parse_bytes(b"city = 'Gen\xE8ve'\n", Encoding::Latin1).unwrap(), (Encoding::Latin1, false));
+    /// 
+    /// let err = parser.parse_bytes(b"city = 'Gen\xE8ve'\n", Encoding::Utf8).unwrap_err();
+    /// assert_eq!(format!("{}", err), "Invalid UTF-8 data at byte 11");
+    /// 
+    /// let data = parser.data();
+    /// assert_eq!(data[&Identifier::new(None, String::from("name"))], Value::Str(String::from("café")));
+    /// assert_eq!(data[&Identifier::new(None, String::from("city"))], Value::Str(String::from("Genève")));
+    /// ```
+    pub fn parse_bytes(&mut self, bytes: &[u8], fallback: Encoding) -> Result<(Encoding, bool), ParseFileError> {
+        let (content, encoding, bom) = encoding::decode(bytes, fallback)?;
+        self.parse_content(&content)?;
+
+        Ok((encoding, bom))
     }
 
     /// Parses an assignment ligne. An assignment is of form
@@ -114,36 +731,124 @@ impl Parser {
     /// `Err(error)` in case of error with `error` as the error code
     fn parse_assignment(&mut self, line: &str) -> Result<(), Error> {
         // Getting the expression of `identifier` in "`identifier` = `value`[;comment]"
-        let equal = match line.find('=') {
-            Some(index) => index,
-            None        => {
+        // With the colon, the first unescaped delimiter splits the line so that `\=` and `\:` may be in the value. A verbatim line does not have any escape sequence
+        let find = |pattern| if self.escapes && self.colon_delimiter {
+            super::find_unescaped(line, pattern)
+        } else {
+            line.find(pattern)
+        };
+        let (colon, expected) = if self.colon_delimiter {
+            (find(':'), "= or :")
+        } else {
+            (None, "=")
+        };
+        let delimiter = match (find('='), colon) {
+            (Some(equal), Some(colon)) => equal.min(colon),
+            (Some(index), None)
+            | (None, Some(index))      => index,
+            (None, None)               => {
                 let end_of_ident = line.trim_end().len();
 
-                return Err(Error::from(ExpectedToken::new(String::from(line), end_of_ident, String::from("="))));
-            }
+                return Err(Error::from(ExpectedToken::new(String::from(line), end_of_ident, String::from(expected))));
+            },
         };
 
-        let identifier = String::from(line[..equal].trim());
+        let written = line[..delimiter].trim();
+        let identifier = if self.lowercase_keys {
+            written.to_lowercase()
+        } else {
+            String::from(written)
+        };
 
         // Getting the expression of `value` in "`identifier` = `value`[;comment]"
-        let value = if line.len() == equal + 1 {
-            ""
+        let value = &line[delimiter + 1..];
+        let text = if self.inline_comments {
+            ignore_comment(value).trim()
+        } else {
+            value.trim()
+        };
+
+        if !self.identifier_policy.is_valid(&self.identifier_policy.normalize(&identifier)) {
+            return Err(Error::from(InvalidIdentifier::with_policy(String::from(line), String::from(written), self.identifier_policy)));
+        }
+        let identifier = Identifier::with_policy(self.cur_section.clone(), identifier, self.identifier_policy);
+        let value = self.parse_value(&identifier, text).map_err(|err| match err {
+            Error::Overflow(err) => Error::from(err.in_line(line)),
+            err                  => err,
+        })?;
+
+        if self.continuation_lines {
+            self.continued = Some(Continued {
+                identifier: identifier.clone(),
+                indent: line.len() - line.trim_start().len(),
+                text: String::from(text),
+                empty_lines: 0,
+            });
+        }
+        if self.keep_lexemes {
+            self.lexemes.insert(identifier.clone(), Lexeme::new(String::from(text), value.clone()));
+        }
+        if let Some(unit_file) = &mut self.unit_file {
+            unit_file.add(identifier.clone(), value.clone());
+        }
+        self.locations.insert(identifier.clone(), self.line_number);
+        self.variables.insert(identifier, value);
+        Ok(())
+    }
+
+    /// Returns `true` if `line` is indented more than the last key assigned and continues its value
+    fn is_continuation(&self, line: &str) -> bool {
+        match &self.continued {
+            Some(continued) => line.len() - line.trim_start().len() > continued.indent,
+            None            => false,
+        }
+    }
+
+    /// Appends `line` to the value of the last key assigned, keeping the empty lines before it
+    fn continue_value(&mut self, line: &str) -> Result<(), Error> {
+        let mut continued = self.continued.take().expect("A line is continued only after an assignment");
+
+        let newline = if self.escapes { "\\n" } else { "\n" };
+        for _ in 0..=continued.empty_lines {
+            continued.text.push_str(newline);
+        }
+        continued.empty_lines = 0;
+
+        let line = if self.inline_comments {
+            ignore_comment(line).trim()
         } else {
-            ignore_comment(&line[equal + 1..]).trim()
+            line.trim()
         };
+        continued.text.push_str(line);
 
-        if !Identifier::is_valid(&identifier) {
-            return Err(Error::from(InvalidIdentifier::new(String::from(line), identifier)));
+        let value = self.parse_value(&continued.identifier, &continued.text)?;
+        if self.keep_lexemes {
+            self.lexemes.insert(continued.identifier.clone(), Lexeme::new(continued.text.clone(), value.clone()));
         }
-        let value = Value::parse(value)?;
+        self.variables.insert(continued.identifier.clone(), value);
 
-        self.variables.insert(
-            Identifier::new(self.cur_section.clone(), identifier),
-            value,
-        );
+        self.continued = Some(continued);
         Ok(())
     }
 
+    /// Reads `text`, the value of `identifier`, according to the type hints and the dialect
+    fn parse_value(&self, identifier: &Identifier, text: &str) -> Result<Value, Error> {
+        // A verbatim value is escaped so that it is read as written
+        let escaped;
+        let text = if !self.escapes {
+            escaped = dump::dump_str_with(text, self.text_mode);
+            &escaped
+        } else {
+            text
+        };
+
+        match self.type_hints.get(identifier) {
+            Some(value_type)         => Value::parse_as(text, *value_type, &self.bool_vocabulary, self.text_mode),
+            None if self.infer_types => Value::parse_with_vocabulary(text, &self.bool_vocabulary, self.text_mode),
+            None                     => Value::parse_uninferred(text, self.text_mode),
+        }
+    }
+
     /// Parses a section declaration. A section declaration is of form
     /// 
     /// ```ini
@@ -189,8 +894,8 @@ impl Parser {
         }
 
         let section = &line[leading_spaces + 1..end];
-        if !Identifier::is_valid(section) {
-            return Err(Error::from(InvalidIdentifier::new(String::from(line), String::from(section))));
+        if !self.identifier_policy.is_valid(&self.identifier_policy.normalize(section)) {
+            return Err(Error::from(InvalidIdentifier::with_policy(String::from(line), String::from(section), self.identifier_policy)));
         }
 
         // Checking integrity: I want to ensure there is no extra character after the section declaration
@@ -207,9 +912,653 @@ impl Parser {
             }
         }
 
-        self.cur_section = Some(String::from(section));
+        self.cur_section = Some(self.identifier_policy.normalize(section));
         Ok(())
     }
+
+    /// Parses a line with the Desktop Entry syntax
+    fn parse_desktop_line(&mut self, line: &str) -> Result<(), Error> {
+        let effective_line = line.trim_start();
+        match effective_line.chars().next() {
+            None | Some('#') => Ok(()),
+            Some('[')        => self.parse_desktop_group(line),
+            Some(_)          => self.parse_desktop_assignment(line),
+        }
+    }
+
+    /// Parses a group header with the Desktop Entry syntax, which is of form
+    /// 
+    /// ```ini
+    /// [Desktop Entry]
+    /// ```
+    /// 
+    /// # Panics
+    /// Panics if `line` doesn't start with a `[` character
+    fn parse_desktop_group(&mut self, line: &str) -> Result<(), Error> {
+        let begin = line.find('[').expect("A group header starts with `[`") + 1;
+        let end = line.trim_end().len();
+        if end <= begin || !line[..end].ends_with(']') {
+            return Err(Error::from(ExpectedToken::new(String::from(line), end, String::from("]"))));
+        }
+
+        let group = &line[begin..end - 1];
+        if group.is_empty() {
+            return Err(Error::from(ExpectedIdentifier::new(String::from(line), begin)));
+        } else if !DesktopKey::is_valid_group(group) {
+            // Such a group contains a bracket, a control or a non-ASCII character so it is not a valid `Identifier` either, whatever the policy of the other dialects
+            return Err(Error::from(InvalidIdentifier::new(String::from(line), String::from(group))));
+        }
+
+        self.cur_section = Some(String::from(group));
+        Ok(())
+    }
+
+    /// Parses an assignment with the Desktop Entry syntax, which is of form
+    /// 
+    /// ```ini
+    /// Key[locale]=value
+    /// ```
+    /// 
+    /// The locale is optional. The value is kept as written and only its escape sequences are checked
+    fn parse_desktop_assignment(&mut self, line: &str) -> Result<(), Error> {
+        let group = match &self.cur_section {
+            Some(group) => group.clone(),
+            None        => return Err(Error::from(ExpectedToken::new(String::from(line), 0, String::from("[")))),
+        };
+
+        let delimiter = match line.find('=') {
+            Some(index) => index,
+            None        => return Err(Error::from(ExpectedToken::new(String::from(line), line.trim_end().len(), String::from("=")))),
+        };
+        let begin = line.len() - line.trim_start().len();
+        let end = line[..delimiter].trim_end().len();
+
+        let (key_end, locale) = match line[begin..end].find('[') {
+            Some(index) => {
+                let open = begin + index;
+                if !line[..end].ends_with(']') {
+                    return Err(Error::from(ExpectedToken::new(String::from(line), end, String::from("]"))));
+                }
+
+                let text = &line[open + 1..end - 1];
+                let locale = Locale::parse(text).ok_or_else(|| match text.find(|c: char| !c.is_ascii_alphanumeric() && !"-_.@".contains(c)) {
+                    Some(index) => Error::from(UnexpectedToken::new(String::from(line), open + 1 + index)),
+                    None        => Error::from(ExpectedIdentifier::new(String::from(line), open + 1)),
+                })?;
+                (open, Some(locale))
+            },
+            None        => (end, None),
+        };
+
+        let key = &line[begin..key_end];
+        if key.is_empty() {
+            return Err(Error::from(ExpectedIdentifier::new(String::from(line), begin)));
+        } else if let Some(index) = key.find(|c: char| !c.is_ascii_alphanumeric() && c != '-') {
+            return Err(Error::from(UnexpectedToken::new(String::from(line), begin + index)));
+        }
+
+        let value = line[delimiter + 1..].trim_start();
+        if let Some(index) = desktop::find_invalid_escape(value) {
+            let escape = value[index..].chars().take(2).collect();
+            return Err(Error::from(InvalidEscape::new(String::from(line), escape)));
+        }
+
+        self.desktop_entry.as_mut()
+                          .expect("The Desktop Entry syntax is only used with a `DesktopEntry`")
+                          .insert(DesktopKey::new(group, String::from(key), locale), String::from(value));
+        Ok(())
+    }
+
+    /// Parses a line with the syntax of the profile files of Windows, which never fails
+    /// 
+    /// Only a line starting with `;` is a comment. A line between `[` and `]` declares a section, and any other line is a key followed by `=` and its value
+    fn parse_profile_line(&mut self, line: &str) {
+        let profile = self.profile.as_mut().expect("The profile syntax is only used with a `Profile`");
+
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(';') {
+            profile.push_comment(String::from(line));
+            return;
+        }
+
+        // Like on Windows, a section declaration without `]` is read as a key
+        if let (Some(rest), Some(end)) = (line.strip_prefix('['), line.rfind(']')) {
+            profile.push_section(String::from(rest[..end - 1].trim()));
+            return;
+        }
+
+        match line.find('=') {
+            Some(index) => profile.push_entry(String::from(line[..index].trim_end()), Some(String::from(line[index + 1..].trim_start()))),
+            None        => profile.push_entry(String::from(line), None),
+        }
+    }
+
+    /// Parses a line with the PHP syntax, which may be joined to the next ones if it ends inside quotes
+    fn parse_php_line(&mut self, line: &str) -> Result<(), Error> {
+        let joined = match self.pending_line.take() {
+            Some(mut pending) => {
+                pending.push('\n');
+                pending.push_str(line);
+                pending
+            },
+            None              => String::from(line),
+        };
+
+        if is_php_quote_open(&joined) {
+            self.pending_line = Some(joined);
+            return Ok(());
+        }
+
+        self.parse_php_statement(&joined)
+    }
+
+    /// Parses a statement with the PHP syntax, which may be made of several lines
+    fn parse_php_statement(&mut self, line: &str) -> Result<(), Error> {
+        let effective_line = line.trim_start();
+        match effective_line.chars().next() {
+            None | Some(';') => Ok(()),
+            Some('[')        => self.parse_php_section(line),
+            Some(_)          => self.parse_php_assignment(line),
+        }
+    }
+
+    /// Parses a section declaration with the PHP syntax, which is of form
+    /// 
+    /// ```ini
+    /// [section] ; comment
+    /// ```
+    /// 
+    /// The name may be quoted or contain variables like a value. A section declared again replaces the previous one
+    /// 
+    /// # Panics
+    /// Panics if `line` doesn't start with a `[` character
+    fn parse_php_section(&mut self, line: &str) -> Result<(), Error> {
+        let begin = line.find('[').expect("A section declaration starts with `[`") + 1;
+        let end = find_php_closing_bracket(line, begin)?;
+        let section = self.read_php_text(line, begin, end, false)?;
+        if section.is_empty() {
+            return Err(Error::from(ExpectedIdentifier::new(String::from(line), begin)));
+        }
+
+        let rest = line[end + 1..].trim_start();
+        if !rest.is_empty() && !rest.starts_with(';') {
+            return Err(Error::from(UnexpectedToken::new(String::from(line), line.len() - rest.len())));
+        }
+
+        self.php_array.as_mut()
+                      .expect("The PHP syntax is only used with a `PhpArray`")
+                      .insert(PhpKey::from(section.as_str()), PhpValue::Array(PhpArray::new()));
+        self.cur_section = Some(section);
+        Ok(())
+    }
+
+    /// Parses an assignment with the PHP syntax, which is of form
+    /// 
+    /// ```ini
+    /// key[offset] = value ; comment
+    /// ```
+    /// 
+    /// The offset is optional. `key[] = value` appends the value to the array `key` and `key[offset] = value` inserts it with the key `offset`, replacing `key` by an array if it was a value. A line without `=` is ignored like in PHP
+    fn parse_php_assignment(&mut self, line: &str) -> Result<(), Error> {
+        let begin = line.len() - line.trim_start().len();
+        let key_end = line[begin..].find(['=', '[', ';'])
+                                   .map_or(line.len(), |index| index + begin);
+        let key = line[begin..key_end].trim_end();
+        if key.is_empty() {
+            return Err(Error::from(ExpectedIdentifier::new(String::from(line), begin)));
+        } else if let Some(index) = key.find(|c: char| "?{}|&~!()^\"'$]".contains(c)) {
+            return Err(Error::from(UnexpectedToken::new(String::from(line), begin + index)));
+        } else if PHP_TRUE_WORDS.iter().chain(PHP_FALSE_WORDS).any(|word| key.eq_ignore_ascii_case(word)) {
+            return Err(Error::from(UnexpectedToken::new(String::from(line), begin)));
+        }
+
+        let (offset, offset_end) = if line[key_end..].starts_with('[') {
+            let close = find_php_closing_bracket(line, key_end + 1)?;
+            (Some(self.read_php_text(line, key_end + 1, close, false)?), close + 1)
+        } else {
+            (None, key_end)
+        };
+
+        let delimiter = offset_end + line[offset_end..].len() - line[offset_end..].trim_start().len();
+        match line[delimiter..].chars().next() {
+            None | Some(';') => return Ok(()),
+            Some('=')        => (),
+            Some(_)          => return Err(Error::from(ExpectedToken::new(String::from(line), delimiter, String::from("=")))),
+        }
+        let value = PhpValue::Value(Value::Str(self.read_php_text(line, delimiter + 1, line.len(), true)?));
+
+        let array = self.php_array.as_mut().expect("The PHP syntax is only used with a `PhpArray`");
+        let array = match &self.cur_section {
+            Some(section) => match array.get_mut(section.as_str()) {
+                Some(PhpValue::Array(section)) => section,
+                _                              => panic!("A section is declared as an array"),
+            },
+            None          => array,
+        };
+
+        let key = PhpKey::from(key);
+        let offset = match offset {
+            Some(offset) => offset,
+            None         => {
+                array.insert(key, value);
+                return Ok(());
+            },
+        };
+
+        if !matches!(array.get(key.clone()), Some(PhpValue::Array(_))) {
+            array.insert(key.clone(), PhpValue::Array(PhpArray::new()));
+        }
+        let array = match array.get_mut(key) {
+            Some(PhpValue::Array(array)) => array,
+            _                            => unreachable!("The key has just been replaced by an array"),
+        };
+
+        if offset.is_empty() {
+            array.push(value);
+        } else {
+            array.insert(PhpKey::from(offset.as_str()), value);
+        }
+        Ok(())
+    }
+
+    /// Reads the text of `line` between the indexes `begin` and `end`, concatenating its unquoted words, its strings between double or single quotes and its `${VAR}` variables
+    /// 
+    /// In a value, a `;` starts a comment, the constants are expanded and the words like `true` or `null` are converted. The whitespaces are ignored at both ends
+    fn read_php_text(&self, line: &str, begin: usize, end: usize, value: bool) -> Result<String, Error> {
+        let mut pieces = Vec::new();
+        let mut index = begin;
+        while index < end {
+            let rest = &line[index..end];
+            let c = rest.chars().next().expect("`rest` is not empty");
+
+            if c == ' ' || c == '\t' {
+                let len = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+                if !pieces.is_empty() {
+                    pieces.push(PhpPiece::Space(&rest[..len]));
+                }
+                index += len;
+            } else if c == ';' && value {
+                break;
+            } else if c == '"' {
+                let (text, next) = self.read_php_quoted(line, index, end)?;
+                pieces.push(PhpPiece::Quoted(text));
+                index = next;
+            } else if c == '\'' {
+                let close = rest[1..].find('\'')
+                                     .ok_or_else(|| Error::from(ExpectedToken::new(String::from(line), end, String::from("'"))))?;
+                pieces.push(PhpPiece::Quoted(String::from(&rest[1..close + 1])));
+                index += close + 2;
+            } else if rest.starts_with("${") {
+                let close = rest.find('}')
+                                .ok_or_else(|| Error::from(ExpectedToken::new(String::from(line), end, String::from("}"))))?;
+                pieces.push(PhpPiece::Quoted(self.php_variable(&rest[2..close])));
+                index += close + 1;
+            } else if "=|&^~!();".contains(c) {
+                // The bitwise operators of PHP are not supported
+                return Err(Error::from(UnexpectedToken::new(String::from(line), index)));
+            } else {
+                let len = rest.char_indices()
+                              .find(|&(n, c)| " \t;\"'=|&^~!()".contains(c) || rest[n..].starts_with("${"))
+                              .map_or(rest.len(), |(n, _)| n);
+                pieces.push(PhpPiece::Word(&rest[..len]));
+                index += len;
+            }
+        }
+
+        while let Some(PhpPiece::Space(_)) = pieces.last() {
+            pieces.pop();
+        }
+
+        if let (true, [PhpPiece::Word(word)]) = (value, pieces.as_slice()) {
+            if PHP_TRUE_WORDS.iter().any(|i| word.eq_ignore_ascii_case(i)) {
+                return Ok(String::from("1"));
+            } else if PHP_FALSE_WORDS.iter().any(|i| word.eq_ignore_ascii_case(i)) {
+                return Ok(String::new());
+            }
+        }
+
+        let mut text = String::new();
+        for piece in pieces {
+            match piece {
+                PhpPiece::Space(space)                  => text.push_str(space),
+                PhpPiece::Quoted(quoted)                => text.push_str(&quoted),
+                PhpPiece::Word(word) if value           => match self.php_constants.get(word) {
+                    Some(constant) => text.push_str(constant),
+                    None           => text.push_str(word),
+                },
+                PhpPiece::Word(word)                    => text.push_str(word),
+            }
+        }
+
+        Ok(text)
+    }
+
+    /// Reads the string between double quotes starting at the index `begin` of `line`, where `\"`, `\\` and `\$` are escape sequences and the `${VAR}` variables are expanded
+    /// 
+    /// # Return value
+    /// `Ok((text, next))` in case of success with `text` as the string read and `next` as the index after the closing quote
+    /// 
+    /// `Err(error)` in case of error with `error` as the error code
+    fn read_php_quoted(&self, line: &str, begin: usize, end: usize) -> Result<(String, usize), Error> {
+        let mut text = String::new();
+        let mut iter = line[begin + 1..end].char_indices()
+                                           .map(|(n, c)| (n + begin + 1, c))
+                                           .peekable();
+        while let Some((n, c)) = iter.next() {
+            match c {
+                '"'                                  => return Ok((text, n + 1)),
+                '\\'                                 => match iter.peek() {
+                    Some(&(_, c)) if "\"\\$".contains(c) => {
+                        text.push(c);
+                        iter.next();
+                    },
+                    _                                    => text.push('\\'),
+                },
+                '$' if line[n..end].starts_with("${") => {
+                    let close = line[n..end].find('}')
+                                            .map(|index| index + n)
+                                            .ok_or_else(|| Error::from(ExpectedToken::new(String::from(line), end, String::from("}"))))?;
+                    text.push_str(&self.php_variable(&line[n + 2..close]));
+                    while iter.next_if(|&(i, _)| i <= close).is_some() {}
+                },
+                _                                    => text.push(c),
+            }
+        }
+
+        Err(Error::from(ExpectedToken::new(String::from(line), end, String::from("\""))))
+    }
+
+    /// Returns the value of the `${name}` variable with the PHP syntax
+    fn php_variable(&self, name: &str) -> String {
+        match self.php_variables.get(name) {
+            Some(value) => value.clone(),
+            None        => std::env::var(name).unwrap_or_default(),
+        }
+    }
+
+    /// Parses a line with the git-config syntax, which may continue the value of the previous line
+    fn parse_git_line(&mut self, line: &str) -> Result<(), Error> {
+        if let Some((identifier, mut value)) = self.git_continued.take() {
+            return self.read_git_value(line, 0, identifier, &mut value);
+        }
+
+        let effective_line = line.trim_start();
+        match effective_line.chars().next() {
+            None | Some(';') | Some('#') => Ok(()),
+            Some('[')                    => self.parse_git_section(line),
+            Some(_)                      => self.parse_git_assignment(line),
+        }
+    }
+
+    /// Parses a section declaration with the git-config syntax, which is of form
+    /// 
+    /// ```ini
+    /// [section "subsection"] # comment
+    /// ```
+    /// 
+    /// The subsection is optional and may be written with the deprecated form `[section.subsection]`, which is case-insensitive. An assignment may follow the declaration on the same line
+    /// 
+    /// # Panics
+    /// Panics if `line` doesn't start with a `[` character
+    fn parse_git_section(&mut self, line: &str) -> Result<(), Error> {
+        let begin = line.find('[').expect("A section declaration starts with `[`") + 1;
+        let name_end = line[begin..].find(|c: char| c == ']' || c.is_whitespace())
+                                    .map(|index| index + begin)
+                                    .ok_or_else(|| Error::from(ExpectedToken::new(String::from(line), line.len(), String::from("]"))))?;
+        let name = &line[begin..name_end];
+        if name.is_empty() {
+            return Err(Error::from(ExpectedIdentifier::new(String::from(line), begin)));
+        } else if let Some(index) = name.find(|c: char| !c.is_ascii_alphanumeric() && c != '-' && c != '.') {
+            return Err(Error::from(UnexpectedToken::new(String::from(line), begin + index)));
+        }
+
+        let (section, subsection, end) = if line[name_end..].starts_with(']') {
+            match name.find('.') {
+                Some(dot) => (&name[..dot], Some(name[dot + 1..].to_lowercase()), name_end),
+                None      => (name, None, name_end),
+            }
+        } else {
+            let quote = name_end + line[name_end..].len() - line[name_end..].trim_start().len();
+            if !line[quote..].starts_with('"') {
+                return Err(Error::from(ExpectedToken::new(String::from(line), quote, String::from("\""))));
+            }
+
+            // In a subsection, a backslash escapes any character
+            let mut subsection = String::new();
+            let mut iter = line[quote + 1..].char_indices().map(|(n, c)| (n + quote + 1, c));
+            let closing = loop {
+                match iter.next() {
+                    Some((_, '\\'))  => match iter.next() {
+                        Some((_, c)) => subsection.push(c),
+                        None         => break None,
+                    },
+                    Some((n, '"'))   => break Some(n),
+                    Some((_, c))     => subsection.push(c),
+                    None             => break None,
+                }
+            };
+            let closing = closing.ok_or_else(|| Error::from(ExpectedToken::new(String::from(line), line.len(), String::from("\""))))?;
+
+            if !line[closing + 1..].starts_with(']') {
+                return Err(Error::from(ExpectedToken::new(String::from(line), closing + 1, String::from("]"))));
+            }
+            (name, Some(subsection), closing + 1)
+        };
+
+        if section.is_empty() {
+            return Err(Error::from(ExpectedIdentifier::new(String::from(line), begin)));
+        }
+        self.cur_section = Some(section.to_lowercase());
+        self.cur_subsection = subsection;
+
+        let rest = line[end + 1..].trim_start();
+        match rest.chars().next() {
+            None | Some(';') | Some('#') => Ok(()),
+            Some(_)                      => self.parse_git_assignment(rest),
+        }
+    }
+
+    /// Parses an assignment with the git-config syntax, which is of form
+    /// 
+    /// ```ini
+    /// key = value # comment
+    /// ```
+    /// 
+    /// A key without any value is `Value::Bool(true)`. Any other value is a `Value::Raw`
+    fn parse_git_assignment(&mut self, line: &str) -> Result<(), Error> {
+        let section = match &self.cur_section {
+            Some(section) => section.clone(),
+            None          => return Err(Error::from(ExpectedToken::new(String::from(line), 0, String::from("[")))),
+        };
+
+        let begin = line.len() - line.trim_start().len();
+        let key_end = line[begin..].find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+                                   .map_or(line.len(), |index| index + begin);
+        let key = &line[begin..key_end];
+        if key.is_empty() {
+            return Err(Error::from(UnexpectedToken::new(String::from(line), begin)));
+        } else if !GitIdentifier::is_valid_key(key) {
+            // Such a key starts with a digit or a `-` so it is not a valid `Identifier` either
+            return Err(Error::from(InvalidIdentifier::new(String::from(line), String::from(key))));
+        }
+        let identifier = GitIdentifier::new(section, self.cur_subsection.clone(), String::from(key));
+
+        let delimiter = key_end + line[key_end..].len() - line[key_end..].trim_start().len();
+        match line[delimiter..].chars().next() {
+            None | Some(';') | Some('#') => {
+                self.add_git_value(identifier, Value::Bool(true));
+                Ok(())
+            },
+            Some('=')                    => self.read_git_value(line, delimiter + 1, identifier, &mut GitValue::default()),
+            Some(_)                      => Err(Error::from(UnexpectedToken::new(String::from(line), delimiter))),
+        }
+    }
+
+    /// Reads the value of `identifier` from the index `begin` of `line`, after the text already read in `value`
+    /// 
+    /// The unquoted words `true` and `false` are a `Value::Bool` and any other value is a `Value::Raw`
+    fn read_git_value(&mut self, line: &str, begin: usize, identifier: GitIdentifier, value: &mut GitValue) -> Result<(), Error> {
+        if value.read(line, begin, self.git_cr_ending)? {
+            let text = std::mem::take(&mut value.text);
+            let value = match (text.as_str(), value.has_quotes) {
+                ("true", false)  => Value::Bool(true),
+                ("false", false) => Value::Bool(false),
+                _                => Value::Raw(text),
+            };
+            self.add_git_value(identifier, value);
+        } else {
+            self.git_continued = Some((identifier, std::mem::take(value)));
+        }
+
+        Ok(())
+    }
+
+    /// Stores a variable read with the git-config syntax
+    fn add_git_value(&mut self, identifier: GitIdentifier, value: Value) {
+        self.git_config.as_mut()
+                       .expect("The git-config syntax is only used with a `GitConfig`")
+                       .add(identifier, value);
+    }
+}
+
+/// The last value assigned, which may be continued by the next indented lines
+#[derive(Debug, Clone)]
+struct Continued {
+    identifier: Identifier,
+    /// The indentation of the line of the assignment
+    indent: usize,
+    /// The value as written, including the lines continuing it
+    text: String,
+    /// The number of empty lines since the last line of the value
+    empty_lines: usize,
+}
+
+/// A value being read with the git-config syntax, which may continue on the next lines
+#[derive(Debug, Clone, Default)]
+struct GitValue {
+    /// The value unescaped so far
+    text: String,
+    /// Whether the end of the text read is between double quotes
+    quoted: bool,
+    /// Whether a part of the text read was between double quotes
+    has_quotes: bool,
+    /// The number of whitespaces not added yet to `text`, which are ignored at the end of the value
+    spaces: usize,
+}
+
+impl GitValue {
+    /// Reads the value from the index `begin` of `line`. Outside of double quotes, a `#` or a `;` starts a comment and the whitespaces are ignored at both ends
+    /// 
+    /// `cr_ending` tells whether `line` ended with a lone carriage return. Like git, it is kept in the value if it is between double quotes
+    /// 
+    /// # Return value
+    /// `Ok(true)` if the value is complete
+    /// 
+    /// `Ok(false)` if `line` ends with a backslash or with a carriage return between double quotes, which means the value continues on the next line
+    /// 
+    /// `Err(error)` in case of error with `error` as the error code
+    fn read(&mut self, line: &str, begin: usize, cr_ending: bool) -> Result<bool, Error> {
+        let mut iter = line[begin..].chars();
+        while let Some(c) = iter.next() {
+            if !self.quoted {
+                if c == '#' || c == ';' {
+                    break;
+                } else if c.is_whitespace() {
+                    if !self.text.is_empty() {
+                        self.spaces += 1;
+                    }
+                    continue;
+                }
+            }
+
+            for _ in 0..self.spaces {
+                self.text.push(' ');
+            }
+            self.spaces = 0;
+
+            match c {
+                '\\' => match iter.next() {
+                    Some('n')  => self.text.push('\n'),
+                    Some('t')  => self.text.push('\t'),
+                    Some('b')  => self.text.push('\x08'),
+                    Some('\\') => self.text.push('\\'),
+                    Some('"')  => self.text.push('"'),
+                    Some(c)    => return Err(Error::from(InvalidEscape::new(String::from(line), format!("\\{}", c)))),
+                    None       => return Ok(false),
+                },
+                '"'  => {
+                    self.quoted = !self.quoted;
+                    self.has_quotes = true;
+                },
+                _    => self.text.push(c),
+            }
+        }
+
+        if self.quoted && cr_ending {
+            self.text.push('\r');
+            return Ok(false);
+        } else if self.quoted {
+            return Err(Error::from(ExpectedToken::new(String::from(line), line.len(), String::from("\""))));
+        }
+        Ok(true)
+    }
+}
+
+/// The unquoted words which are `"1"` with the PHP syntax
+const PHP_TRUE_WORDS: &[&str] = &["true", "on", "yes"];
+
+/// The unquoted words which are `""` with the PHP syntax
+const PHP_FALSE_WORDS: &[&str] = &["false", "off", "no", "none", "null"];
+
+/// A part of a text read with the PHP syntax
+#[derive(Debug, Clone)]
+enum PhpPiece<'a> {
+    /// Whitespaces, which are kept between two other parts
+    Space(&'a str),
+    /// An unquoted word, which may be a constant
+    Word(&'a str),
+    /// A string between quotes or a variable expanded
+    Quoted(String),
+}
+
+/// Returns `true` if `text` ends inside double or single quotes with the PHP syntax, which means the statement continues on the next line
+fn is_php_quote_open(text: &str) -> bool {
+    let mut quote = None;
+    let mut iter = text.chars();
+    while let Some(c) = iter.next() {
+        match (quote, c) {
+            (None, ';')             => break,
+            (None, '"')
+            | (None, '\'')          => quote = Some(c),
+            (Some('"'), '\\')       => {
+                iter.next();
+            },
+            (Some(q), c) if c == q  => quote = None,
+            _                       => (),
+        }
+    }
+
+    quote.is_some()
+}
+
+/// Returns the index of the `]` closing the brackets opened before the index `begin` of `line`, ignoring the ones between quotes
+fn find_php_closing_bracket(line: &str, begin: usize) -> Result<usize, Error> {
+    let mut quote = None;
+    let mut iter = line[begin..].char_indices().map(|(n, c)| (n + begin, c));
+    while let Some((n, c)) = iter.next() {
+        match (quote, c) {
+            (None, ']')             => return Ok(n),
+            (None, '"')
+            | (None, '\'')          => quote = Some(c),
+            (Some('"'), '\\')       => {
+                iter.next();
+            },
+            (Some(q), c) if c == q  => quote = None,
+            _                       => (),
+        }
+    }
+
+    Err(Error::from(ExpectedToken::new(String::from(line), line.len(), String::from("]"))))
 }
 
 /// Returns a subslice of the given slice which is comment-free (stopped at the first non-escaped semicolon ';'). `line` should be a single line
@@ -225,34 +1574,79 @@ fn ignore_comment(line: &str) -> &str {
 /// # Return value
 /// `Ok(data)` in case of success with `data` as a `HashMap<Identifier, Value>` linking each identifier to its associated value
 /// 
-/// `Err(error)` in case of failure with `error` as an error code for either an I/O error or a parsing error (see [ParseFileError](../errors/enum.ParseFileError.html "errors::ParseFileError"))
+/// `Err(error)` in case of failure with `error` as an error code for either an I/O error, an encoding error or a parsing error (see [ParseFileError](../errors/enum.ParseFileError.html "errors::ParseFileError"))
+/// 
+/// # Encoding
+/// The file is read in UTF-8 or in the encoding of its byte order mark (see [`Parser::parse_bytes`](struct.Parser.html#method.parse_bytes "parse::Parser::parse_bytes") for the other encodings)
 pub fn parse_file<T: AsRef<Path>>(path: T) -> Result<HashMap<Identifier, Value>, ParseFileError> {
+    parse_file_with(path, TextMode::Ascii)
+}
+
+/// Reads in an INI file like [`parse_file`](fn.parse_file.html "parse::parse_file") does, but allows the non-ASCII characters not to be escaped in the values if `mode` is `TextMode::Utf8`
+/// 
+/// # Return value
+/// The same as [`parse_file`](fn.parse_file.html "parse::parse_file"), which reads the file in the same encoding
+pub fn parse_file_with<T: AsRef<Path>>(path: T, mode: TextMode) -> Result<HashMap<Identifier, Value>, ParseFileError> {
     let mut file = File::open(path)?;
 
-    let mut content = String::new();
-    file.read_to_string(&mut content)?;
-    let content = content;
+    let mut content = Vec::new();
+    file.read_to_end(&mut content)?;
 
     let mut parser = Parser::new();
+    parser.set_text_mode(mode);
+    parser.parse_bytes(&content, Encoding::Utf8)?;
 
-    let mut begin = 0;
-    while begin < content.len() {
-        let end = match &content[begin..].find('\n') {
-            Some(val) => val + begin,
-            None      => content.len(),
-        };
-        if begin == end {
-            begin += 1;
-            continue;
-        }
+    Ok(parser.data())
+}
+
+/// Reads in a profile file of Windows, which may be encoded in UTF-8 or in UTF-16 with a byte order mark, following [`Dialect::WindowsProfile`](enum.Dialect.html "parse::Dialect")
+/// 
+/// # Return value
+/// `Ok(profile)` in case of success with `profile` as the content of the file, which remembers its encoding
+/// 
+/// `Err(error)` in case of failure with `error` as either an I/O error or an encoding error if the file is not valid in the encoding detected (see [ParseFileError](../errors/enum.ParseFileError.html "errors::ParseFileError"))
+/// 
+/// # See
+/// [`dump_profile_into_file`](../dump/fn.dump_profile_into_file.html "dump::dump_profile_into_file") to write it back
+pub fn parse_profile_file<T: AsRef<Path>>(path: T) -> Result<Profile, ParseFileError> {
+    let mut file = File::open(path)?;
 
-        let line = &content[begin..end];
-        parser.parse_line(line)?;
+    let mut content = Vec::new();
+    file.read_to_end(&mut content)?;
 
-        begin = end + 1;
-    }
+    Ok(parse_profile_bytes(&content)?)
+}
 
-    Ok(parser.data())
+/// Reads the content of a profile file of Windows like [`parse_profile_file`](fn.parse_profile_file.html "parse::parse_profile_file")
+/// 
+/// # Return value
+/// `Ok(profile)` in case of success
+/// 
+/// `Err(error)` if `bytes` is not valid in the encoding detected
+/// 
+/// # Example
+/// ```
+/// use mininip::parse::parse_profile_bytes;
+/// use mininip::datas::encoding::Encoding;
+/// 
+/// let bytes = Encoding::Utf16Le.encode("[Général]\r\nNom=\"Élodie\"\r\n", true).unwrap();
+/// 
+/// let profile = parse_profile_bytes(&bytes).unwrap();
+/// assert_eq!(profile.encoding(), Encoding::Utf16Le);
+/// assert!(profile.bom());
+/// assert_eq!(profile.get(Some("général"), "NOM"), Some("Élodie"));
+/// assert_eq!(profile.to_bytes().unwrap(), bytes);
+/// ```
+pub fn parse_profile_bytes(bytes: &[u8]) -> Result<Profile, EncodingError> {
+    // A profile without byte order mark is read as UTF-8 instead of the ANSI code page of Windows
+    let (content, encoding, bom) = encoding::decode(bytes, Encoding::Utf8)?;
+
+    let mut parser = Parser::with_dialect(Dialect::WindowsProfile);
+    parser.parse_content(&content).expect("The profile syntax does not have any error");
+
+    let mut profile = parser.profile();
+    profile.set_encoding(encoding, bom);
+    Ok(profile)
 }
 
 
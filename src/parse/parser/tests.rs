@@ -1,5 +1,6 @@
 use crate::parse::*;
-use crate::datas::{Identifier, Value};
+use crate::datas::{BoolVocabulary, Identifier, IdentifierPolicy, Value, ValueType};
+use std::collections::HashMap;
 use crate::errors::Error;
 
 #[test]
@@ -156,6 +157,36 @@ fn parser_parse_assignment_bool() {
     assert_eq!(data[&key], val);
 }
 
+#[test]
+fn parser_parse_assignment_bool_default_vocabulary() {
+    let mut parser = Parser::new();
+
+    parser.parse_assignment("a=True").unwrap();
+    parser.parse_assignment("b=FALSE").unwrap();
+    parser.parse_assignment("c=1").unwrap();
+
+    let data = parser.data();
+    assert_eq!(data[&Identifier::new(None, String::from("a"))], Value::Bool(true));
+    assert_eq!(data[&Identifier::new(None, String::from("b"))], Value::Bool(false));
+    assert_eq!(data[&Identifier::new(None, String::from("c"))], Value::Int(1));
+}
+
+#[test]
+fn parser_parse_assignment_bool_custom_vocabulary() {
+    let mut parser = Parser::new();
+    let vocabulary = BoolVocabulary::new(vec![String::from("Oui")], vec![String::from("Non")], true);
+    parser.set_bool_vocabulary(vocabulary);
+
+    parser.parse_assignment("a=Oui").unwrap();
+    parser.parse_assignment("b=non").unwrap();
+    parser.parse_assignment("c=yes").unwrap();
+
+    let data = parser.data();
+    assert_eq!(data[&Identifier::new(None, String::from("a"))], Value::Bool(true));
+    assert_eq!(data[&Identifier::new(None, String::from("b"))], Value::Raw(String::from("non")));
+    assert_eq!(data[&Identifier::new(None, String::from("c"))], Value::Raw(String::from("yes")));
+}
+
 #[test]
 fn parser_parse_assignment_unicode_identifier() {
     let expr = r"é=\x0000e9";
@@ -377,3 +408,257 @@ fn parser_parse_line_empty() {
     parser.parse_line(expr)
         .expect("This line should be accepted because it's a valid INI empty line");
 }
+
+#[test]
+fn parser_type_hints() {
+    let version = Identifier::new(Some(String::from("app")), String::from("version"));
+    let ratio = Identifier::new(Some(String::from("app")), String::from("ratio"));
+    let other = Identifier::new(None, String::from("version"));
+
+    let mut hints = HashMap::new();
+    hints.insert(version.clone(), ValueType::Str);
+    hints.insert(ratio.clone(), ValueType::Float);
+
+    let mut parser = Parser::new();
+    parser.set_type_hints(hints);
+    parser.parse_content("version = 1.10\n[app]\nversion = 1.10\nratio = 3\n").unwrap();
+
+    let data = parser.data();
+    assert_eq!(data[&version], Value::Str(String::from("1.10")));
+    assert_eq!(data[&ratio], Value::Float(3.0));
+    assert_eq!(data[&other], Value::Float(1.1));
+}
+
+#[test]
+fn parser_type_hint_mismatch() {
+    let mut hints = HashMap::new();
+    hints.insert(Identifier::new(None, String::from("port")), ValueType::Int);
+
+    let mut parser = Parser::new();
+    parser.set_type_hints(hints);
+
+    match parser.parse_line("port = eighty") {
+        Err(Error::WrongType(err)) => assert_eq!(format!("{}", err), "Expected a value of type Int but found a value of type Raw in eighty"),
+        result                     => panic!("Wrong result returned (got {:?})", result),
+    }
+}
+
+#[test]
+fn parser_no_type_inference() {
+    let mut parser = Parser::new();
+    parser.set_type_inference(false);
+    parser.parse_content("zip = 01234\nname = 'demo'\nenabled = yes\n").unwrap();
+
+    let data = parser.data();
+    assert_eq!(data[&Identifier::new(None, String::from("zip"))], Value::Raw(String::from("01234")));
+    assert_eq!(data[&Identifier::new(None, String::from("name"))], Value::Str(String::from("demo")));
+    assert_eq!(data[&Identifier::new(None, String::from("enabled"))], Value::Raw(String::from("yes")));
+}
+
+#[test]
+fn parser_keep_lexemes() {
+    let mut parser = Parser::new();
+    parser.parse_line("before = 1").unwrap();
+    parser.set_keep_lexemes(true);
+    parser.parse_content("[section]\nhex = \"0x10\" ; comment\nspaced =   1e3   \n").unwrap();
+
+    let lexemes = parser.lexemes();
+    assert_eq!(lexemes.len(), 2);
+
+    let hex = &lexemes[&Identifier::new(Some(String::from("section")), String::from("hex"))];
+    assert_eq!(hex.text(), "\"0x10\"");
+    assert_eq!(hex.quote(), Some('"'));
+    assert_eq!(hex.value(), &Value::Str(String::from("0x10")));
+
+    let spaced = &lexemes[&Identifier::new(Some(String::from("section")), String::from("spaced"))];
+    assert_eq!(spaced.text(), "1e3");
+    assert_eq!(spaced.value(), &Value::Float(1000.0));
+}
+
+#[test]
+fn parser_colon_delimiter() {
+    let mut parser = Parser::new();
+    parser.set_colon_delimiter(true);
+    parser.parse_content("a: 1\nb = 2\nc: x \\= y\nd = x \\: y\n").unwrap();
+
+    let data = parser.data();
+    assert_eq!(data[&Identifier::new(None, String::from("a"))], Value::Int(1));
+    assert_eq!(data[&Identifier::new(None, String::from("b"))], Value::Int(2));
+    assert_eq!(data[&Identifier::new(None, String::from("c"))], Value::Raw(String::from("x = y")));
+    assert_eq!(data[&Identifier::new(None, String::from("d"))], Value::Raw(String::from("x : y")));
+}
+
+#[test]
+fn parser_colon_delimiter_disabled() {
+    let mut parser = Parser::new();
+    parser.parse_line("a:b = 1").unwrap();
+
+    match parser.parse_line("a: 1") {
+        Err(Error::ExpectedToken(_)) => (),
+        result                       => panic!("Wrong result returned (got {:?})", result),
+    }
+
+    let data = parser.data();
+    assert_eq!(data[&Identifier::new(None, String::from("a:b"))], Value::Int(1));
+}
+
+#[test]
+fn parser_colon_delimiter_expected_tokens() {
+    let mut parser = Parser::new();
+    parser.set_colon_delimiter(true);
+
+    match parser.parse_line("key") {
+        Err(err @ Error::ExpectedToken(_)) => assert_eq!(format!("{}", err), "Expected = or : key{here}"),
+        result                             => panic!("Wrong result returned (got {:?})", result),
+    }
+
+    parser.set_colon_delimiter(false);
+    match parser.parse_line("key") {
+        Err(err @ Error::ExpectedToken(_)) => assert_eq!(format!("{}", err), "Expected = key{here}"),
+        result                             => panic!("Wrong result returned (got {:?})", result),
+    }
+}
+
+#[test]
+fn parser_escaped_equal_in_key() {
+    // Without the colon, the first `=` splits the line as it always did, even if it is escaped
+    let mut parser = Parser::new();
+    match parser.parse_line("a\\=b = 1") {
+        Err(Error::InvalidIdentifier(_)) => (),
+        result                           => panic!("Wrong result returned (got {:?})", result),
+    }
+}
+
+#[test]
+fn parser_ascii_identifiers_by_default() {
+    let mut parser = Parser::new();
+
+    match parser.parse_line("größe = 1") {
+        Err(Error::InvalidIdentifier(_)) => (),
+        other                            => panic!("Expected an InvalidIdentifier error, got {:?}", other),
+    }
+    parser.set_identifier_policy(IdentifierPolicy::Ascii);
+    match parser.parse_line("[名前]") {
+        Err(Error::InvalidIdentifier(_)) => (),
+        other                            => panic!("Expected an InvalidIdentifier error, got {:?}", other),
+    }
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn parser_unicode_identifiers() {
+    let mut parser = Parser::new();
+    parser.set_identifier_policy(IdentifierPolicy::UnicodeXid);
+    parser.parse_content("cafe\u{301} = 1\n[Größe]\nbreite = 2\n名前 = 'x'\n").unwrap();
+    match parser.parse_line("_hidden = 1") {
+        Err(Error::InvalidIdentifier(_)) => (),
+        other                            => panic!("Expected an InvalidIdentifier error, got {:?}", other),
+    }
+
+    let data = parser.data();
+    let ident = |section: Option<&str>, name: &str| Identifier::with_policy(section.map(String::from), String::from(name), IdentifierPolicy::UnicodeXid);
+    assert_eq!(data.len(), 3);
+    assert_eq!(data[&ident(None, "caf\u{e9}")], Value::Int(1));
+    assert_eq!(data[&ident(Some("Gro\u{308}ße"), "breite")], Value::Int(2));
+    assert_eq!(data[&ident(Some("Größe"), "名前")], Value::Str(String::from("x")));
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn parser_unicode_invalid_identifiers() {
+    let mut parser = Parser::new();
+    parser.set_identifier_policy(IdentifierPolicy::UnicodeXid);
+
+    for line in &["[1st]", "[_hidden]", "1st = 1", "名前; = 1"] {
+        match parser.parse_line(line) {
+            Err(Error::InvalidIdentifier(_)) => (),
+            other                            => panic!("Expected an InvalidIdentifier error for {:?}, got {:?}", line, other),
+        }
+    }
+}
+
+#[test]
+fn parser_dotenv_invalid_identifiers() {
+    let mut parser = Parser::new();
+    parser.set_identifier_policy(IdentifierPolicy::Dotenv);
+
+    // These ones are valid ASCII identifiers but not valid `.env` keys
+    for line in &["[a b]", "[a:b]", "a b = 1", "a~b = 1", "[1st]", "1st = 1"] {
+        match parser.parse_line(line) {
+            Err(Error::InvalidIdentifier(_)) => (),
+            other                            => panic!("Expected an InvalidIdentifier error for {:?}, got {:?}", line, other),
+        }
+    }
+}
+
+#[test]
+fn parser_utf8_values() {
+    use crate::datas::TextMode;
+
+    let mut parser = Parser::new();
+    match parser.parse_line("name = 'café'") {
+        Err(Error::ExpectedEscape(_)) => (),
+        other                         => panic!("Expected an ExpectedEscape error, got {:?}", other),
+    }
+
+    parser.set_text_mode(TextMode::Utf8);
+    parser.parse_content("name = 'café'\ngreeting = こんにちは \\x00263a ; comment\n[section]\n").unwrap();
+    match parser.parse_line("bad = é=") {
+        Err(Error::ExpectedEscape(_)) => (),
+        other                         => panic!("Expected an ExpectedEscape error, got {:?}", other),
+    }
+
+    let data = parser.data();
+    assert_eq!(data[&Identifier::new(None, String::from("name"))], Value::Str(String::from("café")));
+    assert_eq!(data[&Identifier::new(None, String::from("greeting"))], Value::Raw(String::from("こんにちは \u{263a}")));
+}
+
+#[test]
+fn parser_line_endings() {
+    use crate::datas::LineEnding;
+
+    for &(content, ending) in &[("a = 'x'\n[s]\nb = 1\n", LineEnding::Lf), ("a = 'x'\r\n[s]\r\nb = 1\r\n", LineEnding::CrLf), ("a = 'x'\r[s]\rb = 1", LineEnding::Cr)] {
+        let mut parser = Parser::new();
+        parser.parse_content(content).unwrap();
+        assert_eq!(parser.line_ending(), Some(ending));
+        assert_eq!(parser.line_number(), 3);
+
+        let data = parser.data();
+        assert_eq!(data[&Identifier::new(None, String::from("a"))], Value::Str(String::from("x")));
+        assert_eq!(data[&Identifier::new(Some(String::from("s")), String::from("b"))], Value::Int(1));
+    }
+
+    let mut parser = Parser::new();
+    parser.parse_line("a = 'x'").unwrap();
+    assert_eq!(parser.line_ending(), None);
+    parser.parse_line("b = 'y'\r\n").unwrap();
+    parser.parse_content("c = 'z'\n").unwrap();
+    assert_eq!(parser.line_ending(), Some(LineEnding::CrLf));
+    assert_eq!(parser.data()[&Identifier::new(None, String::from("b"))], Value::Str(String::from("y")));
+}
+
+#[test]
+fn parser_line_endings_continuations() {
+    let mut parser = Parser::with_dialect(Dialect::Systemd);
+    parser.parse_content("[Service]\r\nExecStart=/bin/a \\\r\n  --flag\r\n").unwrap();
+    let exec_start = Identifier::new(Some(String::from("Service")), String::from("ExecStart"));
+    assert_eq!(parser.data()[&exec_start], Value::Raw(String::from("/bin/a    --flag")));
+
+    let mut parser = Parser::with_dialect(Dialect::GitConfig);
+    parser.parse_content("[core]\r\n\teditor = vim \\\r\n -u NONE\r\n").unwrap();
+    assert_eq!(parser.git_config().get("core", None, "editor"), Some(&Value::Raw(String::from("vim  -u NONE"))));
+}
+
+#[test]
+fn parser_overflow_names_line_and_type() {
+    let mut parser = Parser::new();
+
+    match parser.parse_line("size = 99999999999999999999KiB ; too big") {
+        Err(Error::Overflow(err)) => assert_eq!(format!("{}", err), "Value 99999999999999999999KiB is too big to be stored in u64 in size = 99999999999999999999KiB ; too big"),
+        other                     => panic!("Expected an Overflow error, got {:?}", other),
+    }
+    match parser.parse_line("timeout = 99999999999999999999d") {
+        Err(Error::Overflow(err)) => assert_eq!(format!("{}", err), "Value 99999999999999999999d is too big to be stored in Duration in timeout = 99999999999999999999d"),
+        other                     => panic!("Expected an Overflow error, got {:?}", other),
+    }
+}
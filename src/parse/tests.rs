@@ -1,5 +1,6 @@
 use crate::parse::*;
 use crate::errors::Error;
+use std::time::Duration;
 
 #[test]
 fn token_iterator_no_escapes() {
@@ -8,7 +9,7 @@ fn token_iterator_no_escapes() {
                 .collect::<Vec<Token>>();
 
     let expected = message.chars()
-                          .map(|c| Token::Char(c))
+                          .map(Token::Char)
                           .collect::<Vec<Token>>();
 
     assert_eq!(found, expected);
@@ -50,7 +51,7 @@ fn token_iterator_unfinished_escape() {
 
     let mut expected = message.chars()
                               .take(message.len() - 1)
-                              .map(|c| Token::Char(c))
+                              .map(Token::Char)
                               .collect::<Vec<Token>>();
     expected.push(Token::Escape(String::from("\\")));
 
@@ -113,6 +114,19 @@ fn parse_str_forbidden_unicode() {
     }
 }
 
+#[test]
+fn parse_str_with_utf8() {
+    use crate::datas::TextMode;
+
+    assert_eq!(parse_str_with("日本語 \\x00263a café", TextMode::Utf8).unwrap(), "日本語 \u{263a} café");
+    assert_eq!(parse_str_with("caf\\x0000e9", TextMode::Ascii).unwrap(), "café");
+
+    match parse_str_with("é=", TextMode::Utf8) {
+        Err(Error::ExpectedEscape(err)) => assert_eq!(format!("{}", err), "Expected escape sequence \\= instead of = in é{here}"),
+        other                           => panic!("Wrong return value: {:?}", other),
+    }
+}
+
 #[test]
 fn find_unescaped_found() {
     let sequence = "abc";
@@ -140,3 +154,83 @@ fn find_unescaped_not_found() {
 
     assert_eq!(None, find_unescaped(sequence, 'e'));
 }
+
+#[test]
+fn parse_duration_units() {
+    assert_eq!(parse_duration("7ns").unwrap(),  Some(Duration::from_nanos(7)));
+    assert_eq!(parse_duration("7us").unwrap(),  Some(Duration::from_micros(7)));
+    assert_eq!(parse_duration("7µs").unwrap(),  Some(Duration::from_micros(7)));
+    assert_eq!(parse_duration("7ms").unwrap(),  Some(Duration::from_millis(7)));
+    assert_eq!(parse_duration("7s").unwrap(),   Some(Duration::from_secs(7)));
+    assert_eq!(parse_duration("7m").unwrap(),   Some(Duration::from_secs(7 * 60)));
+    assert_eq!(parse_duration("7min").unwrap(), Some(Duration::from_secs(7 * 60)));
+    assert_eq!(parse_duration("7h").unwrap(),   Some(Duration::from_secs(7 * 3600)));
+    assert_eq!(parse_duration("7 d").unwrap(),  Some(Duration::from_secs(7 * 86400)));
+}
+
+#[test]
+fn parse_duration_not_a_duration() {
+    assert_eq!(parse_duration("s").unwrap(),    None);
+    assert_eq!(parse_duration("30").unwrap(),   None);
+    assert_eq!(parse_duration("-30s").unwrap(), None);
+    assert_eq!(parse_duration("30 S").unwrap(), None);
+    assert_eq!(parse_duration("1.5s").unwrap(), None);
+    assert_eq!(parse_duration("30s 1m").unwrap(), None);
+    assert_eq!(parse_duration("30s later").unwrap(), None);
+}
+
+#[test]
+fn parse_duration_seconds_and_fraction() {
+    assert_eq!(parse_duration("30s 500ms").unwrap(), Some(Duration::from_millis(30_500)));
+    assert_eq!(parse_duration("30 s  7ns").unwrap(), Some(Duration::new(30, 7)));
+    assert_eq!(parse_duration("30ms 5ns").unwrap(), None);
+}
+
+#[test]
+fn parse_duration_overflow() {
+    match parse_duration("18446744073709551616ns") {
+        Ok(_)                   => panic!("This duration overflows and shouldn't be accepted"),
+        Err(Error::Overflow(_)) => {},
+        Err(err)                => panic!("Wrong return value: {:?}", err),
+    }
+
+    match parse_duration("18446744073709551615d") {
+        Ok(_)                   => panic!("This duration overflows and shouldn't be accepted"),
+        Err(Error::Overflow(_)) => {},
+        Err(err)                => panic!("Wrong return value: {:?}", err),
+    }
+}
+
+#[test]
+fn parse_size_units() {
+    assert_eq!(parse_size("3B").unwrap(),   Some(3));
+    assert_eq!(parse_size("3kB").unwrap(),  Some(3_000));
+    assert_eq!(parse_size("3KB").unwrap(),  Some(3_000));
+    assert_eq!(parse_size("3MB").unwrap(),  Some(3_000_000));
+    assert_eq!(parse_size("3GB").unwrap(),  Some(3_000_000_000));
+    assert_eq!(parse_size("3TB").unwrap(),  Some(3_000_000_000_000));
+    assert_eq!(parse_size("3PB").unwrap(),  Some(3_000_000_000_000_000));
+    assert_eq!(parse_size("3EB").unwrap(),  Some(3_000_000_000_000_000_000));
+    assert_eq!(parse_size("3KiB").unwrap(), Some(3 << 10));
+    assert_eq!(parse_size("3MiB").unwrap(), Some(3 << 20));
+    assert_eq!(parse_size("3GiB").unwrap(), Some(3 << 30));
+    assert_eq!(parse_size("3TiB").unwrap(), Some(3 << 40));
+    assert_eq!(parse_size("3PiB").unwrap(), Some(3 << 50));
+    assert_eq!(parse_size("3 EiB").unwrap(), Some(3 << 60));
+}
+
+#[test]
+fn parse_size_not_a_size() {
+    assert_eq!(parse_size("KiB").unwrap(),  None);
+    assert_eq!(parse_size("3kib").unwrap(), None);
+    assert_eq!(parse_size("3 bytes").unwrap(), None);
+}
+
+#[test]
+fn parse_size_overflow() {
+    match parse_size("20EB") {
+        Ok(_)                   => panic!("This size overflows and shouldn't be accepted"),
+        Err(Error::Overflow(_)) => {},
+        Err(err)                => panic!("Wrong return value: {:?}", err),
+    }
+}
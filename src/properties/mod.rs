@@ -0,0 +1,295 @@
+//! Reads and writes the `.properties` files of Java, like `java.util.Properties`, into the same `HashMap<Identifier, Value>` as an INI file
+//! 
+//! A `.properties` file does not have any section. Each key, which usually contains dots like `server.port`, is the name of an identifier of the global section, and each value is a `Value::Raw`
+//! 
+//! # Example
+//! ```
+//! use mininip::datas::{Identifier, Value};
+//! use mininip::datas::tree::Tree;
+//! use mininip::properties;
+//! 
+//! let data = properties::parse_content("# comment\nserver.port = 8080\ngreeting : Hello \\u263a\nlist a,\\\n     b\n").unwrap();
+//! 
+//! assert_eq!(data[&Identifier::new(None, String::from("server.port"))], Value::Raw(String::from("8080")));
+//! assert_eq!(data[&Identifier::new(None, String::from("greeting"))], Value::Raw(String::from("Hello \u{263a}")));
+//! assert_eq!(data[&Identifier::new(None, String::from("list"))], Value::Raw(String::from("a,b")));
+//! 
+//! let tree = Tree::from(data);
+//! let global = tree.sections().next().unwrap();
+//! assert_eq!(global.name(), None);
+//! assert_eq!(global.keys().count(), 3);
+//! ```
+
+use crate::datas::{Identifier, Value};
+use crate::errors::{Error, error_kinds::*, ParseFileError};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+/// The whitespaces which separate a key from its value
+const WHITESPACES: [char; 3] = [' ', '\t', '\x0c'];
+
+/// Reads the content of a `.properties` file
+/// 
+/// # Return value
+/// `Ok(data)` in case of success with `data` as a `HashMap<Identifier, Value>` linking each key of the global section to its value
+/// 
+/// `Err(error)` in case of error with `error` as the error code of the first invalid line, which may be an invalid `\uXXXX` escape or a key which is not a valid [`Identifier`](../datas/struct.Identifier.html "datas::Identifier")
+/// 
+/// # Notes
+/// Like in Java, a line starting with `#` or `!` is a comment, a line ending with an odd number of backslashes continues on the next line and the last value of a key wins
+pub fn parse_content(content: &str) -> Result<HashMap<Identifier, Value>, Error> {
+    let mut data = HashMap::new();
+
+    let content = content.replace("\r\n", "\n");
+    let mut logical = String::new();
+    let mut continued = false;
+    for line in content.split(['\n', '\r']) {
+        let line = line.trim_start_matches(WHITESPACES);
+        if !continued && (line.is_empty() || line.starts_with(['#', '!'])) {
+            continue;
+        }
+
+        logical.push_str(line);
+        let backslashes = logical.len() - logical.trim_end_matches('\\').len();
+        continued = backslashes % 2 == 1;
+        if continued {
+            logical.pop();
+            continue;
+        }
+
+        parse_assignment(&logical, &mut data)?;
+        logical.clear();
+    }
+
+    // The last line may end with a backslash
+    if continued {
+        parse_assignment(&logical, &mut data)?;
+    }
+
+    Ok(data)
+}
+
+/// Reads in a `.properties` file, decoded from ISO-8859-1 like `Properties.load(InputStream)` in Java
+/// 
+/// # Return value
+/// `Ok(data)` in case of success with `data` as a `HashMap<Identifier, Value>` linking each key to its value
+/// 
+/// `Err(error)` in case of failure with `error` as an error code for either an I/O error or a parsing error (see [ParseFileError](../errors/enum.ParseFileError.html "errors::ParseFileError"))
+pub fn parse_file<T: AsRef<Path>>(path: T) -> Result<HashMap<Identifier, Value>, ParseFileError> {
+    let mut file = File::open(path)?;
+
+    let mut content = Vec::new();
+    file.read_to_end(&mut content)?;
+    let content: String = content.iter().map(|&byte| char::from(byte)).collect();
+
+    Ok(parse_content(&content)?)
+}
+
+/// Reads a logical line, which may be made of several lines ending with a backslash, and stores its key and its value into `data`
+fn parse_assignment(line: &str, data: &mut HashMap<Identifier, Value>) -> Result<(), Error> {
+    // The key ends at the first unescaped delimiter or whitespace
+    let mut key_end = line.len();
+    let mut escaped = false;
+    for (n, c) in line.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '=' || c == ':' || WHITESPACES.contains(&c) {
+            key_end = n;
+            break;
+        }
+    }
+
+    let rest = line[key_end..].trim_start_matches(WHITESPACES);
+    let value = match rest.strip_prefix(['=', ':']) {
+        Some(value) => value.trim_start_matches(WHITESPACES),
+        None        => rest,
+    };
+
+    let key = unescape(line, &line[..key_end])?;
+    if key.is_empty() {
+        return Err(Error::from(ExpectedIdentifier::new(String::from(line), 0)));
+    } else if !Identifier::is_valid(&key) {
+        return Err(Error::from(InvalidIdentifier::new(String::from(line), key)));
+    }
+
+    let value = unescape(line, value)?;
+    data.insert(Identifier::new(None, key), Value::Raw(value));
+    Ok(())
+}
+
+/// Unescapes `text`, a part of `line`. `\t`, `\n`, `\r`, `\f` and `\uXXXX` are escape sequences and a backslash followed by any other character is this character
+fn unescape(line: &str, text: &str) -> Result<String, Error> {
+    let mut result = String::with_capacity(text.len());
+    // A high surrogate waiting for the low surrogate following it
+    let mut high_surrogate: Option<(u16, &str)> = None;
+
+    let mut iter = text.char_indices();
+    while let Some((n, c)) = iter.next() {
+        let c = if c == '\\' {
+            match iter.next() {
+                Some((_, 't')) => '\t',
+                Some((_, 'n')) => '\n',
+                Some((_, 'r')) => '\r',
+                Some((_, 'f')) => '\x0c',
+                Some((_, 'u')) => {
+                    let escape = text[n..].get(..6).filter(|escape| escape[2..].chars().all(|c| c.is_ascii_hexdigit()));
+                    let escape = match escape {
+                        Some(escape) => escape,
+                        None         => {
+                            let end = text[n..].char_indices().nth(6).map_or(text.len(), |(i, _)| n + i);
+                            return Err(Error::from(InvalidEscape::new(String::from(line), String::from(&text[n..end]))));
+                        },
+                    };
+                    for _ in 0..4 {
+                        iter.next();
+                    }
+
+                    let unit = u16::from_str_radix(&escape[2..], 16).expect("The escape is made of 4 hexadecimal digits");
+                    match (high_surrogate.take(), unit) {
+                        (Some((high, _)), 0xDC00..=0xDFFF) => {
+                            let code = 0x10000 + ((u32::from(high) - 0xD800) << 10) + (u32::from(unit) - 0xDC00);
+                            result.push(char::from_u32(code).expect("A surrogate pair is a valid character"));
+                        },
+                        (Some((_, high)), _)               => return Err(Error::from(InvalidEscape::new(String::from(line), String::from(high)))),
+                        (None, 0xD800..=0xDBFF)            => high_surrogate = Some((unit, escape)),
+                        (None, 0xDC00..=0xDFFF)            => return Err(Error::from(InvalidEscape::new(String::from(line), String::from(escape)))),
+                        (None, _)                          => result.push(char::from_u32(u32::from(unit)).expect("A unit which is not a surrogate is a valid character")),
+                    }
+                    continue;
+                },
+                Some((_, c))   => c,
+                None           => break,
+            }
+        } else {
+            c
+        };
+
+        if let Some((_, high)) = high_surrogate {
+            return Err(Error::from(InvalidEscape::new(String::from(line), String::from(high))));
+        }
+        result.push(c);
+    }
+
+    if let Some((_, high)) = high_surrogate {
+        return Err(Error::from(InvalidEscape::new(String::from(line), String::from(high))));
+    }
+    Ok(result)
+}
+
+
+/// A stated object, which from couples of [`Identifier`](../datas/struct.Identifier.html "datas::Identifier") and [`Value`](../datas/enum.Value.html "datas::Value"), creates the content of a `.properties` file
+/// 
+/// The key of an identifier with a section is `section.name`. The lines are written in the order they were dumped and only contain ASCII characters, the other ones being written as `\uXXXX` like `Properties.store` does in Java
+/// 
+/// # Example
+/// ```
+/// use mininip::datas::{Identifier, Value};
+/// use mininip::properties::PropertiesDumper;
+/// 
+/// let mut dumper = PropertiesDumper::new();
+/// dumper.dump(Identifier::new(Some(String::from("server")), String::from("port")), Value::Int(8080));
+/// dumper.dump(Identifier::new(None, String::from("motd")), Value::Str(String::from(" Hello = \u{263a}")));
+/// dumper.dump(Identifier::new(None, String::from("debug")), Value::Bool(false));
+/// 
+/// assert_eq!(dumper.generate(), "server.port=8080\nmotd=\\ Hello \\= \\u263A\ndebug=false\n");
+/// ```
+#[derive(Debug, Default)]
+pub struct PropertiesDumper {
+    /// The lines in the order they were dumped
+    lines: Vec<String>,
+}
+
+impl PropertiesDumper {
+    /// Creates a new `PropertiesDumper` object
+    pub fn new() -> PropertiesDumper {
+        PropertiesDumper {
+            lines: Vec::new(),
+        }
+    }
+
+    /// Dumps a couple [`Identifier`](../datas/struct.Identifier.html "datas::Identifier") / [`Value`](../datas/enum.Value.html "datas::Value") into `self`
+    /// 
+    /// A boolean is written `true` or `false` like in Java and any other value is written as its text
+    pub fn dump(&mut self, identifier: Identifier, value: Value) {
+        let key = match identifier.section() {
+            Some(section) => format!("{}.{}", section, identifier.name()),
+            None          => String::from(identifier.name()),
+        };
+        let value = match value {
+            Value::Bool(value) => value.to_string(),
+            value              => format!("{}", value),
+        };
+
+        self.lines.push(format!("{}={}", escape(&key, true), escape(&value, false)));
+    }
+
+    /// Generates a `String` containing the code of the `.properties` file stored in the `PropertiesDumper`
+    pub fn generate(self) -> String {
+        let mut result = String::new();
+        for line in self.lines {
+            result.push_str(&line);
+            result.push('\n');
+        }
+
+        result
+    }
+}
+
+/// Escapes `text` like `Properties.store` in Java. Every space of a key is escaped while only the leading ones of a value are
+fn escape(text: &str, key: bool) -> String {
+    let mut result = String::with_capacity(text.len());
+    for (n, c) in text.char_indices() {
+        match c {
+            ' ' if key || n == 0           => result.push_str("\\ "),
+            '\t'                           => result.push_str("\\t"),
+            '\n'                           => result.push_str("\\n"),
+            '\r'                           => result.push_str("\\r"),
+            '\x0c'                         => result.push_str("\\f"),
+            '\\' | '=' | ':' | '#' | '!'   => {
+                result.push('\\');
+                result.push(c);
+            },
+            ' '..='~'                      => result.push(c),
+            _                              => {
+                let mut units = [0; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    result.push_str(&format!("\\u{:04X}", unit));
+                }
+            },
+        }
+    }
+
+    result
+}
+
+/// Dumps a `HashMap<Identifier, Value>` into a `.properties` file, sorted by key
+/// 
+/// # Parameters
+/// `path` the path of the file (must be closed)
+/// 
+/// `data` the data to dump
+/// 
+/// # Return value
+/// Since any [`PropertiesDumper`](struct.PropertiesDumper.html "properties::PropertiesDumper") operation is infallible, it only returns an `io::Result<()>` which indicates a file manipulation error
+pub fn dump_into_file<T: AsRef<Path>>(path: T, data: HashMap<Identifier, Value>) -> io::Result<()> {
+    let mut file = File::create(path)?;
+
+    let mut data: Vec<(Identifier, Value)> = data.into_iter().collect();
+    data.sort_by(|(a, _), (b, _)| (a.section(), a.name()).cmp(&(b.section(), b.name())));
+
+    let mut dumper = PropertiesDumper::new();
+    for (k, v) in data {
+        dumper.dump(k, v);
+    }
+
+    file.write_all(dumper.generate().as_bytes())?;
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,124 @@
+use crate::datas::{Identifier, Value};
+use crate::dump::Dumper;
+use crate::errors::Error;
+use crate::parse::Parser;
+use crate::properties::*;
+use std::collections::HashMap;
+
+fn ident(name: &str) -> Identifier {
+    Identifier::new(None, String::from(name))
+}
+
+fn raw(text: &str) -> Value {
+    Value::Raw(String::from(text))
+}
+
+#[test]
+fn properties_fixture_sample() {
+    let data = parse_content(include_str!("../../fixtures/properties/sample.properties")).unwrap();
+
+    let mut expected = HashMap::new();
+    expected.insert(ident("server.port"), raw("9090"));
+    expected.insert(ident("server.host"), raw("localhost"));
+    expected.insert(ident("app.name"), raw("My Application"));
+    expected.insert(ident("app.motd"), raw("\u{a1}Hola! \u{1f600}"));
+    expected.insert(ident("path.windows"), raw("C:\\Program Files\\App"));
+    expected.insert(ident("colors"), raw("red,green,blue"));
+    expected.insert(ident("multi.line"), raw("first\nsecond"));
+    expected.insert(ident("escaped key"), raw("value with = and :"));
+    expected.insert(ident("empty.value"), raw(""));
+    expected.insert(ident("trailing.spaces"), raw("kept   "));
+    expected.insert(ident("indented.key"), raw("value"));
+    assert_eq!(data, expected);
+}
+
+#[test]
+fn properties_line_endings_and_continuations() {
+    let data = parse_content("a = 1\r\nb = 2\rc = x\\\r\n  y\nd = even\\\\\ne = last\\").unwrap();
+
+    assert_eq!(data[&ident("a")], raw("1"));
+    assert_eq!(data[&ident("b")], raw("2"));
+    assert_eq!(data[&ident("c")], raw("xy"));
+    assert_eq!(data[&ident("d")], raw("even\\"));
+    assert_eq!(data[&ident("e")], raw("last"));
+}
+
+#[test]
+fn properties_continuation_is_not_a_comment() {
+    let data = parse_content("a = b\\\n# not a comment\nc\n").unwrap();
+
+    assert_eq!(data[&ident("a")], raw("b# not a comment"));
+    assert_eq!(data[&ident("c")], raw(""));
+}
+
+#[test]
+fn properties_errors() {
+    match parse_content("a = \\u12") {
+        Err(Error::InvalidEscape(_)) => (),
+        other                        => panic!("Expected an InvalidEscape error, got {:?}", other),
+    }
+    match parse_content("a = \\ud83d alone") {
+        Err(Error::InvalidEscape(_)) => (),
+        other                        => panic!("Expected an InvalidEscape error, got {:?}", other),
+    }
+    match parse_content("a = \\ude00") {
+        Err(Error::InvalidEscape(_)) => (),
+        other                        => panic!("Expected an InvalidEscape error, got {:?}", other),
+    }
+    match parse_content("1st = value") {
+        Err(Error::InvalidIdentifier(_)) => (),
+        other                            => panic!("Expected an InvalidIdentifier error, got {:?}", other),
+    }
+    match parse_content("= value") {
+        Err(Error::ExpectedIdentifier(_)) => (),
+        other                             => panic!("Expected an ExpectedIdentifier error, got {:?}", other),
+    }
+}
+
+#[test]
+fn properties_dump_escapes() {
+    let mut dumper = PropertiesDumper::new();
+    dumper.dump(ident("my key"), raw("  two spaces"));
+    dumper.dump(ident("path"), raw("C:\\dir\t#1!\n\u{1f600}\u{1}"));
+
+    assert_eq!(dumper.generate(), "my\\ key=\\  two spaces\npath=C\\:\\\\dir\\t\\#1\\!\\n\\uD83D\\uDE00\\u0001\n");
+}
+
+#[test]
+fn properties_dump_reverses_parse() {
+    let data = parse_content(include_str!("../../fixtures/properties/sample.properties")).unwrap();
+
+    let mut dumper = PropertiesDumper::new();
+    for (ident, value) in data.clone() {
+        dumper.dump(ident, value);
+    }
+
+    assert_eq!(parse_content(&dumper.generate()).unwrap(), data);
+}
+
+#[test]
+fn properties_from_ini() {
+    let mut parser = Parser::new();
+    parser.parse_content("name = app\n[server]\nport = 8080\nsecure = on\n").unwrap();
+
+    let mut data: Vec<(Identifier, Value)> = parser.data().into_iter().collect();
+    data.sort_by(|(a, _), (b, _)| (a.section(), a.name()).cmp(&(b.section(), b.name())));
+    let mut dumper = PropertiesDumper::new();
+    for (ident, value) in data {
+        dumper.dump(ident, value);
+    }
+
+    assert_eq!(dumper.generate(), "name=app\nserver.port=8080\nserver.secure=true\n");
+}
+
+#[test]
+fn properties_to_ini() {
+    let data = parse_content("server.port=8080\nserver.host=localhost\n").unwrap();
+
+    let mut dumper = Dumper::new();
+    for (ident, value) in data {
+        dumper.dump(ident, value);
+    }
+
+    assert_eq!(dumper.generate(), "server.host=localhost\nserver.port=8080\n");
+}
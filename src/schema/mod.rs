@@ -0,0 +1,421 @@
+//! Declaration of the expected content of an INI file and validation of a `Tree` against it
+//! 
+//! # See
+//! `Schema` for the set of keys expected
+//! 
+//! `KeySchema` for the constraints on a single key
+//! 
+//! `Diagnostic` for a problem found while checking a `Tree`
+//! 
+//! # Example
+//! ```
+//! use mininip::datas::{Identifier, ValueType};
+//! use mininip::datas::tree::Tree;
+//! use mininip::parse::Parser;
+//! use mininip::schema::{DiagnosticKind, KeySchema, Schema};
+//! 
+//! let mut port = KeySchema::new(ValueType::Int);
+//! port.set_required(true);
+//! port.set_range(Some(1.0), Some(65535.0));
+//! 
+//! let mut schema = Schema::new();
+//! schema.add_key(Identifier::new(Some(String::from("server")), String::from("port")), port);
+//! 
+//! let mut parser = Parser::new();
+//! parser.parse_content("[server]\nport = 0\nprot = 80\n").unwrap();
+//! let locations = parser.locations().clone();
+//! let tree = Tree::from(parser.data());
+//! 
+//! let diagnostics = schema.check_located(&tree, &locations);
+//! assert_eq!(diagnostics.len(), 2);
+//! assert_eq!(diagnostics[0].line(), Some(2));
+//! assert_eq!(format!("{}", diagnostics[0]), "Value must be between 1 and 65535 at server.port, line 2");
+//! assert_eq!(diagnostics[1].kind(), &DiagnosticKind::UnknownKey { suggestion: Some(String::from("port")) });
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use crate::datas::{Identifier, Value, ValueType};
+use crate::datas::tree::Tree;
+
+/// The constraints on a single key
+/// 
+/// A key is optional and accepts any value of its type by default
+#[derive(Debug, Clone)]
+pub struct KeySchema {
+    value_type: ValueType,
+    required: bool,
+    default: Option<Value>,
+    min: Option<f64>,
+    max: Option<f64>,
+    choices: Vec<String>,
+    #[cfg(feature = "regex")]
+    pattern: Option<regex::Regex>,
+}
+
+impl KeySchema {
+    /// Creates a new `KeySchema` for an optional key of type `value_type`
+    /// 
+    /// # Notes
+    /// `ValueType::Raw` and `ValueType::Str` both accept a `Raw` and a `Str` value while `ValueType::Float` also accepts an `Int` value
+    pub fn new(value_type: ValueType) -> KeySchema {
+        KeySchema {
+            value_type,
+            required: false,
+            default: None,
+            min: None,
+            max: None,
+            choices: Vec::new(),
+            #[cfg(feature = "regex")]
+            pattern: None,
+        }
+    }
+
+    /// Returns the type expected
+    pub fn value_type(&self) -> ValueType {
+        self.value_type
+    }
+
+    /// Returns `true` if the key must be defined
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+
+    /// Returns the value used when the key is not defined
+    pub fn default(&self) -> Option<&Value> {
+        self.default.as_ref()
+    }
+
+    /// Makes the key required or optional. A required key with a default value is never missing
+    pub fn set_required(&mut self, required: bool) {
+        self.required = required;
+    }
+
+    /// Changes the value used when the key is not defined (see [`Schema::with_defaults`](struct.Schema.html#method.with_defaults "schema::Schema::with_defaults"))
+    /// 
+    /// # Panics
+    /// Panics if `default` is not accepted by the type expected
+    pub fn set_default(&mut self, default: Value) {
+        assert!(accepts(self.value_type, &default), "The default value must be of type {}", self.value_type);
+
+        self.default = Some(default);
+    }
+
+    /// Restricts an `Int` or a `Float` value to the range `min..=max`. Both bounds are optional
+    pub fn set_range(&mut self, min: Option<f64>, max: Option<f64>) {
+        self.min = min;
+        self.max = max;
+    }
+
+    /// Restricts a `Raw` or a `Str` value to the strings in `choices`. An empty list allows any string
+    pub fn set_choices(&mut self, choices: Vec<String>) {
+        self.choices = choices;
+    }
+
+    /// Requires a `Raw` or a `Str` value to fully match the regular expression `pattern`
+    /// 
+    /// # Return value
+    /// `Ok(())` in case of success
+    /// 
+    /// `Err(error)` if `pattern` is not a valid regular expression
+    #[cfg(feature = "regex")]
+    pub fn set_pattern(&mut self, pattern: &str) -> Result<(), regex::Error> {
+        self.pattern = Some(regex::Regex::new(&format!("^(?:{})$", pattern))?);
+        Ok(())
+    }
+
+    /// Checks `value` against the constraints of `self`
+    fn check(&self, value: &Value) -> Option<DiagnosticKind> {
+        if !accepts(self.value_type, value) {
+            return Some(DiagnosticKind::WrongType {
+                expected: self.value_type,
+                found: value.value_type(),
+            });
+        }
+
+        let number = match value {
+            Value::Int(value)   => Some(*value as f64),
+            Value::Float(value) => Some(*value),
+            _                   => None,
+        };
+        if let Some(number) = number {
+            if self.min.is_some_and(|min| number < min) || self.max.is_some_and(|max| number > max) {
+                return Some(DiagnosticKind::OutOfRange {
+                    min: self.min,
+                    max: self.max,
+                });
+            }
+        }
+
+        if let Value::Raw(string) | Value::Str(string) = value {
+            if !self.choices.is_empty() && !self.choices.contains(string) {
+                return Some(DiagnosticKind::NotAllowed {
+                    allowed: self.choices.clone(),
+                });
+            }
+
+            #[cfg(feature = "regex")]
+            {
+                if let Some(pattern) = &self.pattern {
+                    if !pattern.is_match(string) {
+                        let pattern = pattern.as_str();
+                        return Some(DiagnosticKind::PatternMismatch {
+                            pattern: String::from(&pattern[4..pattern.len() - 2]),
+                        });
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+
+/// The set of keys expected in an INI file
+/// 
+/// A section is known if at least one of its keys is declared. The global section is always known
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    keys: HashMap<Identifier, KeySchema>,
+}
+
+impl Schema {
+    /// Creates an empty `Schema`
+    pub fn new() -> Schema {
+        Schema {
+            keys: HashMap::new(),
+        }
+    }
+
+    /// Declares the key `ident` with its constraints. A key declared twice is overwritten
+    pub fn add_key(&mut self, ident: Identifier, key: KeySchema) {
+        self.keys.insert(ident, key);
+    }
+
+    /// Returns the constraints on the key `ident` if it is declared
+    pub fn get_key(&self, ident: &Identifier) -> Option<&KeySchema> {
+        self.keys.get(ident)
+    }
+
+    /// Returns the type of each key declared, to be forced while parsing (see [`Parser::set_type_hints`](../parse/struct.Parser.html#method.set_type_hints "parse::Parser::set_type_hints"))
+    pub fn type_hints(&self) -> HashMap<Identifier, ValueType> {
+        self.keys.iter()
+                 .map(|(ident, key)| (ident.clone(), key.value_type))
+                 .collect()
+    }
+
+    /// Checks `tree` against `self`
+    /// 
+    /// # Return value
+    /// The list of the problems found, which is empty if `tree` is valid. The diagnostics do not have any line number
+    pub fn check(&self, tree: &Tree) -> Vec<Diagnostic> {
+        self.check_located(tree, &HashMap::new())
+    }
+
+    /// Checks `tree` against `self`, using `locations` (see [`Parser::locations`](../parse/struct.Parser.html#method.locations "parse::Parser::locations")) to locate the problems
+    /// 
+    /// # Return value
+    /// The list of the problems found sorted by line, which is empty if `tree` is valid
+    pub fn check_located(&self, tree: &Tree, locations: &HashMap<Identifier, usize>) -> Vec<Diagnostic> {
+        let data = tree.get_data();
+        let mut diagnostics = Vec::new();
+
+        for (ident, key) in &self.keys {
+            let kind = match data.get(ident) {
+                Some(value)                                         => key.check(value),
+                None if key.is_required() && key.default.is_none() => Some(DiagnosticKind::MissingKey),
+                None                                                => None,
+            };
+
+            if let Some(kind) = kind {
+                diagnostics.push(Diagnostic::new(ident.clone(), locations.get(ident).copied(), kind));
+            }
+        }
+
+        for ident in data.keys().filter(|ident| !self.keys.contains_key(ident)) {
+            let section = ident.section();
+            let kind = if section.is_none() || self.keys.keys().any(|i| i.section() == section) {
+                let names = self.keys.keys()
+                                     .filter(|i| i.section() == section)
+                                     .map(Identifier::name);
+
+                DiagnosticKind::UnknownKey {
+                    suggestion: closest(ident.name(), names),
+                }
+            } else {
+                let sections = self.keys.keys()
+                                        .filter_map(Identifier::section);
+
+                DiagnosticKind::UnknownSection {
+                    suggestion: closest(section.unwrap_or_default(), sections),
+                }
+            };
+
+            diagnostics.push(Diagnostic::new(ident.clone(), locations.get(ident).copied(), kind));
+        }
+
+        diagnostics.sort_by(|a, b| (a.line, format!("{}", a.identifier)).cmp(&(b.line, format!("{}", b.identifier))));
+        diagnostics
+    }
+
+    /// Returns `tree` with the default value of each key not defined
+    pub fn with_defaults(&self, tree: Tree) -> Tree {
+        let mut data = tree.into_data();
+        for (ident, key) in &self.keys {
+            if let Some(default) = &key.default {
+                data.entry(ident.clone()).or_insert_with(|| default.clone());
+            }
+        }
+
+        Tree::from(data)
+    }
+}
+
+
+/// A problem found while checking a `Tree` against a [`Schema`](struct.Schema.html "schema::Schema")
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    identifier: Identifier,
+    line: Option<usize>,
+    kind: DiagnosticKind,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at {}", self.kind, self.identifier)?;
+
+        match self.line {
+            Some(line) => write!(f, ", line {}", line),
+            None       => Ok(()),
+        }
+    }
+}
+
+impl Diagnostic {
+    /// Creates a new `Diagnostic`
+    pub fn new(identifier: Identifier, line: Option<usize>, kind: DiagnosticKind) -> Diagnostic {
+        Diagnostic {
+            identifier,
+            line,
+            kind,
+        }
+    }
+
+    /// Returns the identifier of the key concerned
+    pub fn identifier(&self) -> &Identifier {
+        &self.identifier
+    }
+
+    /// Returns the line of the key concerned (starting from 1) if it is known
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// Returns the problem found
+    pub fn kind(&self) -> &DiagnosticKind {
+        &self.kind
+    }
+}
+
+/// The problem described by a [`Diagnostic`](struct.Diagnostic.html "schema::Diagnostic")
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiagnosticKind {
+    /// A required key is not defined
+    MissingKey,
+    /// The value is not of the type expected
+    WrongType {
+        expected: ValueType,
+        found: ValueType,
+    },
+    /// The number is out of the range `min..=max`
+    OutOfRange {
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    /// The string is not one of the allowed ones
+    NotAllowed {
+        allowed: Vec<String>,
+    },
+    /// The string does not match the regular expression `pattern`
+    PatternMismatch {
+        pattern: String,
+    },
+    /// The key is not declared in a known section. `suggestion` is the closest key name declared in this section
+    UnknownKey {
+        suggestion: Option<String>,
+    },
+    /// The section is not known. `suggestion` is the closest section name declared
+    UnknownSection {
+        suggestion: Option<String>,
+    },
+}
+
+impl Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DiagnosticKind::MissingKey                    => write!(f, "Missing required key"),
+            DiagnosticKind::WrongType { expected, found } => write!(f, "Expected a value of type {} but found a value of type {}", expected, found),
+            DiagnosticKind::OutOfRange { min, max }       => match (min, max) {
+                (Some(min), Some(max)) => write!(f, "Value must be between {} and {}", min, max),
+                (Some(min), None)      => write!(f, "Value must be at least {}", min),
+                (None, Some(max))      => write!(f, "Value must be at most {}", max),
+                (None, None)           => write!(f, "Value out of range"),
+            },
+            DiagnosticKind::NotAllowed { allowed }        => write!(f, "Value must be one of {}", allowed.join(", ")),
+            DiagnosticKind::PatternMismatch { pattern }   => write!(f, "Value does not match the pattern {}", pattern),
+            DiagnosticKind::UnknownKey { suggestion }     => match suggestion {
+                Some(suggestion) => write!(f, "Unknown key, did you mean {}?", suggestion),
+                None             => write!(f, "Unknown key"),
+            },
+            DiagnosticKind::UnknownSection { suggestion } => match suggestion {
+                Some(suggestion) => write!(f, "Unknown section, did you mean [{}]?", suggestion),
+                None             => write!(f, "Unknown section"),
+            },
+        }
+    }
+}
+
+
+/// Returns `true` if a key of type `expected` accepts `value`
+fn accepts(expected: ValueType, value: &Value) -> bool {
+    match (expected, value.value_type()) {
+        (ValueType::Raw, ValueType::Str)
+        | (ValueType::Str, ValueType::Raw)
+        | (ValueType::Float, ValueType::Int) => true,
+        (expected, found)                    => expected == found,
+    }
+}
+
+/// Returns the candidate closest to `target` if it is close enough to be a typo
+fn closest<'a, T: Iterator<Item = &'a str>>(target: &str, candidates: T) -> Option<String> {
+    let target = target.to_lowercase();
+    let threshold = target.chars().count().div_ceil(3);
+
+    candidates.map(|candidate| (levenshtein(&target, &candidate.to_lowercase()), candidate))
+              .filter(|(distance, _)| *distance <= threshold)
+              .min()
+              .map(|(_, candidate)| String::from(candidate))
+}
+
+/// Returns the number of insertions, deletions and substitutions of characters needed to turn `a` into `b`
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + if ca == *cb { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,127 @@
+use crate::datas::{Identifier, Value, ValueType};
+use crate::datas::tree::Tree;
+use crate::parse::Parser;
+use crate::schema::*;
+use std::collections::HashMap;
+
+fn ident(section: Option<&str>, name: &str) -> Identifier {
+    Identifier::new(section.map(String::from), String::from(name))
+}
+
+fn parse(content: &str) -> (Tree, HashMap<Identifier, usize>) {
+    let mut parser = Parser::new();
+    parser.parse_content(content).unwrap();
+    let locations = parser.locations().clone();
+
+    (Tree::from(parser.data()), locations)
+}
+
+fn schema() -> Schema {
+    let mut schema = Schema::new();
+
+    let mut name = KeySchema::new(ValueType::Str);
+    name.set_required(true);
+    schema.add_key(ident(None, "name"), name);
+
+    let mut port = KeySchema::new(ValueType::Int);
+    port.set_range(Some(1.0), Some(65535.0));
+    port.set_default(Value::Int(80));
+    schema.add_key(ident(Some("server"), "port"), port);
+
+    let mut level = KeySchema::new(ValueType::Raw);
+    level.set_choices(vec![String::from("debug"), String::from("info")]);
+    schema.add_key(ident(Some("log"), "level"), level);
+
+    schema.add_key(ident(Some("log"), "ratio"), KeySchema::new(ValueType::Float));
+
+    schema
+}
+
+#[test]
+fn valid_tree() {
+    let (tree, _) = parse("name = demo\n[server]\nport = 8080\n[log]\nlevel = info\nratio = 1\n");
+
+    assert_eq!(schema().check(&tree), vec![]);
+}
+
+#[test]
+fn missing_key() {
+    let (tree, _) = parse("[server]\n");
+
+    assert_eq!(schema().check(&tree), vec![Diagnostic::new(ident(None, "name"), None, DiagnosticKind::MissingKey)]);
+}
+
+#[test]
+fn wrong_type() {
+    let (tree, locations) = parse("name = 12\n");
+    let diagnostics = schema().check_located(&tree, &locations);
+
+    assert_eq!(diagnostics, vec![Diagnostic::new(ident(None, "name"), Some(1), DiagnosticKind::WrongType {
+        expected: ValueType::Str,
+        found: ValueType::Int,
+    })]);
+    assert_eq!(format!("{}", diagnostics[0]), "Expected a value of type Str but found a value of type Int at name, line 1");
+}
+
+#[test]
+fn out_of_range_and_not_allowed() {
+    let (tree, locations) = parse("name = demo\n[server]\nport = 70000\n[log]\nlevel = verbose\n");
+    let diagnostics = schema().check_located(&tree, &locations);
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(format!("{}", diagnostics[0]), "Value must be between 1 and 65535 at server.port, line 3");
+    assert_eq!(format!("{}", diagnostics[1]), "Value must be one of debug, info at log.level, line 5");
+}
+
+#[test]
+fn unknown_keys_and_sections() {
+    let (tree, locations) = parse("name = demo\nnmae = typo\n[server]\nprot = 1\n[srever]\nport = 1\n[other]\nkey = 1\n");
+    let diagnostics = schema().check_located(&tree, &locations);
+
+    let kinds: Vec<_> = diagnostics.iter().map(Diagnostic::kind).cloned().collect();
+    assert_eq!(kinds, vec![
+        DiagnosticKind::UnknownKey { suggestion: Some(String::from("name")) },
+        DiagnosticKind::UnknownKey { suggestion: Some(String::from("port")) },
+        DiagnosticKind::UnknownSection { suggestion: Some(String::from("server")) },
+        DiagnosticKind::UnknownSection { suggestion: None },
+    ]);
+    assert_eq!(format!("{}", diagnostics[2]), "Unknown section, did you mean [server]? at srever.port, line 6");
+}
+
+#[test]
+fn with_defaults() {
+    let (tree, _) = parse("name = demo\n");
+    let tree = schema().with_defaults(tree);
+
+    assert_eq!(tree.get_data()[&ident(Some("server"), "port")], Value::Int(80));
+}
+
+#[test]
+#[should_panic]
+fn default_of_wrong_type() {
+    KeySchema::new(ValueType::Int).set_default(Value::Bool(true));
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn pattern() {
+    let mut schema = Schema::new();
+    let mut version = KeySchema::new(ValueType::Str);
+    version.set_pattern(r"\d+\.\d+").unwrap();
+    schema.add_key(ident(None, "version"), version);
+
+    let (tree, _) = parse("version = '1.10'\n");
+    assert_eq!(schema.check(&tree), vec![]);
+
+    let (tree, _) = parse("version = 'v1.10'\n");
+    assert_eq!(schema.check(&tree)[0].kind(), &DiagnosticKind::PatternMismatch { pattern: String::from(r"\d+\.\d+") });
+}
+
+#[test]
+fn type_hints() {
+    let hints = schema().type_hints();
+
+    assert_eq!(hints.len(), 4);
+    assert_eq!(hints[&ident(None, "name")], ValueType::Str);
+    assert_eq!(hints[&ident(Some("log"), "ratio")], ValueType::Float);
+}
@@ -0,0 +1,715 @@
+//! Serializes any type implementing [`serde::Serialize`](https://docs.rs/serde "serde") into INI data. Requires the `serde` feature
+//! 
+//! # Mapping
+//! - the top-level value must be a structure or a map
+//! - its scalar fields are written in the global section
+//! - its fields which are structures or maps become named sections, which may only contain scalars
+//! - a `None` field is not written at all
+//! - a unit enum variant is written as its name
+//! - a sequence of scalars is written as a comma-separated list, where the strings are between single quotes
+//! 
+//! Any other shape (a structure nested more than once, a map of maps, a sequence of structures, an enum variant with data...) is rejected with an error
+//! 
+//! # Example
+//! ```
+//! use serde::Serialize;
+//! 
+//! #[derive(Serialize)]
+//! struct Config {
+//!     name: String,
+//!     server: Server,
+//! }
+//! 
+//! #[derive(Serialize)]
+//! struct Server {
+//!     port: u16,
+//!     hosts: Vec<&'static str>,
+//!     verbose: Option<bool>,
+//! }
+//! 
+//! let config = Config {
+//!     name: String::from("main"),
+//!     server: Server {
+//!         port: 8080,
+//!         hosts: vec!["localhost", "example.com"],
+//!         verbose: None,
+//!     },
+//! };
+//! 
+//! let expected = "\
+//! name='main'\n\
+//! \n\
+//! [server]\n\
+//! hosts=\\'localhost\\', \\'example.com\\'\n\
+//! port=8080\n";
+//! assert_eq!(mininip::to_string(&config).unwrap(), expected);
+//! ```
+
+use std::convert::TryFrom;
+use std::error;
+use std::fmt::{self, Display};
+use std::io::{self, Write};
+use serde::ser::{self, Impossible, Serialize};
+use crate::datas::{self, Identifier, Value};
+use crate::dump::Dumper;
+
+/// An error happening while serializing a value into INI data
+#[derive(Debug)]
+pub struct Error {
+    message: String,
+}
+
+impl error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Error {
+        Error {
+            message: format!("{}", msg),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        <Error as ser::Error>::custom(err)
+    }
+}
+
+impl Error {
+    /// Returns the message describing the error
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Creates an error telling a value of kind `what` can't be written at `place`
+    fn unsupported(what: &str, place: &str) -> Error {
+        <Error as ser::Error>::custom(format!("{} can't be represented {}", what, place))
+    }
+}
+
+
+/// Serializes `value` into a `String` of INI data
+/// 
+/// # Return value
+/// `Ok(string)` in case of success
+/// 
+/// `Err(error)` if `value` can't be represented as INI data
+pub fn to_string<T: Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+    let mut dumper = Dumper::new();
+    value.serialize(TopSerializer { dumper: &mut dumper })?;
+
+    Ok(dumper.generate())
+}
+
+/// Serializes `value` as INI data into `writer`, such as a file
+/// 
+/// # Return value
+/// `Ok(())` in case of success
+/// 
+/// `Err(error)` if `value` can't be represented as INI data or if writing fails
+pub fn to_writer<W: Write, T: Serialize + ?Sized>(mut writer: W, value: &T) -> Result<(), Error> {
+    let content = to_string(value)?;
+    writer.write_all(content.as_bytes())?;
+
+    Ok(())
+}
+
+
+/// Checks `key` is a valid identifier (see [`Identifier::is_valid`](../datas/struct.Identifier.html#method.is_valid "datas::Identifier::is_valid"))
+fn check_identifier(key: &str) -> Result<(), Error> {
+    if Identifier::is_valid(key) {
+        Ok(())
+    } else {
+        Err(<Error as ser::Error>::custom(format!("{:?} is not a valid INI identifier", key)))
+    }
+}
+
+/// Implements the methods of `serde::Serializer` rejecting their value with `Error::unsupported`
+macro_rules! reject {
+    ($place:expr; $($method:ident($($arg:ident: $type:ty),*) -> $ret:ty = $what:expr;)*) => {
+        $(
+            fn $method(self, $(_: $type),*) -> Result<$ret, Error> {
+                Err(Error::unsupported($what, &$place(&self)))
+            }
+        )*
+    };
+}
+
+
+/// Serializes the top-level value, which must be a structure or a map
+struct TopSerializer<'a> {
+    dumper: &'a mut Dumper,
+}
+
+/// Returns the place of the top-level value for the error messages
+fn top_place(_: &TopSerializer) -> String {
+    String::from("at the top level (expected a structure or a map)")
+}
+
+impl<'a> ser::Serializer for TopSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Impossible<(), Error>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = TopMapSerializer<'a>;
+    type SerializeStruct = TopMapSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<TopMapSerializer<'a>, Error> {
+        Ok(TopMapSerializer {
+            dumper: self.dumper,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<TopMapSerializer<'a>, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _name: &'static str, _index: u32, _variant: &'static str, _value: &T) -> Result<(), Error> {
+        Err(Error::unsupported("An enum variant", &top_place(&self)))
+    }
+
+    reject! { top_place;
+        serialize_bool(v: bool) -> () = "A boolean";
+        serialize_i8(v: i8) -> () = "An integer";
+        serialize_i16(v: i16) -> () = "An integer";
+        serialize_i32(v: i32) -> () = "An integer";
+        serialize_i64(v: i64) -> () = "An integer";
+        serialize_u8(v: u8) -> () = "An integer";
+        serialize_u16(v: u16) -> () = "An integer";
+        serialize_u32(v: u32) -> () = "An integer";
+        serialize_u64(v: u64) -> () = "An integer";
+        serialize_f32(v: f32) -> () = "A float";
+        serialize_f64(v: f64) -> () = "A float";
+        serialize_char(v: char) -> () = "A character";
+        serialize_str(v: &str) -> () = "A string";
+        serialize_bytes(v: &[u8]) -> () = "A byte array";
+        serialize_none() -> () = "None";
+        serialize_unit() -> () = "A unit";
+        serialize_unit_struct(name: &'static str) -> () = "A unit structure";
+        serialize_unit_variant(name: &'static str, index: u32, variant: &'static str) -> () = "An enum variant";
+        serialize_seq(len: Option<usize>) -> Impossible<(), Error> = "A sequence";
+        serialize_tuple(len: usize) -> Impossible<(), Error> = "A tuple";
+        serialize_tuple_struct(name: &'static str, len: usize) -> Impossible<(), Error> = "A tuple structure";
+        serialize_tuple_variant(name: &'static str, index: u32, variant: &'static str, len: usize) -> Impossible<(), Error> = "An enum variant";
+        serialize_struct_variant(name: &'static str, index: u32, variant: &'static str, len: usize) -> Impossible<(), Error> = "An enum variant";
+    }
+}
+
+/// Serializes the fields of the top-level structure or map
+struct TopMapSerializer<'a> {
+    dumper: &'a mut Dumper,
+    /// The key of the next value when serializing a map
+    key: Option<String>,
+}
+
+impl<'a> ser::SerializeMap for TopMapSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(key.serialize(KeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self.key.take().expect("`serialize_value` must be called after `serialize_key`");
+
+        value.serialize(EntrySerializer {
+            dumper: self.dumper,
+            section: None,
+            key,
+        })
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeStruct for TopMapSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Error> {
+        ser::SerializeMap::serialize_entry(self, key, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+
+/// Serializes the key of a map, which must be a string
+struct KeySerializer;
+
+/// Returns the place of a key for the error messages
+fn key_place(_: &KeySerializer) -> String {
+    String::from("as a key (expected a string)")
+}
+
+impl ser::Serializer for KeySerializer {
+    type Ok = String;
+    type Error = Error;
+    type SerializeSeq = Impossible<String, Error>;
+    type SerializeTuple = Impossible<String, Error>;
+    type SerializeTupleStruct = Impossible<String, Error>;
+    type SerializeTupleVariant = Impossible<String, Error>;
+    type SerializeMap = Impossible<String, Error>;
+    type SerializeStruct = Impossible<String, Error>;
+    type SerializeStructVariant = Impossible<String, Error>;
+
+    fn serialize_str(self, v: &str) -> Result<String, Error> {
+        check_identifier(v)?;
+        Ok(String::from(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<String, Error> {
+        self.serialize_str(&format!("{}", v))
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<String, Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<String, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<String, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _name: &'static str, _index: u32, _variant: &'static str, _value: &T) -> Result<String, Error> {
+        Err(Error::unsupported("An enum variant with data", &key_place(&self)))
+    }
+
+    reject! { key_place;
+        serialize_bool(v: bool) -> String = "A boolean";
+        serialize_i8(v: i8) -> String = "An integer";
+        serialize_i16(v: i16) -> String = "An integer";
+        serialize_i32(v: i32) -> String = "An integer";
+        serialize_i64(v: i64) -> String = "An integer";
+        serialize_u8(v: u8) -> String = "An integer";
+        serialize_u16(v: u16) -> String = "An integer";
+        serialize_u32(v: u32) -> String = "An integer";
+        serialize_u64(v: u64) -> String = "An integer";
+        serialize_f32(v: f32) -> String = "A float";
+        serialize_f64(v: f64) -> String = "A float";
+        serialize_bytes(v: &[u8]) -> String = "A byte array";
+        serialize_none() -> String = "None";
+        serialize_unit() -> String = "A unit";
+        serialize_unit_struct(name: &'static str) -> String = "A unit structure";
+        serialize_seq(len: Option<usize>) -> Impossible<String, Error> = "A sequence";
+        serialize_tuple(len: usize) -> Impossible<String, Error> = "A tuple";
+        serialize_tuple_struct(name: &'static str, len: usize) -> Impossible<String, Error> = "A tuple structure";
+        serialize_tuple_variant(name: &'static str, index: u32, variant: &'static str, len: usize) -> Impossible<String, Error> = "An enum variant";
+        serialize_map(len: Option<usize>) -> Impossible<String, Error> = "A map";
+        serialize_struct(name: &'static str, len: usize) -> Impossible<String, Error> = "A structure";
+        serialize_struct_variant(name: &'static str, index: u32, variant: &'static str, len: usize) -> Impossible<String, Error> = "An enum variant";
+    }
+}
+
+
+/// Serializes the value of a key. It becomes a section if it is a structure or a map in the global section
+struct EntrySerializer<'a> {
+    dumper: &'a mut Dumper,
+    /// The section of the key or `None` for the global section
+    section: Option<String>,
+    key: String,
+}
+
+impl<'a> EntrySerializer<'a> {
+    /// Dumps `value` as the value of this entry
+    fn dump(self, value: Value) -> Result<(), Error> {
+        check_identifier(&self.key)?;
+
+        self.dumper.dump(Identifier::new(self.section, self.key), value);
+        Ok(())
+    }
+
+    /// Returns the identifier of the entry for the error messages
+    fn place(&self) -> String {
+        match &self.section {
+            Some(section) => format!("at {}.{}", section, self.key),
+            None          => format!("at {}", self.key),
+        }
+    }
+}
+
+/// Returns the place of an entry for the error messages
+fn entry_place(entry: &EntrySerializer) -> String {
+    entry.place()
+}
+
+impl<'a> ser::Serializer for EntrySerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = ListSerializer<'a>;
+    type SerializeTuple = ListSerializer<'a>;
+    type SerializeTupleStruct = ListSerializer<'a>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = SectionSerializer<'a>;
+    type SerializeStruct = SectionSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), Error> {
+        self.dump(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), Error> {
+        self.dump(Value::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), Error> {
+        self.dump(Value::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), Error> {
+        self.dump(Value::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), Error> {
+        self.dump(Value::from(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), Error> {
+        self.dump(Value::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), Error> {
+        self.dump(Value::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), Error> {
+        self.dump(Value::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), Error> {
+        match i64::try_from(v) {
+            Ok(v)  => self.dump(Value::Int(v)),
+            Err(_) => Err(Error::unsupported("An integer bigger than 2^63 - 1", &self.place())),
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), Error> {
+        self.dump(Value::from(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), Error> {
+        self.dump(Value::from(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), Error> {
+        self.dump(Value::Str(format!("{}", v)))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        self.dump(Value::from(v))
+    }
+
+    fn serialize_none(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<(), Error> {
+        self.dump(Value::Raw(String::from(variant)))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _name: &'static str, _index: u32, _variant: &'static str, _value: &T) -> Result<(), Error> {
+        Err(Error::unsupported("An enum variant with data", &self.place()))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<ListSerializer<'a>, Error> {
+        Ok(ListSerializer {
+            entry: self,
+            items: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<ListSerializer<'a>, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<ListSerializer<'a>, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SectionSerializer<'a>, Error> {
+        if self.section.is_some() {
+            return Err(Error::unsupported("A structure or a map nested in a section", &self.place()));
+        }
+        check_identifier(&self.key)?;
+
+        Ok(SectionSerializer {
+            dumper: self.dumper,
+            section: self.key,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SectionSerializer<'a>, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    reject! { entry_place;
+        serialize_bytes(v: &[u8]) -> () = "A byte array";
+        serialize_unit() -> () = "A unit";
+        serialize_unit_struct(name: &'static str) -> () = "A unit structure";
+        serialize_tuple_variant(name: &'static str, index: u32, variant: &'static str, len: usize) -> Impossible<(), Error> = "An enum variant with data";
+        serialize_struct_variant(name: &'static str, index: u32, variant: &'static str, len: usize) -> Impossible<(), Error> = "An enum variant with data";
+    }
+}
+
+
+/// Serializes the keys of a named section
+struct SectionSerializer<'a> {
+    dumper: &'a mut Dumper,
+    section: String,
+    /// The key of the next value when serializing a map
+    key: Option<String>,
+}
+
+impl<'a> ser::SerializeMap for SectionSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(key.serialize(KeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self.key.take().expect("`serialize_value` must be called after `serialize_key`");
+
+        value.serialize(EntrySerializer {
+            dumper: self.dumper,
+            section: Some(self.section.clone()),
+            key,
+        })
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeStruct for SectionSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Error> {
+        ser::SerializeMap::serialize_entry(self, key, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+
+/// Serializes a sequence of scalars as a comma-separated list
+struct ListSerializer<'a> {
+    entry: EntrySerializer<'a>,
+    items: Vec<String>,
+}
+
+impl<'a> ListSerializer<'a> {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let item = value.serialize(ItemSerializer)
+                        .map_err(|err| <Error as ser::Error>::custom(format!("{} {}", err, self.entry.place())))?;
+        self.items.push(item);
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), Error> {
+        let list = self.items.join(", ");
+        self.entry.dump(Value::Raw(list))
+    }
+}
+
+impl<'a> ser::SerializeSeq for ListSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeTuple for ListSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeTupleStruct for ListSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.finish()
+    }
+}
+
+
+/// Serializes an item of a comma-separated list, which must be a scalar
+struct ItemSerializer;
+
+/// Returns the place of an item for the error messages
+fn item_place(_: &ItemSerializer) -> String {
+    String::from("in a list")
+}
+
+impl ItemSerializer {
+    /// Writes `value` as an item of a list
+    fn item(value: Value) -> Result<String, Error> {
+        match datas::format_list_item(&value) {
+            Some(item) => Ok(item),
+            None       => Err(Error::unsupported(&format!("The item {:?} which contains a comma", format!("{}", value)), "in a list")),
+        }
+    }
+}
+
+impl ser::Serializer for ItemSerializer {
+    type Ok = String;
+    type Error = Error;
+    type SerializeSeq = Impossible<String, Error>;
+    type SerializeTuple = Impossible<String, Error>;
+    type SerializeTupleStruct = Impossible<String, Error>;
+    type SerializeTupleVariant = Impossible<String, Error>;
+    type SerializeMap = Impossible<String, Error>;
+    type SerializeStruct = Impossible<String, Error>;
+    type SerializeStructVariant = Impossible<String, Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<String, Error> {
+        ItemSerializer::item(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<String, Error> {
+        ItemSerializer::item(Value::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<String, Error> {
+        ItemSerializer::item(Value::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<String, Error> {
+        ItemSerializer::item(Value::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<String, Error> {
+        ItemSerializer::item(Value::from(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<String, Error> {
+        ItemSerializer::item(Value::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<String, Error> {
+        ItemSerializer::item(Value::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<String, Error> {
+        ItemSerializer::item(Value::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<String, Error> {
+        match i64::try_from(v) {
+            Ok(v)  => ItemSerializer::item(Value::Int(v)),
+            Err(_) => Err(Error::unsupported("An integer bigger than 2^63 - 1", &item_place(&self))),
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<String, Error> {
+        ItemSerializer::item(Value::from(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<String, Error> {
+        ItemSerializer::item(Value::from(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<String, Error> {
+        ItemSerializer::item(Value::Str(format!("{}", v)))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<String, Error> {
+        ItemSerializer::item(Value::Str(String::from(v)))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<String, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<String, Error> {
+        Ok(String::from(variant))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<String, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _name: &'static str, _index: u32, _variant: &'static str, _value: &T) -> Result<String, Error> {
+        Err(Error::unsupported("An enum variant with data", &item_place(&self)))
+    }
+
+    reject! { item_place;
+        serialize_bytes(v: &[u8]) -> String = "A byte array";
+        serialize_none() -> String = "None";
+        serialize_unit() -> String = "A unit";
+        serialize_unit_struct(name: &'static str) -> String = "A unit structure";
+        serialize_seq(len: Option<usize>) -> Impossible<String, Error> = "A sequence";
+        serialize_tuple(len: usize) -> Impossible<String, Error> = "A tuple";
+        serialize_tuple_struct(name: &'static str, len: usize) -> Impossible<String, Error> = "A tuple structure";
+        serialize_tuple_variant(name: &'static str, index: u32, variant: &'static str, len: usize) -> Impossible<String, Error> = "An enum variant with data";
+        serialize_map(len: Option<usize>) -> Impossible<String, Error> = "A map";
+        serialize_struct(name: &'static str, len: usize) -> Impossible<String, Error> = "A structure";
+        serialize_struct_variant(name: &'static str, index: u32, variant: &'static str, len: usize) -> Impossible<String, Error> = "An enum variant with data";
+    }
+}
+
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,148 @@
+use crate::ser::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Level {
+    Debug,
+    Info,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct Config {
+    name: String,
+    level: Level,
+    retries: Option<u8>,
+    log: Log,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct Log {
+    path: String,
+    verbose: bool,
+    ratio: f64,
+    levels: Vec<Level>,
+}
+
+fn config() -> Config {
+    Config {
+        name: String::from("main; \u{263a}"),
+        level: Level::Info,
+        retries: None,
+        log: Log {
+            path: String::from("/var/log/main.log"),
+            verbose: true,
+            ratio: 0.5,
+            levels: vec![Level::Debug, Level::Info],
+        },
+    }
+}
+
+#[test]
+fn to_string_layout() {
+    let expected = "\
+    level=info\n\
+    name='main\\; \\x00263a'\n\
+    \n\
+    [log]\n\
+    levels=debug, info\n\
+    path='/var/log/main.log'\n\
+    ratio=0.5\n\
+    verbose=on\n";
+
+    assert_eq!(to_string(&config()).unwrap(), expected);
+}
+
+#[test]
+fn to_string_round_trip() {
+    let dumped = to_string(&config()).unwrap();
+
+    assert_eq!(crate::from_str::<Config>(&dumped).unwrap(), config());
+}
+
+#[test]
+fn to_string_round_trip_string_list() {
+    let mut map = BTreeMap::new();
+    map.insert("hosts", vec!["localhost", "yes", "42", "1.5"]);
+
+    let dumped = to_string(&map).unwrap();
+
+    assert_eq!(crate::from_str::<BTreeMap<String, Vec<String>>>(&dumped).unwrap()["hosts"], ["localhost", "yes", "42", "1.5"]);
+}
+
+#[test]
+fn to_string_map_of_sections() {
+    let mut data = BTreeMap::new();
+    let mut section = BTreeMap::new();
+    section.insert("key", 1);
+    data.insert("section", section);
+
+    assert_eq!(to_string(&data).unwrap(), "[section]\nkey=1\n");
+}
+
+#[test]
+fn to_writer_works() {
+    let mut output = Vec::new();
+    to_writer(&mut output, &config()).unwrap();
+
+    assert_eq!(String::from_utf8(output).unwrap(), to_string(&config()).unwrap());
+}
+
+#[test]
+fn to_string_rejects_scalar() {
+    assert!(to_string(&42).is_err());
+}
+
+#[test]
+fn to_string_rejects_deep_nesting() {
+    let mut inner = BTreeMap::new();
+    inner.insert("key", 1);
+    let mut section = BTreeMap::new();
+    section.insert("inner", inner);
+    let mut data = BTreeMap::new();
+    data.insert("section", section);
+
+    let err = to_string(&data).unwrap_err();
+    assert_eq!(err.message(), "A structure or a map nested in a section can't be represented at section.inner");
+}
+
+#[test]
+fn to_string_rejects_invalid_keys() {
+    let mut data = BTreeMap::new();
+    data.insert("invalid key!", 1);
+
+    assert!(to_string(&data).is_err());
+}
+
+#[test]
+fn to_string_rejects_items_with_commas() {
+    let mut data = BTreeMap::new();
+    data.insert("list", vec!["a,b"]);
+
+    assert!(to_string(&data).is_err());
+}
+
+#[test]
+fn to_string_rejects_big_integers() {
+    let mut data = BTreeMap::new();
+    data.insert("big", u64::MAX);
+    assert!(to_string(&data).is_err());
+
+    let mut data = BTreeMap::new();
+    data.insert("list", vec![1, u64::MAX]);
+    assert!(to_string(&data).is_err());
+}
+
+#[test]
+fn to_string_rejects_variants_with_data() {
+    #[derive(Serialize)]
+    enum Shape {
+        Circle(f64),
+    }
+
+    let mut data = BTreeMap::new();
+    data.insert("shape", Shape::Circle(1.0));
+
+    assert!(to_string(&data).is_err());
+}
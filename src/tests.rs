@@ -3,7 +3,7 @@ use parse::parse_file;
 use errors::ParseFileError;
 use crate::datas::{Identifier, Value};
 use std::collections::HashMap;
-use dump::dump_into_file;
+use dump::{dump_into_file, dump_profile_into_file};
 use std::fs::{self, File};
 use std::io::Read;
 
@@ -116,3 +116,122 @@ fn test_dump_into_file() {
 
     assert_eq!(content, expected);
 }
+
+#[test]
+fn test_profile_file_round_trip() {
+    let mut profile = parse::parse_profile_file("fixtures/windows/utf16le.ini").unwrap();
+    profile.set(Some("Général"), "Pays", "Suisse");
+
+    let path = "test profile.ini";
+    dump_profile_into_file(path, &profile).unwrap();
+
+    let read = parse::parse_profile_file(path);
+    if let Err(err) = fs::remove_file(path) {
+        eprintln!("Error while removing the file: {}", err);
+    }
+
+    let read = read.unwrap();
+    assert_eq!(read, profile);
+    assert_eq!(read.get(Some("général"), "pays"), Some("Suisse"));
+}
+
+#[test]
+fn test_profile_file_encoding_errors() {
+    use crate::datas::encoding::Encoding;
+
+    let mut profile = parse::parse_profile_file("fixtures/windows/utf16le.ini").unwrap();
+    profile.set(Some("Général"), "Pays", "\u{263a}");
+    profile.set_encoding(Encoding::Latin1, false);
+
+    let path = "test profile latin1.ini";
+    match dump_profile_into_file(path, &profile) {
+        Err(ParseFileError::EncodingError(err)) => assert_eq!(err.character(), Some('\u{263a}')),
+        other                                   => panic!("Expected an encoding error, got {:?}", other),
+    }
+    assert!(!std::path::Path::new(path).exists());
+
+    match parse::parse_profile_file("fixtures/windows/missing.ini") {
+        Err(ParseFileError::IOError(_)) => (),
+        other                           => panic!("Expected an I/O error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_properties_file_round_trip() {
+    let data = crate::properties::parse_file("fixtures/properties/sample.properties").unwrap();
+
+    let path = "test dump.properties";
+    crate::properties::dump_into_file(path, data.clone()).unwrap();
+
+    let read = crate::properties::parse_file(path);
+    if let Err(err) = fs::remove_file(path) {
+        eprintln!("Error while removing the file: {}", err);
+    }
+
+    assert_eq!(read.unwrap(), data);
+}
+
+#[test]
+fn test_dotenv_file_round_trip() {
+    let data = crate::dotenv::parse_file("fixtures/dotenv/launcher.env").unwrap();
+
+    let path = "test dump.env";
+    crate::dotenv::dump_into_file(path, data.clone()).unwrap();
+
+    let read = crate::dotenv::parse_file(path);
+    if let Err(err) = fs::remove_file(path) {
+        eprintln!("Error while removing the file: {}", err);
+    }
+
+    assert_eq!(read.unwrap(), data);
+}
+
+#[test]
+fn test_utf8_file_round_trip() {
+    use crate::datas::TextMode;
+
+    let mut data = HashMap::new();
+    data.insert(Identifier::new(None, String::from("title")), Value::Str(String::from("Les Misérables")));
+    data.insert(Identifier::new(Some(String::from("ja")), String::from("title")), Value::Raw(String::from("レ・ミゼラブル")));
+
+    let path = "test dump utf8.ini";
+    dump::dump_into_file_with(path, data.clone(), TextMode::Utf8).unwrap();
+
+    let mut content = String::new();
+    File::open(path).unwrap().read_to_string(&mut content).unwrap();
+    let read = parse::parse_file_with(path, TextMode::Utf8);
+    let ascii = parse_file(path);
+    if let Err(err) = fs::remove_file(path) {
+        eprintln!("Error while removing the file: {}", err);
+    }
+
+    assert_eq!(content, "title='Les Misérables'\n\n[ja]\ntitle=レ・ミゼラブル\n");
+    assert_eq!(read.unwrap(), data);
+    assert!(ascii.is_err());
+}
+
+#[test]
+fn test_parse_file_encodings() {
+    use crate::datas::encoding::Encoding;
+
+    let path = "test encodings.ini";
+    let key = Identifier::new(Some(String::from("names")), String::from("first"));
+    let read = |bytes: Vec<u8>| {
+        fs::write(path, bytes).unwrap();
+        parse_file(path)
+    };
+
+    let utf8_bom = read(Encoding::Utf8.encode("[names]\nfirst=Boris\n", true).unwrap());
+    let utf16 = read(Encoding::Utf16Le.encode("[names]\nfirst=Boris\n", true).unwrap());
+    let latin1 = read(Encoding::Latin1.encode("[names]\nfirst=\u{c9}lodie\n", false).unwrap());
+    if let Err(err) = fs::remove_file(path) {
+        eprintln!("Error while removing the file: {}", err);
+    }
+
+    assert_eq!(utf8_bom.unwrap()[&key], Value::Raw(String::from("Boris")));
+    assert_eq!(utf16.unwrap()[&key], Value::Raw(String::from("Boris")));
+    match latin1 {
+        Err(ParseFileError::EncodingError(err)) => assert_eq!((err.encoding(), err.offset()), (Encoding::Utf8, 14)),
+        other                                   => panic!("Expected an EncodingError, got {:?}", other),
+    }
+}
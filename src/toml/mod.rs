@@ -0,0 +1,264 @@
+//! Conversion between a [`Tree`](../datas/tree/struct.Tree.html "datas::tree::Tree") and TOML (requires the `toml` feature)
+//! 
+//! The keys of the global section are the root key/value pairs while each section is a table. The values are converted as follows
+//! - `Int`, `Float`, `Bool` and `Str` are TOML integers, floats, booleans and strings
+//! - `Date`, `Time` and `DateTime` are TOML local dates, local times and local or offset date-times
+//! - `Raw`, `Duration` and `Size` have no TOML equivalent. They are rejected unless the [`TextFallback`](enum.TextFallback.html "toml::TextFallback") writes them as TOML strings of their INI text, which are read back as a `Str`
+//! 
+//! A TOML document which can't be represented by a `Tree`, such as a table nested in a table, an array or an array of tables, is rejected with an [`Error`](enum.Error.html "toml::Error") instead of being partially converted
+//! 
+//! # Example
+//! ```
+//! use mininip::datas::tree::Tree;
+//! use mininip::parse::Parser;
+//! use mininip::toml::TextFallback;
+//! 
+//! let mut parser = Parser::new();
+//! parser.parse_content("name = 'app'\n[server]\nport = 8080\nstarted = 2020-04-28T12:30:00Z\n").unwrap();
+//! let tree = Tree::from(parser.data());
+//! 
+//! let toml = mininip::toml::to_string(&tree, TextFallback::Reject).unwrap();
+//! assert_eq!(toml, "name = \"app\"\n\n[server]\nport = 8080\nstarted = 2020-04-28T12:30:00Z\n");
+//! 
+//! let back = mininip::toml::from_str(&toml).unwrap();
+//! assert_eq!(back.get_data(), tree.get_data());
+//! ```
+
+use crate::datas::{Identifier, Value};
+use crate::datas::datetime::{Date, Time, DateTime};
+use crate::datas::tree::{nested, Tree};
+use ::toml::{map, Table};
+use ::toml::value::{self, Datetime, Offset};
+use std::error;
+use std::fmt::{self, Display};
+
+/// How `Value::Raw`, `Value::Duration` and `Value::Size`, which have no TOML equivalent, are converted into TOML
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextFallback {
+    /// They are rejected with `Error::NoEquivalent` since they would not be read back with the same type
+    #[default]
+    Reject,
+    /// They are written as TOML strings of their INI text, which are read back as a `Str`
+    Str,
+}
+
+/// Represents a failure while converting between a [`Tree`](../datas/tree/struct.Tree.html "datas::tree::Tree") and TOML
+/// 
+/// The path stored by most variants is the dotted key of the item involved, like `section.key`
+#[derive(Debug)]
+pub enum Error {
+    /// The text is not valid TOML
+    Syntax(::toml::de::Error),
+    /// A table is nested in a section while sections can't be nested
+    NestedTable(String),
+    /// An array of tables has no INI equivalent
+    ArrayOfTables(String),
+    /// An array has no INI equivalent
+    Array(String),
+    /// A key is not a valid [`Identifier`](../datas/struct.Identifier.html "datas::Identifier")
+    InvalidName(String),
+    /// A key of the global section has the same name as a section so they can't be both in the root table
+    NameClash(String),
+    /// A TOML date or time is out of the range of the [`datetime`](../datas/datetime/index.html "datas::datetime") types
+    InvalidDatetime(String),
+    /// A `Raw`, a `Duration` or a `Size` has no TOML equivalent and the [`TextFallback`](enum.TextFallback.html "toml::TextFallback") rejects it
+    NoEquivalent(Identifier),
+}
+
+impl error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Syntax(err)           => write!(f, "{}", err),
+            Error::NestedTable(path)     => write!(f, "Sections can't be nested at {}", path),
+            Error::ArrayOfTables(path)   => write!(f, "An array of tables has no INI equivalent at {}", path),
+            Error::Array(path)           => write!(f, "An array has no INI equivalent at {}", path),
+            Error::InvalidName(path)     => write!(f, "Invalid identifier at {}", path),
+            Error::NameClash(path)       => write!(f, "A key and a section have the same name at {}", path),
+            Error::InvalidDatetime(path) => write!(f, "Unsupported date or time at {}", path),
+            Error::NoEquivalent(ident)   => write!(f, "The value of {} has no TOML equivalent", ident),
+        }
+    }
+}
+
+impl From<::toml::de::Error> for Error {
+    fn from(err: ::toml::de::Error) -> Error {
+        Error::Syntax(err)
+    }
+}
+
+impl nested::Map for Table {
+    type Item = ::toml::Value;
+    type Error = Error;
+    type Entries<'a> = map::Iter<'a>;
+
+    fn entries(&self) -> map::Iter<'_> {
+        self.iter()
+    }
+
+    fn contains_key(&self, key: &str) -> bool {
+        self.contains_key(key)
+    }
+
+    fn insert(&mut self, key: String, item: ::toml::Value) {
+        self.insert(key, item);
+    }
+
+    fn as_map(item: &::toml::Value) -> Option<&Self> {
+        item.as_table()
+    }
+
+    fn into_item(self) -> ::toml::Value {
+        ::toml::Value::Table(self)
+    }
+
+    /// Returns the dotted key of `key` in the table at `parent`, quoting `key` if it is not a bare key
+    fn path(parent: Option<&str>, key: &str) -> String {
+        let bare = !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        let key = if bare {
+            String::from(key)
+        } else {
+            format!("{:?}", key)
+        };
+
+        match parent {
+            Some(parent) => format!("{}.{}", parent, key),
+            None         => key,
+        }
+    }
+
+    fn invalid_name(path: String) -> Error {
+        Error::InvalidName(path)
+    }
+
+    fn name_clash(path: String) -> Error {
+        Error::NameClash(path)
+    }
+
+    fn nested(path: String) -> Error {
+        Error::NestedTable(path)
+    }
+}
+
+
+/// Converts `tree` into a TOML table, writing the values which have no TOML equivalent according to `fallback`
+/// 
+/// # Return value
+/// `Ok(table)` in case of success
+/// 
+/// `Err(error)` if a key of the global section has the name of a section or if `fallback` rejects a value
+pub fn to_table(tree: &Tree, fallback: TextFallback) -> Result<Table, Error> {
+    nested::from_tree(tree, |ident, value| to_toml(ident, value, fallback))
+}
+
+/// Converts `tree` into a TOML text
+/// 
+/// # Return value
+/// The same as [`to_table`](fn.to_table.html "toml::to_table")
+pub fn to_string(tree: &Tree, fallback: TextFallback) -> Result<String, Error> {
+    let table = to_table(tree, fallback)?;
+
+    Ok(::toml::to_string(&table).expect("A table of scalars and tables of scalars is always valid TOML"))
+}
+
+/// Converts the value of `ident` into TOML
+fn to_toml(ident: &Identifier, value: &Value, fallback: TextFallback) -> Result<::toml::Value, Error> {
+    Ok(match value {
+        Value::Int(number)        => ::toml::Value::Integer(*number),
+        Value::Float(number)      => ::toml::Value::Float(*number),
+        Value::Bool(value)        => ::toml::Value::Boolean(*value),
+        Value::Str(text)          => ::toml::Value::String(text.clone()),
+        Value::Date(date)         => ::toml::Value::Datetime(Datetime {
+            date: Some(to_toml_date(date)),
+            time: None,
+            offset: None,
+        }),
+        Value::Time(time)         => ::toml::Value::Datetime(Datetime {
+            date: None,
+            time: Some(to_toml_time(time)),
+            offset: None,
+        }),
+        Value::DateTime(datetime) => ::toml::Value::Datetime(Datetime {
+            date: Some(to_toml_date(&datetime.date())),
+            time: Some(to_toml_time(&datetime.time())),
+            offset: datetime.offset().map(|minutes| if minutes == 0 { Offset::Z } else { Offset::Custom { minutes } }),
+        }),
+        value                     => match fallback {
+            TextFallback::Reject => return Err(Error::NoEquivalent(ident.clone())),
+            TextFallback::Str    => ::toml::Value::String(format!("{}", value)),
+        },
+    })
+}
+
+/// Converts `date` into a TOML local date
+fn to_toml_date(date: &Date) -> value::Date {
+    value::Date {
+        year: date.year(),
+        month: date.month(),
+        day: date.day(),
+    }
+}
+
+/// Converts `time` into a TOML local time
+fn to_toml_time(time: &Time) -> value::Time {
+    value::Time {
+        hour: time.hour(),
+        minute: time.minute(),
+        second: time.second(),
+        nanosecond: time.nanosecond(),
+    }
+}
+
+
+/// Converts a TOML table into a [`Tree`](../datas/tree/struct.Tree.html "datas::tree::Tree")
+/// 
+/// # Return value
+/// `Ok(tree)` in case of success
+/// 
+/// `Err(error)` in case of error with `error` as the first item found which can't be represented (see [`Error`](enum.Error.html "toml::Error"))
+pub fn from_table(table: &Table) -> Result<Tree, Error> {
+    nested::to_tree(table, from_toml)
+}
+
+/// Reads a TOML text into a [`Tree`](../datas/tree/struct.Tree.html "datas::tree::Tree")
+/// 
+/// # Return value
+/// The same as [`from_table`](fn.from_table.html "toml::from_table") or `Err(Error::Syntax(error))` if `content` is not valid TOML
+pub fn from_str(content: &str) -> Result<Tree, Error> {
+    let table: Table = content.parse()?;
+    from_table(&table)
+}
+
+/// Converts the item `item` found at `path` into a `Value`
+fn from_toml(path: &str, item: &::toml::Value) -> Result<Value, Error> {
+    Ok(match item {
+        ::toml::Value::Integer(number)    => Value::Int(*number),
+        ::toml::Value::Float(number)      => Value::Float(*number),
+        ::toml::Value::Boolean(value)     => Value::Bool(*value),
+        ::toml::Value::String(text)       => Value::Str(text.clone()),
+        ::toml::Value::Table(_)           => return Err(Error::NestedTable(String::from(path))),
+        ::toml::Value::Array(array)       => {
+            let tables = !array.is_empty() && array.iter().all(::toml::Value::is_table);
+            return Err(if tables { Error::ArrayOfTables(String::from(path)) } else { Error::Array(String::from(path)) });
+        },
+        ::toml::Value::Datetime(datetime) => {
+            let text = datetime.to_string();
+            let value = match (datetime.date, datetime.time) {
+                (Some(_), Some(_)) => DateTime::parse(&text).map(Value::DateTime),
+                (Some(_), None)    => Date::parse(&text).map(Value::Date),
+                (None, Some(_))    => Time::parse(&text).map(Value::Time),
+                (None, None)       => None,
+            };
+
+            match value {
+                Some(value) => value,
+                None        => return Err(Error::InvalidDatetime(String::from(path))),
+            }
+        },
+    })
+}
+
+
+#[cfg(test)]
+mod tests;
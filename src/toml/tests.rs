@@ -0,0 +1,116 @@
+use crate::datas::{Identifier, Value};
+use crate::datas::datetime::{Date, Time, DateTime};
+use crate::datas::tree::Tree;
+use crate::toml::*;
+use std::collections::HashMap;
+
+fn tree(content: &str) -> Tree {
+    let mut parser = crate::parse::Parser::new();
+    parser.parse_content(content).unwrap();
+
+    Tree::from(parser.data())
+}
+
+fn ident(section: Option<&str>, name: &str) -> Identifier {
+    Identifier::new(section.map(String::from), String::from(name))
+}
+
+#[test]
+fn toml_round_trip() {
+    let tree = tree("\
+title = 'Config'
+[values]
+int = -3
+float = 1.5
+bool = off
+[dates]
+date = 2020-02-29
+time = 23:59:07.25
+local = 2020-04-28T12:30:00
+offset = 2020-04-28T12:30:00-07:30
+utc = 2020-04-28T12:30:00Z
+");
+
+    let back = from_str(&to_string(&tree, TextFallback::Reject).unwrap()).unwrap();
+    assert_eq!(back.get_data(), tree.get_data());
+}
+
+#[test]
+fn toml_values() {
+    let data = from_str("\
+a = 1979-05-27
+b = 07:32:00.999
+c = 1979-05-27T07:32:00+01:00
+d = \"\"\"multi
+line\"\"\"
+e = 0x10
+").unwrap().into_data();
+
+    assert_eq!(data[&ident(None, "a")], Value::Date(Date::new(1979, 5, 27)));
+    assert_eq!(data[&ident(None, "b")], Value::Time(Time::new(7, 32, 0, 999_000_000)));
+    assert_eq!(data[&ident(None, "c")], Value::DateTime(DateTime::new(Date::new(1979, 5, 27), Time::new(7, 32, 0, 0), Some(60))));
+    assert_eq!(data[&ident(None, "d")], Value::Str(String::from("multi\nline")));
+    assert_eq!(data[&ident(None, "e")], Value::Int(16));
+}
+
+#[test]
+fn toml_strings() {
+    let tree = tree("raw = text\nsize = 4KiB\n");
+
+    match to_table(&tree, TextFallback::Reject) {
+        Err(Error::NoEquivalent(ident)) => assert_eq!(ident, self::ident(None, "raw")),
+        other                           => panic!("Expected a NoEquivalent error, got {:?}", other),
+    }
+
+    let table = to_table(&tree, TextFallback::Str).unwrap();
+    assert_eq!(table["raw"], ::toml::Value::String(String::from("text")));
+    assert_eq!(table["size"], ::toml::Value::String(String::from("4KiB")));
+
+    let data = from_table(&table).unwrap().into_data();
+    assert_eq!(data[&ident(None, "raw")], Value::Str(String::from("text")));
+}
+
+#[test]
+fn toml_dotted_keys_are_sections() {
+    let data = from_str("server.port = 8080\n[client]\nname = 'me'\n").unwrap().into_data();
+
+    assert_eq!(data[&ident(Some("server"), "port")], Value::Int(8080));
+    assert_eq!(data[&ident(Some("client"), "name")], Value::Str(String::from("me")));
+}
+
+#[test]
+fn toml_import_errors() {
+    let expect = |content: &str, expected: &str| {
+        match from_str(content) {
+            Err(err) => assert_eq!(format!("{}", err), expected, "for {}", content),
+            Ok(_)    => panic!("{} should be rejected", content),
+        }
+    };
+
+    expect("[a.b]\nc = 1\n", "Sections can't be nested at a.b");
+    expect("[a]\nb = { c = 1 }\n", "Sections can't be nested at a.b");
+    expect("[[servers]]\nname = 'a'\n", "An array of tables has no INI equivalent at servers");
+    expect("[a]\n[[a.list]]\nname = 'a'\n", "An array of tables has no INI equivalent at a.list");
+    expect("list = [1, 2]\n", "An array has no INI equivalent at list");
+    expect("[a]\nempty = []\n", "An array has no INI equivalent at a.empty");
+    expect("1st = 1\n\"a.b\" = 2\n", "Invalid identifier at 1st");
+    expect("\"a/b\" = 1\n", "Invalid identifier at \"a/b\"");
+    expect("[a]\n\"b c\" = 1\n\"with space\" = 2\n\"_\" = 3\n", "Invalid identifier at a._");
+
+    match from_str("a = ") {
+        Err(Error::Syntax(_)) => (),
+        other                 => panic!("Expected a Syntax error, got {:?}", other.map(|tree| tree.into_data())),
+    }
+}
+
+#[test]
+fn toml_export_errors() {
+    let mut data = HashMap::new();
+    data.insert(ident(None, "server"), Value::Int(1));
+    data.insert(ident(Some("server"), "port"), Value::Int(2));
+
+    match to_table(&Tree::from(data), TextFallback::Reject) {
+        Err(Error::NameClash(path)) => assert_eq!(path, "server"),
+        other                       => panic!("Expected a NameClash error, got {:?}", other),
+    }
+}